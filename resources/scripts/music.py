@@ -0,0 +1,92 @@
+#!/usr/bin/env python3
+
+import math
+import os
+import struct
+import wave
+
+SAMPLE_RATE = 22050
+OUTPUT_PATH = '../assets/sounds/music/'
+
+NOTES = {
+    'C': 0, 'C#': 1, 'D': 2, 'D#': 3, 'E': 4, 'F': 5,
+    'F#': 6, 'G': 7, 'G#': 8, 'A': 9, 'A#': 10, 'B': 11,
+}
+
+def frequency(note, octave):
+    return 440.0 * 2.0 ** ((NOTES[note] - 9 + (octave - 4) * 12) / 12.0)
+
+def tone(frequency, t, shape):
+    phase = (frequency * t) % 1.0
+    if shape == 'square':
+        return 1.0 if phase < 0.5 else -1.0
+    if shape == 'triangle':
+        return 4.0 * abs(phase - 0.5) - 1.0
+    return math.sin(2.0 * math.pi * phase)
+
+def envelope(t, duration):
+    attack = min(0.01, duration / 4.0)
+    release = min(0.05, duration / 4.0)
+    if t < attack:
+        return t / attack
+    if t > duration - release:
+        return max(0.0, (duration - t) / release)
+    return 1.0
+
+def voice(melody, beat, shape, volume):
+    samples = []
+    for note, octave, beats in melody:
+        duration = beat * beats
+        for i in range(int(duration * SAMPLE_RATE)):
+            t = i / SAMPLE_RATE
+            if note is None:
+                samples.append(0.0)
+            else:
+                samples.append(volume * envelope(t, duration) * tone(frequency(note, octave), t, shape))
+    return samples
+
+def arpeggio(chords, octave, beats_per_chord):
+    melody = []
+    for chord in chords:
+        for i in range(beats_per_chord * 2):
+            melody.append((chord[i % len(chord)], octave, 0.5))
+    return melody
+
+def bass(chords, octave, beats_per_chord):
+    return [(chord[0], octave, beats_per_chord) for chord in chords]
+
+def mix(*tracks):
+    length = min(len(track) for track in tracks)
+    return [sum(track[i] for track in tracks) for i in range(length)]
+
+def save(title, samples):
+    path = os.path.join(OUTPUT_PATH, title + '.wav')
+    with wave.open(path, 'wb') as file:
+        file.setnchannels(1)
+        file.setsampwidth(2)
+        file.setframerate(SAMPLE_RATE)
+        frames = b''.join(struct.pack('<h', int(max(-1.0, min(1.0, s)) * 32767)) for s in samples)
+        file.writeframes(frames)
+    print('ok: ' + title)
+
+def loop(title, chords, tempo, beats_per_chord, lead_shape, lead_octave, repeats):
+    beat = 60.0 / tempo
+    chords = chords * repeats
+    save(title, mix(
+        voice(arpeggio(chords, lead_octave, beats_per_chord), beat, lead_shape, 0.18),
+        voice(bass(chords, 2, beats_per_chord), beat, 'triangle', 0.35),
+    ))
+
+os.makedirs(OUTPUT_PATH, exist_ok = True)
+
+A_MINOR = ['A', 'C', 'E']
+F_MAJOR = ['F', 'A', 'C']
+C_MAJOR = ['C', 'E', 'G']
+G_MAJOR = ['G', 'B', 'D']
+D_MINOR = ['D', 'F', 'A']
+E_MAJOR = ['E', 'G#', 'B']
+
+loop('exploration', [A_MINOR, F_MAJOR, D_MINOR, E_MAJOR], 84, 4, 'triangle', 4, 2)
+loop('survival_wave', [D_MINOR, A_MINOR, D_MINOR, E_MAJOR], 140, 2, 'square', 4, 4)
+loop('reward', [C_MAJOR, G_MAJOR, A_MINOR, F_MAJOR], 96, 4, 'sine', 5, 2)
+loop('result', [F_MAJOR, C_MAJOR, G_MAJOR, C_MAJOR], 72, 4, 'sine', 4, 2)
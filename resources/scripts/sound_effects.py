@@ -0,0 +1,78 @@
+#!/usr/bin/env python3
+
+import math
+import os
+import random
+import struct
+import wave
+
+SAMPLE_RATE = 22050
+OUTPUT_PATH = '../assets/sounds/effects/'
+
+random.seed(7)
+
+def envelope(t, duration, attack = 0.01):
+    if t < attack:
+        return t / attack
+    return max(0.0, 1.0 - (t - attack) / (duration - attack))
+
+def tone(frequency, t, shape = 'sine'):
+    phase = (frequency * t) % 1.0
+    if shape == 'square':
+        return 1.0 if phase < 0.5 else -1.0
+    if shape == 'saw':
+        return 2.0 * phase - 1.0
+    return math.sin(2.0 * math.pi * phase)
+
+def sweep(start, end, duration, shape = 'sine', volume = 0.6):
+    samples = []
+    phase = 0.0
+    for i in range(int(duration * SAMPLE_RATE)):
+        t = i / SAMPLE_RATE
+        frequency = start + (end - start) * t / duration
+        phase += frequency / SAMPLE_RATE
+        samples.append(volume * envelope(t, duration) * tone(1.0, phase % 1.0, shape))
+    return samples
+
+def noise(duration, volume = 0.5, smoothing = 0.0):
+    samples = []
+    last = 0.0
+    for i in range(int(duration * SAMPLE_RATE)):
+        t = i / SAMPLE_RATE
+        last = last * smoothing + random.uniform(-1.0, 1.0) * (1.0 - smoothing)
+        samples.append(volume * envelope(t, duration) * last)
+    return samples
+
+def notes(frequencies, note_duration, shape = 'square', volume = 0.4):
+    samples = []
+    for frequency in frequencies:
+        for i in range(int(note_duration * SAMPLE_RATE)):
+            t = i / SAMPLE_RATE
+            samples.append(volume * envelope(t, note_duration) * tone(frequency, t, shape))
+    return samples
+
+def mix(*tracks):
+    length = max(len(track) for track in tracks)
+    return [sum(track[i] for track in tracks if i < len(track)) for i in range(length)]
+
+def save(title, samples):
+    path = os.path.join(OUTPUT_PATH, title + '.wav')
+    with wave.open(path, 'wb') as file:
+        file.setnchannels(1)
+        file.setsampwidth(2)
+        file.setframerate(SAMPLE_RATE)
+        frames = b''.join(struct.pack('<h', int(max(-1.0, min(1.0, s)) * 32767)) for s in samples)
+        file.writeframes(frames)
+    print('ok: ' + title)
+
+os.makedirs(OUTPUT_PATH, exist_ok = True)
+
+save('swing', noise(0.15, 0.5, 0.85))
+save('shoot', sweep(900.0, 300.0, 0.12, 'square', 0.3))
+save('monster_hit', mix(noise(0.08, 0.5, 0.5), sweep(180.0, 80.0, 0.1, 'sine', 0.6)))
+save('player_hurt', sweep(400.0, 120.0, 0.25, 'saw', 0.4))
+save('potion_pickup', notes([523.0, 659.0, 784.0], 0.07, 'sine', 0.5))
+save('door', mix(noise(0.3, 0.3, 0.95), sweep(90.0, 60.0, 0.3, 'sine', 0.5)))
+save('skill_cast', mix(sweep(200.0, 1200.0, 0.4, 'sine', 0.4), noise(0.4, 0.1, 0.3)))
+save('wave_cleared', notes([392.0, 523.0, 659.0, 784.0], 0.12))
+save('floor_cleared', notes([523.0, 659.0, 784.0, 1047.0, 784.0, 1047.0], 0.1))
@@ -0,0 +1,20 @@
+use std::process::ExitCode;
+
+use dungeon_quest_v2::headless::balance::{self, BalanceOptions};
+
+// plays bot runs for every hero and weapon of its progression and reports how they went
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let options = match BalanceOptions::from_args(&args) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}", err);
+            eprintln!("{}", balance::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    balance::run(options);
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,20 @@
+use std::process::ExitCode;
+
+use dungeon_quest_v2::validate;
+
+// checks the content files the game loads, run it from the game directory
+fn main() -> ExitCode {
+    let problems = validate::validate_content();
+
+    for problem in problems.iter() {
+        println!("{}", problem);
+    }
+
+    if problems.is_empty() {
+        println!("content is valid");
+        ExitCode::SUCCESS
+    } else {
+        println!("{} problems found", problems.len());
+        ExitCode::FAILURE
+    }
+}
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::resources::monster::monster_class::MonsterClass;
+
 #[derive(Component)]
 pub struct BulletComponent {
     pub duration: Timer,
@@ -7,3 +9,12 @@ pub struct BulletComponent {
     pub target_y: f32,
     pub speed: f32,
 }
+
+// bullets fired by monsters, they hurt the player instead of monsters
+#[derive(Component)]
+pub struct MonsterBulletComponent {
+    pub damage: f32,
+    // the shooter's damage bonus when it fired, the shooter may be gone on hit
+    pub damage_percent_bonus: f32,
+    pub monster_class: MonsterClass,
+}
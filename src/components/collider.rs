@@ -0,0 +1,133 @@
+use bevy::prelude::*;
+use std::slice::Iter;
+
+use crate::utils::collide::{collide, collide_circle_rectangle, collide_circles};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum CollisionLayer {
+    Player,
+    Monster,
+    Block,
+    Weapon,
+    Bullet,
+    MonsterBullet,
+    Potion,
+    Door,
+    EndPoint,
+}
+
+impl CollisionLayer {
+    pub fn iterator() -> Iter<'static, CollisionLayer> {
+        [
+            CollisionLayer::Player,
+            CollisionLayer::Monster,
+            CollisionLayer::Block,
+            CollisionLayer::Weapon,
+            CollisionLayer::Bullet,
+            CollisionLayer::MonsterBullet,
+            CollisionLayer::Potion,
+            CollisionLayer::Door,
+            CollisionLayer::EndPoint,
+        ]
+        .iter()
+    }
+
+    pub fn bit(&self) -> u32 {
+        1 << *self as u32
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ColliderShape {
+    Rectangle(Vec2),
+    Circle(f32),
+}
+
+// `mask` holds the layers this collider reports collisions with,
+// colliders with an empty mask are only ever the `other` side of a collision
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Collider {
+    pub shape: ColliderShape,
+    pub offset: Vec2,
+    pub layer: CollisionLayer,
+    pub mask: u32,
+}
+
+impl Collider {
+    pub fn rectangle(width: f32, height: f32, layer: CollisionLayer) -> Self {
+        Collider {
+            shape: ColliderShape::Rectangle(Vec2::new(width, height)),
+            offset: Vec2::ZERO,
+            layer,
+            mask: 0,
+        }
+    }
+
+    pub fn circle(radius: f32, layer: CollisionLayer) -> Self {
+        Collider {
+            shape: ColliderShape::Circle(radius),
+            offset: Vec2::ZERO,
+            layer,
+            mask: 0,
+        }
+    }
+
+    pub fn with_offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_mask(mut self, layers: &[CollisionLayer]) -> Self {
+        self.mask = layers.iter().fold(0, |mask, layer| mask | layer.bit());
+        self
+    }
+
+    pub fn scaled(&self, scale: f32) -> Self {
+        let shape = match self.shape {
+            ColliderShape::Rectangle(size) => ColliderShape::Rectangle(size * scale),
+            ColliderShape::Circle(radius) => ColliderShape::Circle(radius * scale),
+        };
+
+        Collider {
+            shape,
+            offset: self.offset * scale,
+            ..*self
+        }
+    }
+
+    pub fn collides_with(&self, layer: CollisionLayer) -> bool {
+        self.mask & layer.bit() != 0
+    }
+
+    pub fn centre(&self, translation: Vec3) -> Vec3 {
+        translation + self.offset.extend(0.0)
+    }
+
+    // bounding box of the shape, used by the spatial hash and to push against walls
+    pub fn size(&self) -> Vec2 {
+        match self.shape {
+            ColliderShape::Rectangle(size) => size,
+            ColliderShape::Circle(radius) => Vec2::splat(radius * 2.0),
+        }
+    }
+
+    pub fn intersects(&self, translation: Vec3, other: &Collider, other_translation: Vec3) -> bool {
+        let centre = self.centre(translation);
+        let other_centre = other.centre(other_translation);
+
+        match (self.shape, other.shape) {
+            (ColliderShape::Rectangle(size), ColliderShape::Rectangle(other_size)) => {
+                collide(centre, size, other_centre, other_size)
+            }
+            (ColliderShape::Circle(radius), ColliderShape::Circle(other_radius)) => {
+                collide_circles(centre, radius, other_centre, other_radius)
+            }
+            (ColliderShape::Circle(radius), ColliderShape::Rectangle(other_size)) => {
+                collide_circle_rectangle(centre, radius, other_centre, other_size)
+            }
+            (ColliderShape::Rectangle(size), ColliderShape::Circle(other_radius)) => {
+                collide_circle_rectangle(other_centre, other_radius, centre, size)
+            }
+        }
+    }
+}
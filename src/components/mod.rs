@@ -1,8 +1,10 @@
 pub mod bullet;
+pub mod collider;
 pub mod invinsible_cooldown;
 pub mod monster;
 pub mod monster_animation;
 pub mod monster_list_effects;
+pub mod monster_skill;
 pub mod player;
 pub mod player_animation;
 pub mod player_list_effects;
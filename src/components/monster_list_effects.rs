@@ -7,53 +7,35 @@ use crate::resources::effect::effect_type::EffectType;
 #[derive(Component, Debug)]
 pub struct MonsterListEffectsComponent {
     pub activated_effects: HashMap<EffectType, Timer>,
+    // how strong each effect was when it landed, e.g. 0.3 for a 30% slow
+    pub bonuses: HashMap<EffectType, f32>,
 }
 
 impl MonsterListEffectsComponent {
     pub fn new() -> MonsterListEffectsComponent {
-        let mut monster_list_effects = MonsterListEffectsComponent {
+        MonsterListEffectsComponent {
             activated_effects: HashMap::new(),
-        };
-
-        monster_list_effects.activated_effects.insert(
-            EffectType::Stun,
-            Timer::new(Duration::from_secs(0), TimerMode::Once),
-        );
-
-        monster_list_effects.activated_effects.insert(
-            EffectType::Slow,
-            Timer::new(Duration::from_secs(0), TimerMode::Once),
-        );
+            bonuses: HashMap::new(),
+        }
+    }
 
-        monster_list_effects.activated_effects.insert(
-            EffectType::ReduceDamage,
-            Timer::new(Duration::from_secs(0), TimerMode::Once),
-        );
+    pub fn activate(&mut self, effect_type: EffectType, duration: Duration, bonus: f32) {
+        self.activated_effects
+            .insert(effect_type, Timer::new(duration, TimerMode::Once));
+        self.bonuses.insert(effect_type, bonus);
+    }
 
-        monster_list_effects
+    pub fn is_active(&self, effect_type: EffectType) -> bool {
+        self.activated_effects
+            .get(&effect_type)
+            .is_some_and(|duration| !duration.finished())
     }
 
-    pub fn activate(&mut self, effect_type: EffectType) {
-        match effect_type {
-            EffectType::Stun => {
-                self.activated_effects.insert(
-                    EffectType::Stun,
-                    Timer::new(Duration::from_secs(2), TimerMode::Once),
-                );
-            }
-            EffectType::ReduceDamage => {
-                self.activated_effects.insert(
-                    EffectType::ReduceDamage,
-                    Timer::new(Duration::from_secs(10), TimerMode::Once),
-                );
-            }
-            EffectType::Slow => {
-                self.activated_effects.insert(
-                    EffectType::Slow,
-                    Timer::new(Duration::from_secs(3), TimerMode::Once),
-                );
-            }
-            _ => {}
+    pub fn bonus(&self, effect_type: EffectType) -> f32 {
+        if self.is_active(effect_type) {
+            self.bonuses.get(&effect_type).copied().unwrap_or(0.0)
+        } else {
+            0.0
         }
     }
 }
@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use std::time::Duration;
+
+use crate::resources::monster::monster_skill::MonsterSkill;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum MonsterSkillState {
+    Ready,
+    Telegraphing,
+    // the telegraph is over, the skill fires this frame
+    Casting,
+    Charging,
+}
+
+#[derive(Component)]
+pub struct MonsterSkillComponent {
+    pub skill: MonsterSkill,
+    pub state: MonsterSkillState,
+    pub cooldown: Timer,
+    pub telegraph: Timer,
+    pub duration: Timer,
+    // where the player stood when the telegraph started
+    pub target: Vec2,
+    pub direction: Vec2,
+}
+
+impl MonsterSkillComponent {
+    pub fn new(skill: MonsterSkill) -> Self {
+        // only a charge lasts, the other skills are over once they are cast
+        let duration = match skill {
+            MonsterSkill::Charge { duration, .. } => duration,
+            _ => 0.0,
+        };
+
+        MonsterSkillComponent {
+            state: MonsterSkillState::Ready,
+            cooldown: Timer::new(Duration::from_secs_f32(skill.cooldown()), TimerMode::Once),
+            telegraph: Timer::new(Duration::from_secs_f32(skill.telegraph()), TimerMode::Once),
+            duration: Timer::new(Duration::from_secs_f32(duration), TimerMode::Once),
+            target: Vec2::ZERO,
+            direction: Vec2::ZERO,
+            skill,
+        }
+    }
+
+    pub fn is_busy(&self) -> bool {
+        self.state != MonsterSkillState::Ready
+    }
+
+    pub fn finish(&mut self) {
+        self.state = MonsterSkillState::Ready;
+        self.cooldown.reset();
+    }
+}
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use bevy_inspector_egui::InspectorOptions;
+use serde::{Deserialize, Serialize};
 
 use crate::resources::game_data::GameData;
 use crate::resources::hero::hero_class::HeroClass;
@@ -7,7 +8,7 @@ use crate::resources::hero::power::Power;
 use crate::resources::hero::stats::Stats;
 use crate::resources::upgrade::Upgrade;
 
-#[derive(Component, InspectorOptions)]
+#[derive(Component, InspectorOptions, Serialize, Deserialize, Clone)]
 pub struct PlayerComponent {
     pub class: HeroClass,
     pub current_health_points: f32,
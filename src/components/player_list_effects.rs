@@ -10,6 +10,8 @@ use crate::resources::upgrade::Upgrade;
 pub struct PlayerListEffectsComponent {
     pub information: Vec<Effect>,
     pub activated_effects: HashMap<EffectType, Timer>,
+    // bonuses brought by whatever activated the effect, e.g. a weapon buff
+    pub bonuses: HashMap<EffectType, f32>,
 }
 
 impl PlayerListEffectsComponent {
@@ -17,6 +19,7 @@ impl PlayerListEffectsComponent {
         PlayerListEffectsComponent {
             information,
             activated_effects: HashMap::new(),
+            bonuses: HashMap::new(),
         }
     }
 
@@ -53,7 +56,7 @@ impl PlayerListEffectsComponent {
     pub fn activate(&mut self, effect_type: EffectType) {
         let information = self
             .information
-            .iter_mut()
+            .iter()
             .find(|effect_information| effect_information.name == effect_type)
             .unwrap();
 
@@ -64,5 +67,31 @@ impl PlayerListEffectsComponent {
                 TimerMode::Once,
             ),
         );
+        self.bonuses.remove(&effect_type);
+    }
+
+    pub fn activate_with_bonus(&mut self, effect_type: EffectType, bonus: f32) {
+        self.activate(effect_type);
+        self.bonuses.insert(effect_type, bonus);
+    }
+
+    pub fn is_active(&self, effect_type: EffectType) -> bool {
+        self.activated_effects
+            .get(&effect_type)
+            .is_some_and(|duration| !duration.finished())
+    }
+
+    // falls back to the effect information bonus, which is what potions and monsters give
+    pub fn bonus(&self, effect_type: EffectType) -> f32 {
+        match self.bonuses.get(&effect_type) {
+            Some(bonus) => *bonus,
+            None => {
+                self.information
+                    .iter()
+                    .find(|effect_information| effect_information.name == effect_type)
+                    .unwrap()
+                    .bonus
+            }
+        }
     }
 }
@@ -5,6 +5,4 @@ use crate::resources::potion::potion_type::PotionType;
 #[derive(Component)]
 pub struct PotionComponent {
     pub potion_type: PotionType,
-    pub height: f32,
-    pub width: f32,
 }
@@ -5,7 +5,7 @@ use crate::resources::weapon::Weapon;
 use crate::resources::weapon::attack_type::AttackType;
 use crate::resources::weapon::weapon_type::WeaponType;
 
-#[derive(Component, Copy, Clone)]
+#[derive(Component, Clone)]
 pub struct WeaponComponent {
     pub attack_type: AttackType,
     pub name: WeaponType,
@@ -18,32 +18,34 @@ pub struct WeaponComponent {
     pub buff_effect: Option<EffectType>,
     pub debuff_effect: Option<EffectType>,
     pub trigger_chance: f32,
+    pub effect_bonus: Option<f32>,
+    pub shoot_while_moving: bool,
+    pub aim_rotation: Option<f32>,
 }
 
 impl WeaponComponent {
+    pub fn new(weapon: &Weapon) -> Self {
+        let effect = weapon.effect;
+
+        WeaponComponent {
+            attack_type: weapon.attack_type,
+            name: weapon.name.clone(),
+            level: weapon.level,
+            size_height: weapon.height,
+            size_width: weapon.width,
+            scale: weapon.scale,
+            strength: weapon.strength,
+            intelligence: weapon.intelligence,
+            buff_effect: effect.filter(|effect| effect.is_buff()),
+            debuff_effect: effect.filter(|effect| !effect.is_buff()),
+            trigger_chance: weapon.trigger_chance.unwrap_or(0.0),
+            effect_bonus: weapon.effect_bonus,
+            shoot_while_moving: weapon.shoot_while_moving.unwrap_or(false),
+            aim_rotation: weapon.aim_rotation,
+        }
+    }
+
     pub fn upgrade_weapon(&mut self, weapon: &Weapon) {
-        self.strength = weapon.strength;
-        self.intelligence = weapon.intelligence;
-        self.attack_type = weapon.attack_type.clone();
-        self.name = weapon.name.clone();
-        self.level = weapon.level;
-        self.size_width = weapon.width;
-        self.size_height = weapon.height;
-        self.scale = weapon.scale;
-        self.buff_effect = if weapon.name == WeaponType::Spear {
-            Some(EffectType::SpeedUp)
-        } else {
-            None
-        };
-        self.debuff_effect = if weapon.name == WeaponType::BigHammer {
-            Some(EffectType::Stun)
-        } else if weapon.name == WeaponType::BigMachete {
-            Some(EffectType::ReduceDamage)
-        } else if weapon.name == WeaponType::MagicWand {
-            Some(EffectType::Slow)
-        } else {
-            None
-        };
-        self.trigger_chance = weapon.trigger_chance.unwrap_or(0.0);
+        *self = WeaponComponent::new(weapon);
     }
 }
@@ -16,11 +16,13 @@ pub struct WeaponShootAttackComponent {
 
 impl WeaponShootAttackComponent {
     pub fn upgrade(&mut self, weapon: &Weapon) {
-        self.bullet_information = weapon.bullet.unwrap_or(Bullet {
+        self.bullet_information = weapon.bullet.clone().unwrap_or(Bullet {
             width: 0.0,
             height: 0.0,
             speed: 0.0,
             scale: 0.0,
+            sprite: None,
+            color: None,
         });
         self.bullet_target_x = 0.0;
         self.bullet_target_y = 0.0;
@@ -13,14 +13,19 @@ pub const SUB_MENU_BACKGROUND_IMAGE: &str = "images/sub_menu_background.png";
 pub const FIBBERISH_FONT: &str = "fonts/fibberish.ttf";
 pub const ROBOTO_FONT: &str = "fonts/Roboto-Bold.ttf";
 
+// the asset server reads from here, headless runs and the tools read the same files
+pub const ASSETS_DIRECTORY: &str = "assets";
+
 pub const VIETNAMESE_LANGUAGE_FILE: &str = "vietnamese.json";
 pub const ENGLISH_LANGUAGE_FILE: &str = "english.json";
 pub const HIGHSCORE_FILE: &str = "highscores.json";
 pub const SETTING_FILE: &str = "setting.json";
+pub const SAVED_RUN_FILE: &str = "saved_run.json";
+pub const SAVED_SURVIVAL_RUN_FILE: &str = "saved_survival_run.json";
 pub const CREDITS_FILE: &str = "credits.txt";
 pub const DATA_FILE: &str = "data.json";
-pub const LIST_FLOOR_FILE: &str = "list_floor.txt";
 pub const LIST_ROOM_FILE: &str = "list_room.txt";
 pub const UPGRADES_FILE: &str = "upgrades.json";
+pub const MODS_DIRECTORY: &str = "mods";
 
 pub const TILE_SIZE: f32 = 64.0;
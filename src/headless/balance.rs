@@ -0,0 +1,459 @@
+use bevy::prelude::*;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::thread;
+
+use crate::components::weapon::WeaponComponent;
+use crate::components::weapon_shoot_attack::WeaponShootAttackComponent;
+use crate::components::weapon_swing_attack::WeaponSwingAttackComponent;
+use crate::headless::{
+    FRAMES_PER_SECOND, HeadlessOptions, headless_app, is_run_over, load_game_data,
+    parse_game_mode, parse_hero_class, step_run,
+};
+use crate::plugins::player::{PlayerHurtEvent, UpgradePickedEvent};
+use crate::resources::dungeon::wave::Wave;
+use crate::resources::game_mode::GameMode;
+use crate::resources::hero::hero_class::HeroClass;
+use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
+use crate::resources::profile::Profile;
+use crate::resources::weapon::Weapon;
+use crate::scenes::SceneState;
+
+const DEFAULT_RUNS: u64 = 20;
+const DEFAULT_FIRST_SEED: u64 = 1;
+// the run gets in game within a couple of frames, the weapon is swapped then
+const MAX_START_FRAMES: u64 = 10;
+
+pub struct BalanceOptions {
+    pub game_modes: Vec<GameMode>,
+    pub hero_classes: Vec<HeroClass>,
+    pub runs: u64,
+    pub first_seed: u64,
+    pub max_frames: u64,
+    pub json_path: Option<String>,
+    pub csv_path: Option<String>,
+}
+
+impl Default for BalanceOptions {
+    fn default() -> Self {
+        BalanceOptions {
+            game_modes: vec![GameMode::ClassicMode, GameMode::SurvivalMode],
+            hero_classes: load_game_data()
+                .get_heroes()
+                .into_iter()
+                .map(|hero| hero.hero_class)
+                .collect(),
+            runs: DEFAULT_RUNS,
+            first_seed: DEFAULT_FIRST_SEED,
+            max_frames: HeadlessOptions::default().max_frames,
+            json_path: None,
+            csv_path: None,
+        }
+    }
+}
+
+pub const USAGE: &str = "usage: balance-sim [--mode classic|survival] \
+    [--hero <hero>] [--runs <number>] [--seed <first seed>] \
+    [--frames <number>] [--json <path>] [--csv <path>]\n\
+    every mode, hero and weapon is played --runs times (20 by default) for up to --frames \
+    frames, the runs are spread over the cores but a full sweep still takes minutes";
+
+impl BalanceOptions {
+    // every mode and hero is played without --mode and --hero, the json goes to stdout
+    // without a path, see USAGE for the rest
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut options = BalanceOptions::default();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("{} needs a value", arg))?;
+
+            match arg.as_str() {
+                "--mode" => options.game_modes = vec![parse_game_mode(value)?],
+                "--hero" => options.hero_classes = vec![parse_hero_class(value)?],
+                "--runs" => options.runs = parse_number(value)?,
+                "--seed" => options.first_seed = parse_number(value)?,
+                "--frames" => options.max_frames = parse_number(value)?,
+                "--json" => options.json_path = Some(value.clone()),
+                "--csv" => options.csv_path = Some(value.clone()),
+                _ => return Err(format!("{} is not a balance-sim argument", arg)),
+            }
+        }
+
+        if options.runs == 0 {
+            return Err("--runs has to be at least 1".to_string());
+        }
+
+        Ok(options)
+    }
+}
+
+fn parse_number(value: &str) -> Result<u64, String> {
+    value
+        .parse::<u64>()
+        .map_err(|_| format!("{} is not a number", value))
+}
+
+// what happened during one run that the profile does not keep
+#[derive(Resource, Default)]
+struct RunStats {
+    floor_reached: usize,
+    wave_reached: usize,
+    damage_taken: BTreeMap<String, f32>,
+    upgrades: Vec<String>,
+}
+
+fn collect_run_stats(
+    mut run_stats: ResMut<RunStats>,
+    mut hurt_events: EventReader<PlayerHurtEvent>,
+    mut upgrade_events: EventReader<UpgradePickedEvent>,
+    player_dungeon_stats: Option<Res<PlayerDungeonStats>>,
+    wave: Option<Res<Wave>>,
+) {
+    for event in hurt_events.read() {
+        *run_stats
+            .damage_taken
+            .entry(format!("{:?}", event.monster_class))
+            .or_insert(0.0) += event.amount;
+    }
+
+    for event in upgrade_events.read() {
+        run_stats.upgrades.push(event.name.clone());
+    }
+
+    if let Some(player_dungeon_stats) = player_dungeon_stats {
+        run_stats.floor_reached = run_stats
+            .floor_reached
+            .max(player_dungeon_stats.current_floor_index + 1);
+    }
+
+    if let Some(wave) = wave {
+        run_stats.wave_reached = run_stats.wave_reached.max(wave.wave_number);
+    }
+}
+
+#[derive(Serialize)]
+pub struct RunReport {
+    pub mode: String,
+    pub hero: String,
+    pub weapon: String,
+    pub seed: u64,
+    pub frames: u64,
+    pub seconds: f64,
+    pub finished: bool,
+    pub completed: bool,
+    pub killed_monsters: usize,
+    pub floor_reached: Option<usize>,
+    pub waves_survived: Option<usize>,
+    pub damage_taken: BTreeMap<String, f32>,
+    pub upgrades: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct Spread {
+    pub min: f64,
+    pub mean: f64,
+    pub max: f64,
+}
+
+// the runs of one mode, hero and starting weapon put together
+#[derive(Serialize)]
+pub struct SetupSummary {
+    pub mode: String,
+    pub hero: String,
+    pub weapon: String,
+    pub runs: usize,
+    pub completed_runs: usize,
+    // how many runs ended on each floor or after each number of waves
+    pub floors_reached: BTreeMap<usize, usize>,
+    pub waves_survived: BTreeMap<usize, usize>,
+    // seconds of game time, completed runs only
+    pub time_to_clear: Option<Spread>,
+    // per run on average
+    pub damage_taken: BTreeMap<String, f32>,
+    pub upgrades_picked: BTreeMap<String, usize>,
+}
+
+#[derive(Serialize)]
+pub struct BalanceReport {
+    pub runs_per_setup: u64,
+    pub first_seed: u64,
+    pub max_frames: u64,
+    pub summaries: Vec<SetupSummary>,
+    pub runs: Vec<RunReport>,
+}
+
+pub fn run(options: BalanceOptions) {
+    let report = simulate(&options);
+
+    let json = serde_json::to_string_pretty(&report).expect("Report can't be serialized");
+    match &options.json_path {
+        Some(path) => fs::write(path, json).expect("Can't write the json report"),
+        None if options.csv_path.is_none() => println!("{}", json),
+        None => {}
+    }
+
+    if let Some(path) = &options.csv_path {
+        fs::write(path, to_csv(&report.runs)).expect("Can't write the csv report");
+    }
+}
+
+// every mode, hero and weapon of the hero progression played from the same seeds
+pub fn simulate(options: &BalanceOptions) -> BalanceReport {
+    let mut summaries = Vec::new();
+    let mut runs = Vec::new();
+    let game_data = load_game_data();
+
+    for game_mode in options.game_modes.iter() {
+        for hero_class in options.hero_classes.iter() {
+            for weapon in game_data.get_weapons(hero_class.clone()) {
+                let setup_runs = play_seeds(options, game_mode, hero_class, &weapon);
+                summaries.push(summarize(&setup_runs));
+                runs.extend(setup_runs);
+            }
+        }
+    }
+
+    BalanceReport {
+        runs_per_setup: options.runs,
+        first_seed: options.first_seed,
+        max_frames: options.max_frames,
+        summaries,
+        runs,
+    }
+}
+
+// every run is an app of its own, so the seeds of a setup are played on all cores and
+// the reports come back in seed order
+fn play_seeds(
+    options: &BalanceOptions,
+    game_mode: &GameMode,
+    hero_class: &HeroClass,
+    weapon: &Weapon,
+) -> Vec<RunReport> {
+    let seeds: Vec<u64> = (0..options.runs)
+        .map(|index| options.first_seed + index)
+        .collect();
+    let threads = thread::available_parallelism().map_or(1, |threads| threads.get());
+    let chunk_size = seeds.len().div_ceil(threads);
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = seeds
+            .chunks(chunk_size)
+            .map(|seeds| {
+                scope.spawn(move || {
+                    seeds
+                        .iter()
+                        .map(|seed| {
+                            let headless_options = HeadlessOptions {
+                                game_mode: game_mode.clone(),
+                                hero_class: hero_class.clone(),
+                                seed: Some(*seed),
+                                max_frames: options.max_frames,
+                                ..Default::default()
+                            };
+                            play(&headless_options, weapon)
+                        })
+                        .collect::<Vec<RunReport>>()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("A balance run panicked"))
+            .collect()
+    })
+}
+
+fn play(options: &HeadlessOptions, weapon: &Weapon) -> RunReport {
+    let mut app = headless_app(options);
+    app.init_resource::<RunStats>();
+    app.add_systems(Last, collect_run_stats);
+
+    let mut frames = 0;
+    while frames < MAX_START_FRAMES && !is_in_game(&app) {
+        app.update();
+        frames += 1;
+    }
+    equip_weapon(&mut app, weapon);
+    frames += step_run(&mut app, options.max_frames.saturating_sub(frames));
+
+    let world = app.world();
+    let profile = world.resource::<Profile>();
+    let run_stats = world.resource::<RunStats>();
+
+    RunReport {
+        mode: format!("{:?}", profile.game_mode),
+        hero: profile.hero_class.to_string(),
+        weapon: weapon.name.to_string(),
+        seed: profile.seed,
+        frames,
+        seconds: frames as f64 / FRAMES_PER_SECOND,
+        finished: is_run_over(&app),
+        completed: profile.is_run_completed,
+        killed_monsters: profile.total_killed_monsters,
+        floor_reached: match profile.game_mode {
+            GameMode::ClassicMode => Some(run_stats.floor_reached),
+            GameMode::SurvivalMode => None,
+        },
+        waves_survived: match profile.game_mode {
+            GameMode::ClassicMode => None,
+            GameMode::SurvivalMode => Some(run_stats.wave_reached.saturating_sub(1)),
+        },
+        damage_taken: run_stats.damage_taken.clone(),
+        upgrades: run_stats.upgrades.clone(),
+    }
+}
+
+fn is_in_game(app: &App) -> bool {
+    matches!(
+        app.world().resource::<State<SceneState>>().get(),
+        SceneState::InGameClassicMode | SceneState::InGameSurvivalMode
+    )
+}
+
+// the run starts with a later weapon of the progression, like after picking it as a reward
+fn equip_weapon(app: &mut App, weapon: &Weapon) {
+    let mut weapon_query = app.world_mut().query::<(
+        &mut WeaponComponent,
+        &mut WeaponSwingAttackComponent,
+        &mut WeaponShootAttackComponent,
+    )>();
+    let (mut weapon_component, mut swing_attack, mut shoot_attack) = weapon_query
+        .single_mut(app.world_mut())
+        .expect("The run has no weapon");
+
+    if weapon_component.level != weapon.level {
+        weapon_component.upgrade_weapon(weapon);
+        swing_attack.upgrade(weapon);
+        shoot_attack.upgrade(weapon);
+    }
+}
+
+fn summarize(runs: &[RunReport]) -> SetupSummary {
+    let mut floors_reached = BTreeMap::new();
+    let mut waves_survived = BTreeMap::new();
+    let mut damage_taken = BTreeMap::new();
+    let mut upgrades_picked = BTreeMap::new();
+
+    for run in runs.iter() {
+        if let Some(floor) = run.floor_reached {
+            *floors_reached.entry(floor).or_insert(0) += 1;
+        }
+        if let Some(waves) = run.waves_survived {
+            *waves_survived.entry(waves).or_insert(0) += 1;
+        }
+        for (monster_class, amount) in run.damage_taken.iter() {
+            *damage_taken.entry(monster_class.clone()).or_insert(0.0) += amount / runs.len() as f32;
+        }
+        for upgrade in run.upgrades.iter() {
+            *upgrades_picked.entry(upgrade.clone()).or_insert(0) += 1;
+        }
+    }
+
+    let clear_times: Vec<f64> = runs
+        .iter()
+        .filter(|run| run.completed)
+        .map(|run| run.seconds)
+        .collect();
+
+    let first = &runs[0];
+    SetupSummary {
+        mode: first.mode.clone(),
+        hero: first.hero.clone(),
+        weapon: first.weapon.clone(),
+        runs: runs.len(),
+        completed_runs: clear_times.len(),
+        floors_reached,
+        waves_survived,
+        time_to_clear: spread(&clear_times),
+        damage_taken,
+        upgrades_picked,
+    }
+}
+
+fn spread(values: &[f64]) -> Option<Spread> {
+    if values.is_empty() {
+        return None;
+    }
+
+    Some(Spread {
+        min: values.iter().cloned().fold(f64::INFINITY, f64::min),
+        mean: values.iter().sum::<f64>() / values.len() as f64,
+        max: values.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+    })
+}
+
+// one row per run, a damage column for every monster class that hit someone and the
+// picked upgrades in one column separated by ;
+pub fn to_csv(runs: &[RunReport]) -> String {
+    let mut monster_classes: Vec<&String> = runs
+        .iter()
+        .flat_map(|run| run.damage_taken.keys())
+        .collect();
+    monster_classes.sort();
+    monster_classes.dedup();
+
+    let mut header = vec![
+        "mode",
+        "hero",
+        "weapon",
+        "seed",
+        "frames",
+        "seconds",
+        "finished",
+        "completed",
+        "killed_monsters",
+        "floor_reached",
+        "waves_survived",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect::<Vec<String>>();
+    header.extend(
+        monster_classes
+            .iter()
+            .map(|monster_class| format!("damage_{}", monster_class)),
+    );
+    header.push("upgrades".to_string());
+
+    let mut csv = header.join(",");
+    csv.push('\n');
+
+    for run in runs.iter() {
+        let mut row = vec![
+            run.mode.clone(),
+            run.hero.clone(),
+            run.weapon.clone(),
+            run.seed.to_string(),
+            run.frames.to_string(),
+            format!("{:.2}", run.seconds),
+            run.finished.to_string(),
+            run.completed.to_string(),
+            run.killed_monsters.to_string(),
+            run.floor_reached
+                .map(|floor| floor.to_string())
+                .unwrap_or_default(),
+            run.waves_survived
+                .map(|waves| waves.to_string())
+                .unwrap_or_default(),
+        ];
+        row.extend(monster_classes.iter().map(|monster_class| {
+            run.damage_taken
+                .get(*monster_class)
+                .cloned()
+                .unwrap_or(0.0)
+                .to_string()
+        }));
+        row.push(format!("\"{}\"", run.upgrades.join(";")));
+
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
@@ -0,0 +1,253 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::{HashMap, VecDeque};
+
+use crate::components::monster::MonsterComponent;
+use crate::components::player::PlayerComponent;
+use crate::components::skill::SkillComponent;
+use crate::components::weapon::WeaponComponent;
+use crate::plugins::survival_mode::dungeon::wave::RewardsSceneButton;
+use crate::plugins::weapon::AimTarget;
+use crate::resources::dungeon::Dungeon;
+use crate::resources::dungeon::door::Door;
+use crate::resources::dungeon::end_point::EndPoint;
+use crate::resources::dungeon::position::Position;
+use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
+use crate::resources::weapon::attack_type::AttackType;
+
+const MELEE_RANGE: f32 = 40.0;
+const SHOOT_MIN_RANGE: f32 = 150.0;
+const SHOOT_MAX_RANGE: f32 = 400.0;
+const SKILL_RANGE: f32 = 200.0;
+
+// how far past a door the bot walks, so it keeps pushing until the room changes
+const DOOR_OVERSHOOT: f32 = 60.0;
+
+const STUCK_FRAMES: u32 = 30;
+const SIDESTEP_FRAMES: u32 = 30;
+
+// a simple player for headless runs, fights the closest monster and walks to the end
+// room once a room is cleared
+#[derive(Resource)]
+pub struct Bot {
+    rng: StdRng,
+    last_position: Vec2,
+    stuck_frames: u32,
+    sidestep_frames: u32,
+    sidestep: Vec2,
+}
+
+impl Bot {
+    pub fn new(seed: u64) -> Self {
+        Bot {
+            rng: StdRng::seed_from_u64(seed),
+            last_position: Vec2::ZERO,
+            stuck_frames: 0,
+            sidestep_frames: 0,
+            sidestep: Vec2::ZERO,
+        }
+    }
+}
+
+// everything the bot looks at before pressing buttons
+#[derive(SystemParam)]
+pub struct BotView<'w, 's> {
+    player_query:
+        Query<'w, 's, (&'static GlobalTransform, &'static SkillComponent), With<PlayerComponent>>,
+    weapon_query: Query<'w, 's, &'static WeaponComponent>,
+    monster_query: Query<'w, 's, &'static GlobalTransform, With<MonsterComponent>>,
+    door_query: Query<'w, 's, (&'static Door, &'static GlobalTransform)>,
+    end_point_query: Query<'w, 's, &'static GlobalTransform, With<EndPoint>>,
+    player_dungeon_stats: Option<Res<'w, PlayerDungeonStats>>,
+    dungeon: Option<Res<'w, Dungeon>>,
+}
+
+pub fn bot_input(
+    mut bot: ResMut<Bot>,
+    mut keyboard_input: ResMut<ButtonInput<KeyCode>>,
+    mut mouse_input: ResMut<ButtonInput<MouseButton>>,
+    mut aim_target: ResMut<AimTarget>,
+    mut reward_query: Query<(&RewardsSceneButton, &mut Interaction)>,
+    view: BotView,
+) {
+    keyboard_input.release_all();
+    mouse_input.release_all();
+
+    // survival rewards, picked at random
+    if !reward_query.is_empty() {
+        let choice = bot.rng.gen_range(0..reward_query.iter().len());
+        if let Some((_, mut interaction)) = reward_query.iter_mut().nth(choice) {
+            *interaction = Interaction::Pressed;
+        }
+        return;
+    }
+
+    let (Ok((player_transform, skill)), Ok(weapon)) =
+        (view.player_query.single(), view.weapon_query.single())
+    else {
+        return;
+    };
+    let player_position = player_transform.translation().truncate();
+
+    let closest_monster = view
+        .monster_query
+        .iter()
+        .map(|transform| transform.translation().truncate())
+        .min_by(|a, b| {
+            a.distance(player_position)
+                .total_cmp(&b.distance(player_position))
+        });
+
+    let mut direction = Vec2::ZERO;
+
+    if let Some(monster_position) = closest_monster {
+        let distance = monster_position.distance(player_position);
+        let towards_monster = (monster_position - player_position).normalize_or_zero();
+
+        aim_target.position = Some(monster_position);
+        mouse_input.press(MouseButton::Left);
+        // passive skills have no cooldown and are never cast
+        if distance < SKILL_RANGE && skill.skill.cooldown.is_some() {
+            keyboard_input.press(KeyCode::Space);
+        }
+
+        direction = match weapon.attack_type {
+            AttackType::Swing if distance > MELEE_RANGE => towards_monster,
+            AttackType::Swing => Vec2::ZERO,
+            AttackType::Shoot if distance < SHOOT_MIN_RANGE => -towards_monster,
+            AttackType::Shoot if distance > SHOOT_MAX_RANGE => towards_monster,
+            // shooting mostly needs the hero to stand still
+            AttackType::Shoot => Vec2::ZERO,
+        };
+    } else if let Some(target) = exit_target(&view) {
+        direction = (target - player_position).normalize_or_zero();
+    }
+
+    if direction != Vec2::ZERO {
+        if bot.sidestep_frames > 0 {
+            bot.sidestep_frames -= 1;
+            direction = bot.sidestep;
+        } else if player_position.distance(bot.last_position) < 0.5 {
+            bot.stuck_frames += 1;
+            if bot.stuck_frames >= STUCK_FRAMES {
+                bot.stuck_frames = 0;
+                bot.sidestep_frames = SIDESTEP_FRAMES;
+                bot.sidestep = if bot.rng.gen_bool(0.5) {
+                    direction.perp()
+                } else {
+                    -direction.perp()
+                };
+            }
+        } else {
+            bot.stuck_frames = 0;
+        }
+    }
+    bot.last_position = player_position;
+
+    press_direction(&mut keyboard_input, direction);
+}
+
+// where to walk after the room is cleared, the end point in the end room, otherwise
+// the door on the shortest way to the end room
+fn exit_target(view: &BotView) -> Option<Vec2> {
+    let player_dungeon_stats = view.player_dungeon_stats.as_ref()?;
+    let dungeon = view.dungeon.as_ref()?;
+    if !player_dungeon_stats.is_room_cleared {
+        return None;
+    }
+
+    let current_position = player_dungeon_stats.current_room_position;
+    let end_room_position = dungeon.current_floor.end_room_position;
+
+    if current_position == end_room_position {
+        return view
+            .end_point_query
+            .single()
+            .ok()
+            .map(|transform| transform.translation().truncate());
+    }
+
+    let next_door = next_door(
+        &dungeon.current_floor.map,
+        current_position,
+        end_room_position,
+    )?;
+    let (_, door_transform) = view
+        .door_query
+        .iter()
+        .find(|(door, _)| **door == next_door)?;
+
+    let outwards = match next_door {
+        Door::Left => Vec2::NEG_X,
+        Door::Right => Vec2::X,
+        Door::Top => Vec2::Y,
+        Door::Bottom => Vec2::NEG_Y,
+    };
+
+    Some(door_transform.translation().truncate() + outwards * DOOR_OVERSHOOT)
+}
+
+// breadth first search over the rooms of the floor, returns the door of the first step
+fn next_door(map: &[Vec<f32>], start: Position, end: Position) -> Option<Door> {
+    let is_room = |row: usize, column: usize| {
+        map.get(row)
+            .and_then(|rooms| rooms.get(column))
+            .is_some_and(|room| *room != 0.0)
+    };
+
+    let mut first_doors: HashMap<Position, Door> = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(position) = queue.pop_front() {
+        if position == end {
+            return first_doors.get(&position).cloned();
+        }
+
+        let mut neighbours = vec![
+            (Door::Right, position.row_index, position.column_index + 1),
+            (Door::Bottom, position.row_index + 1, position.column_index),
+        ];
+        if position.column_index > 0 {
+            neighbours.push((Door::Left, position.row_index, position.column_index - 1));
+        }
+        if position.row_index > 0 {
+            neighbours.push((Door::Top, position.row_index - 1, position.column_index));
+        }
+
+        for (door, row_index, column_index) in neighbours {
+            let neighbour = Position {
+                row_index,
+                column_index,
+            };
+            if neighbour == start
+                || first_doors.contains_key(&neighbour)
+                || !is_room(row_index, column_index)
+            {
+                continue;
+            }
+
+            let first_door = first_doors.get(&position).cloned().unwrap_or(door);
+            first_doors.insert(neighbour, first_door);
+            queue.push_back(neighbour);
+        }
+    }
+
+    None
+}
+
+fn press_direction(keyboard_input: &mut ButtonInput<KeyCode>, direction: Vec2) {
+    // small components are dropped so the bot does not zigzag along an axis
+    if direction.x > 0.3 {
+        keyboard_input.press(KeyCode::KeyD);
+    } else if direction.x < -0.3 {
+        keyboard_input.press(KeyCode::KeyA);
+    }
+
+    if direction.y > 0.3 {
+        keyboard_input.press(KeyCode::KeyW);
+    } else if direction.y < -0.3 {
+        keyboard_input.press(KeyCode::KeyS);
+    }
+}
@@ -0,0 +1,261 @@
+use bevy::input::{InputPlugin, InputSystem};
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+use crate::materials::font::FontMaterials;
+use crate::materials::ingame::InGameMaterials;
+use crate::materials::scenes::ScenesMaterials;
+use crate::plugins::GameplayPlugins;
+use crate::plugins::content::load_data;
+use crate::plugins::sound::SoundEffect;
+use crate::resources::dictionary::Dictionary;
+use crate::resources::dungeon::wave::Wave;
+use crate::resources::game_data::GameData;
+use crate::resources::game_mode::GameMode;
+use crate::resources::hero::gender::Gender;
+use crate::resources::hero::hero_class::HeroClass;
+use crate::resources::language::Language;
+use crate::resources::mod_packs::ModPacks;
+use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
+use crate::resources::profile::Profile;
+use crate::scenes::SceneState;
+
+pub mod balance;
+pub mod bot;
+pub mod script;
+
+use bot::Bot;
+use script::InputScript;
+
+pub const FRAMES_PER_SECOND: f64 = 60.0;
+
+// ten minutes of game time
+const DEFAULT_MAX_FRAMES: u64 = 36000;
+
+// present while the game runs without a window, render only systems and saving skip
+// themselves when they see it
+#[derive(Resource)]
+pub struct HeadlessFlag;
+
+pub enum HeadlessInput {
+    Bot,
+    Script(InputScript),
+    // nothing presses buttons, whoever steps the app sets the button inputs
+    Manual,
+}
+
+pub struct HeadlessOptions {
+    pub game_mode: GameMode,
+    pub hero_class: HeroClass,
+    pub gender: Gender,
+    pub seed: Option<u64>,
+    pub max_frames: u64,
+    pub input: HeadlessInput,
+}
+
+impl Default for HeadlessOptions {
+    fn default() -> Self {
+        HeadlessOptions {
+            game_mode: GameMode::ClassicMode,
+            hero_class: HeroClass::new("Knight"),
+            gender: Gender::Male,
+            seed: None,
+            max_frames: DEFAULT_MAX_FRAMES,
+            input: HeadlessInput::Bot,
+        }
+    }
+}
+
+pub const USAGE: &str = "usage: dungeon-quest-v2 --headless [--mode classic|survival] \
+    [--hero <hero>] [--gender male|female] [--seed <number>] \
+    [--frames <number>] [--script <path>]";
+
+impl HeadlessOptions {
+    // the bot plays without --script, see USAGE for the rest
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut options = HeadlessOptions::default();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            if arg == "--headless" {
+                continue;
+            }
+
+            let value = args
+                .next()
+                .ok_or_else(|| format!("{} needs a value", arg))?;
+
+            match arg.as_str() {
+                "--mode" => options.game_mode = parse_game_mode(value)?,
+                "--hero" => options.hero_class = parse_hero_class(value)?,
+                "--gender" => options.gender = parse_gender(value)?,
+                "--seed" => {
+                    options.seed = Some(
+                        value
+                            .parse::<u64>()
+                            .map_err(|_| format!("{} is not a seed", value))?,
+                    )
+                }
+                "--frames" => {
+                    options.max_frames = value
+                        .parse::<u64>()
+                        .map_err(|_| format!("{} is not a number of frames", value))?
+                }
+                "--script" => options.input = HeadlessInput::Script(InputScript::load(value)?),
+                _ => return Err(format!("unknown argument {}", arg)),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+pub fn parse_game_mode(value: &str) -> Result<GameMode, String> {
+    match value.to_lowercase().as_str() {
+        "classic" => Ok(GameMode::ClassicMode),
+        "survival" => Ok(GameMode::SurvivalMode),
+        _ => Err(format!("{} is not a game mode", value)),
+    }
+}
+
+// any hero of the game data, the case of its id does not matter
+pub fn parse_hero_class(value: &str) -> Result<HeroClass, String> {
+    load_game_data()
+        .get_heroes()
+        .into_iter()
+        .map(|hero| hero.hero_class)
+        .find(|hero_class| hero_class.id().eq_ignore_ascii_case(value))
+        .ok_or(format!("{} is not a hero class", value))
+}
+
+// the game data with the packs applied, the same way the runs load it, so heroes and
+// weapon progressions from mod packs can be played too
+pub fn load_game_data() -> GameData {
+    let mut mod_packs = ModPacks::new();
+    let contents = mod_packs.read_content();
+
+    let mut game_data = GameData::new();
+    mod_packs.apply_game_data(&mut game_data, &contents);
+    game_data
+}
+
+pub fn parse_gender(value: &str) -> Result<Gender, String> {
+    match value.to_lowercase().as_str() {
+        "male" => Ok(Gender::Male),
+        "female" => Ok(Gender::Female),
+        _ => Err(format!("{} is not a gender", value)),
+    }
+}
+
+// the gameplay plugins on top of minimal plugins, time advances by exactly one frame
+// on every update so a seed and an input always give the same run
+pub fn headless_app(options: &HeadlessOptions) -> App {
+    let mut profile = Profile::new();
+    profile.game_mode = options.game_mode.clone();
+    profile.hero_class = options.hero_class.clone();
+    profile.gender = options.gender.clone();
+    if let Some(seed) = options.seed {
+        profile.seed = seed;
+    }
+
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        StatesPlugin,
+        AssetPlugin::default(),
+        InputPlugin,
+        TransformPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+        1.0 / FRAMES_PER_SECOND,
+    )))
+    .init_asset::<TextureAtlasLayout>()
+    .add_event::<SoundEffect>()
+    .insert_resource(Dictionary::new(Language::EN))
+    .init_resource::<FontMaterials>()
+    .init_resource::<ScenesMaterials>()
+    .init_resource::<InGameMaterials>()
+    .insert_resource(HeadlessFlag)
+    .insert_resource(Bot::new(profile.seed))
+    .insert_resource(profile)
+    .init_state::<SceneState>()
+    .add_plugins(GameplayPlugins)
+    .add_systems(Startup, (load_data, start_run).chain());
+
+    match &options.input {
+        HeadlessInput::Bot => {
+            app.add_systems(
+                PreUpdate,
+                bot::bot_input.after(InputSystem).run_if(
+                    in_state(SceneState::InGameClassicMode)
+                        .or(in_state(SceneState::InGameSurvivalMode)),
+                ),
+            );
+        }
+        HeadlessInput::Script(script) => {
+            app.insert_resource(script.clone());
+            app.add_systems(PreUpdate, script::script_input.after(InputSystem));
+        }
+        HeadlessInput::Manual => {}
+    }
+
+    app.finish();
+    app.cleanup();
+    app
+}
+
+fn start_run(profile: Res<Profile>, mut state: ResMut<NextState<SceneState>>) {
+    match profile.game_mode {
+        GameMode::ClassicMode => state.set(SceneState::PreClassicMode),
+        GameMode::SurvivalMode => state.set(SceneState::PreSurvivalMode),
+    }
+}
+
+pub fn is_run_over(app: &App) -> bool {
+    *app.world().resource::<State<SceneState>>().get() == SceneState::ResultScene
+}
+
+// steps the app until the run ends or the frame budget is used up, returns the
+// number of frames it took
+pub fn step_run(app: &mut App, max_frames: u64) -> u64 {
+    let mut frames = 0;
+    while frames < max_frames && !is_run_over(app) {
+        app.update();
+        frames += 1;
+    }
+    frames
+}
+
+pub fn run(options: HeadlessOptions) {
+    let mut app = headless_app(&options);
+    let frames = step_run(&mut app, options.max_frames);
+
+    let world = app.world();
+    let profile = world.resource::<Profile>();
+
+    println!("mode: {:?}", profile.game_mode);
+    println!("hero: {} {:?}", profile.hero_class, profile.gender);
+    println!("seed: {}", profile.seed);
+    println!("frames: {}", frames);
+    println!("seconds: {:.2}", frames as f64 / FRAMES_PER_SECOND);
+    println!("finished: {}", profile.is_run_finished);
+    println!("completed: {}", profile.is_run_completed);
+    println!("killed monsters: {}", profile.total_killed_monsters);
+
+    match profile.game_mode {
+        GameMode::ClassicMode => {
+            println!("cleared rooms: {}", profile.total_cleared_rooms);
+            if let Some(player_dungeon_stats) = world.get_resource::<PlayerDungeonStats>() {
+                println!("floor: {}", player_dungeon_stats.current_floor_index + 1);
+            }
+        }
+        GameMode::SurvivalMode => {
+            println!("cleared waves: {}", profile.total_cleared_waves);
+            if let Some(wave) = world.get_resource::<Wave>() {
+                println!("wave: {}", wave.wave_number);
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use bevy::prelude::*;
+use std::fs;
+
+use crate::plugins::weapon::AimTarget;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ScriptButton {
+    Key(KeyCode),
+    Mouse,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ScriptAction {
+    Press(ScriptButton),
+    Release(ScriptButton),
+    Aim(Vec2),
+    // a single left click, released on the next frame
+    Click,
+}
+
+// one action per line, "<frame> press|release <W|A|S|D|Space|Escape|Mouse>",
+// "<frame> aim <x> <y>" or "<frame> click", lines starting with # are ignored
+#[derive(Resource, Clone, Default)]
+pub struct InputScript {
+    actions: Vec<(u64, ScriptAction)>,
+    frame: u64,
+    next_action: usize,
+    is_clicking: bool,
+}
+
+impl InputScript {
+    pub fn load(path: &str) -> Result<Self, String> {
+        let contents = fs::read_to_string(path).map_err(|err| format!("{}: {}", path, err))?;
+        InputScript::parse(&contents).map_err(|err| format!("{}:{}", path, err))
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut actions = Vec::new();
+
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let action = parse_line(line).map_err(|err| format!("{}: {}", index + 1, err))?;
+            actions.push(action);
+        }
+
+        // stable, actions on the same frame keep the order they were written in
+        actions.sort_by_key(|(frame, _)| *frame);
+
+        Ok(InputScript {
+            actions,
+            ..Default::default()
+        })
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next_action >= self.actions.len()
+    }
+}
+
+fn parse_line(line: &str) -> Result<(u64, ScriptAction), String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+
+    let frame = words[0]
+        .parse::<u64>()
+        .map_err(|_| format!("{} is not a frame", words[0]))?;
+
+    let action = match words.get(1..) {
+        Some(["press", button]) => ScriptAction::Press(parse_button(button)?),
+        Some(["release", button]) => ScriptAction::Release(parse_button(button)?),
+        Some(["aim", x, y]) => {
+            let x = x
+                .parse::<f32>()
+                .map_err(|_| format!("{} is not a number", x))?;
+            let y = y
+                .parse::<f32>()
+                .map_err(|_| format!("{} is not a number", y))?;
+            ScriptAction::Aim(Vec2::new(x, y))
+        }
+        Some(["click"]) => ScriptAction::Click,
+        _ => return Err(format!("unknown action {}", line)),
+    };
+
+    Ok((frame, action))
+}
+
+fn parse_button(button: &str) -> Result<ScriptButton, String> {
+    match button {
+        "W" => Ok(ScriptButton::Key(KeyCode::KeyW)),
+        "A" => Ok(ScriptButton::Key(KeyCode::KeyA)),
+        "S" => Ok(ScriptButton::Key(KeyCode::KeyS)),
+        "D" => Ok(ScriptButton::Key(KeyCode::KeyD)),
+        "Space" => Ok(ScriptButton::Key(KeyCode::Space)),
+        "Escape" => Ok(ScriptButton::Key(KeyCode::Escape)),
+        "Mouse" => Ok(ScriptButton::Mouse),
+        _ => Err(format!("{} is not a button", button)),
+    }
+}
+
+pub fn script_input(
+    mut script: ResMut<InputScript>,
+    mut keyboard_input: ResMut<ButtonInput<KeyCode>>,
+    mut mouse_input: ResMut<ButtonInput<MouseButton>>,
+    mut aim_target: ResMut<AimTarget>,
+) {
+    if script.is_clicking {
+        mouse_input.release(MouseButton::Left);
+        script.is_clicking = false;
+    }
+
+    let frame = script.frame;
+    while let Some((action_frame, action)) = script.actions.get(script.next_action).copied() {
+        if action_frame > frame {
+            break;
+        }
+
+        match action {
+            ScriptAction::Press(ScriptButton::Key(key)) => keyboard_input.press(key),
+            ScriptAction::Press(ScriptButton::Mouse) => mouse_input.press(MouseButton::Left),
+            ScriptAction::Release(ScriptButton::Key(key)) => keyboard_input.release(key),
+            ScriptAction::Release(ScriptButton::Mouse) => mouse_input.release(MouseButton::Left),
+            ScriptAction::Aim(position) => aim_target.position = Some(position),
+            ScriptAction::Click => {
+                mouse_input.press(MouseButton::Left);
+                script.is_clicking = true;
+            }
+        }
+
+        script.next_action += 1;
+    }
+
+    script.frame += 1;
+}
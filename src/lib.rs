@@ -0,0 +1,13 @@
+// game code shared by the windowed game and the headless runs, most constructors load
+// files or roll a seed so they stay explicit instead of becoming Default
+#![allow(clippy::new_without_default)]
+
+pub mod components;
+pub mod config;
+pub mod headless;
+pub mod materials;
+pub mod plugins;
+pub mod resources;
+pub mod scenes;
+pub mod utils;
+pub mod validate;
@@ -1,19 +1,55 @@
-use bevy::window::{WindowResizeConstraints, WindowResolution};
-use bevy::{prelude::*, window::WindowMode};
-use bevy_kira_audio::AudioPlugin;
+use std::process::ExitCode;
 
-use config::*;
+use dungeon_quest_v2::headless::{self, HeadlessOptions};
 
-mod components;
-mod config;
-mod materials;
-mod plugins;
-mod resources;
-mod scenes;
-mod utils;
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if args.iter().any(|arg| arg == "--headless") {
+        return run_headless(&args);
+    }
+
+    // nothing to draw on without the render feature
+    if !cfg!(feature = "render") {
+        return run_headless(&args);
+    }
+
+    #[cfg(feature = "render")]
+    run_game();
+
+    ExitCode::SUCCESS
+}
+
+fn run_headless(args: &[String]) -> ExitCode {
+    let options = match HeadlessOptions::from_args(args) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}", err);
+            eprintln!("{}", headless::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    headless::run(options);
+    ExitCode::SUCCESS
+}
+
+#[cfg(feature = "render")]
+fn run_game() {
+    use bevy::asset::io::AssetSourceBuilder;
+    use bevy::window::{WindowResizeConstraints, WindowResolution};
+    use bevy::{prelude::*, window::WindowMode};
+    use bevy_kira_audio::AudioPlugin;
+
+    use dungeon_quest_v2::config::*;
+    use dungeon_quest_v2::{plugins, resources, scenes};
 
-fn main() {
     App::new()
+        // mod pack sprites, has to be registered before the asset plugin
+        .register_asset_source(
+            resources::mod_packs::MODS_ASSET_SOURCE,
+            AssetSourceBuilder::platform_default(MODS_DIRECTORY, None),
+        )
         .add_plugins(
             DefaultPlugins
                 .set(WindowPlugin {
@@ -42,12 +78,13 @@ fn main() {
                 .set(ImagePlugin::default_nearest()),
         )
         .init_resource::<resources::setting::Setting>()
-        .init_resource::<resources::dictionary::Dictionary>()
         .init_state::<scenes::SceneState>()
         .add_plugins(AudioPlugin)
         .add_systems(Startup, plugins::music::background_audio_channel_setup)
         .add_systems(Update, plugins::music::play_background_music)
+        .add_plugins(plugins::sound::SoundEffectPlugin)
         .add_plugins(plugins::camera::CameraPlugin)
+        .add_plugins(plugins::content::ContentPlugin)
         .add_plugins(scenes::loading_scene::LoadingScenePlugin)
         .add_plugins(scenes::main_menu_scene::MainMenuScenePlugin)
         .add_plugins(scenes::highscore_scene::HighscoreScenePlugin)
@@ -57,14 +94,9 @@ fn main() {
         .add_plugins(scenes::game_mode_select_scene::GameModeSelectScenePlugin)
         .add_plugins(scenes::hero_select_scene::HeroSelectScenePlugin)
         .add_plugins(scenes::result_scene::ResultScenePlugin)
-        .add_plugins(plugins::input::InputHandlePlugin)
-        .add_plugins(plugins::player::PlayerPlugin)
-        .add_plugins(plugins::weapon::WeaponPlugin)
-        .add_plugins(plugins::classic_mode::ClassicModePlugin)
+        .add_plugins(plugins::GameplayPlugins)
         .add_plugins(plugins::classic_mode::ui::ClassicModeUIPlugin)
-        .add_plugins(plugins::survival_mode::SurvivalModePlugin)
         .add_plugins(plugins::survival_mode::ui::SurvivalModeUIPlugin)
-        .add_plugins(plugins::monster::MonsterPlugin)
         // .add_plugins(plugins::debug::DebugPlugin)
         .run();
 }
@@ -1,7 +1,6 @@
 use bevy::prelude::*;
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct BulletsMaterials {
-    pub arrow: Handle<Image>,
     pub bullet: Handle<Image>,
 }
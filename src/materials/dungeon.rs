@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct DungeonMaterials {
     pub floor: Handle<Image>,
     pub ladder: Handle<Image>,
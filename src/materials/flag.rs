@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 
+#[derive(Default)]
 pub struct FlagMaterials {
     pub vietnam: Handle<Image>,
     pub united_states: Handle<Image>,
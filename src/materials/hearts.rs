@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct HeartsMaterials {
     pub full_heart: Handle<Image>,
     pub half_heart: Handle<Image>,
@@ -1,39 +1,40 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
 
+use crate::resources::game_data::GameData;
 use crate::resources::hero::gender::Gender;
 use crate::resources::hero::hero_class::HeroClass;
+use crate::resources::hero::{GenderSprites, Hero};
+use crate::resources::mod_packs::ModPacks;
 
-#[derive(Clone)]
+// one image per hero and gender, `sprites` picks which images of the hero entry to load
+#[derive(Clone, Default)]
 pub struct HeroesMaterials {
-    pub male_elf: Handle<Image>,
-    pub male_knight: Handle<Image>,
-    pub male_wizard: Handle<Image>,
-    pub male_lizard: Handle<Image>,
-    pub female_elf: Handle<Image>,
-    pub female_knight: Handle<Image>,
-    pub female_wizard: Handle<Image>,
-    pub female_lizard: Handle<Image>,
+    images: HashMap<(HeroClass, Gender), Handle<Image>>,
 }
 
 impl HeroesMaterials {
-    pub fn get_texture(&self, hero_class: HeroClass, gender: Gender) -> Handle<Image> {
-        return match hero_class {
-            HeroClass::Elf => match gender {
-                Gender::Male => self.male_elf.clone(),
-                Gender::Female => self.female_elf.clone(),
-            },
-            HeroClass::Knight => match gender {
-                Gender::Male => self.male_knight.clone(),
-                Gender::Female => self.female_knight.clone(),
-            },
-            HeroClass::Lizard => match gender {
-                Gender::Male => self.male_lizard.clone(),
-                Gender::Female => self.female_lizard.clone(),
-            },
-            HeroClass::Wizard => match gender {
-                Gender::Male => self.male_wizard.clone(),
-                Gender::Female => self.female_wizard.clone(),
-            },
-        };
+    pub fn load(
+        game_data: &GameData,
+        sprites: fn(&Hero) -> &GenderSprites,
+        mod_packs: &ModPacks,
+        asset_server: &AssetServer,
+    ) -> Self {
+        let mut images = HashMap::new();
+        for hero in game_data.get_heroes() {
+            for gender in Gender::iterator() {
+                let image = mod_packs.load(asset_server, sprites(&hero).get(gender));
+                images.insert((hero.hero_class.clone(), gender.clone()), image);
+            }
+        }
+
+        HeroesMaterials { images }
+    }
+
+    pub fn get_texture(&self, hero_class: &HeroClass, gender: &Gender) -> Handle<Image> {
+        self.images
+            .get(&(hero_class.clone(), gender.clone()))
+            .cloned()
+            .unwrap_or_default()
     }
 }
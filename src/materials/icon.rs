@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 
+#[derive(Default)]
 pub struct IconMaterials {
     pub sound_icon_off: Handle<Image>,
     pub sound_icon_on: Handle<Image>,
@@ -7,7 +7,7 @@ use crate::materials::potions::PotionsMaterials;
 use crate::materials::weapons::WeaponsMaterials;
 use bevy::prelude::*;
 
-#[derive(Resource)]
+#[derive(Resource, Default)]
 pub struct InGameMaterials {
     pub heroes_materials: HeroesMaterials,
     pub weapons_materials: WeaponsMaterials,
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct MenuBoxMaterials {
     pub top_right: Handle<Image>,
     pub top_center: Handle<Image>,
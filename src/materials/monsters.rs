@@ -1,33 +1,34 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
 
+use crate::resources::game_data::GameData;
+use crate::resources::mod_packs::ModPacks;
 use crate::resources::monster::monster_class::MonsterClass;
 
-#[derive(Clone)]
+// tilesets of every monster in the game data, loaded from the sprite path of its entry
+#[derive(Clone, Default)]
 pub struct MonstersMaterials {
-    pub small_zombie: Handle<Image>,
-    pub zombie: Handle<Image>,
-    pub big_zombie: Handle<Image>,
-    pub goblin: Handle<Image>,
-    pub orc: Handle<Image>,
-    pub ogre: Handle<Image>,
-    pub imp: Handle<Image>,
-    pub chort: Handle<Image>,
-    pub big_demon: Handle<Image>,
-    pub swampy: Handle<Image>,
+    tilesets: HashMap<MonsterClass, Handle<Image>>,
 }
+
 impl MonstersMaterials {
-    pub fn get_texture(&self, monster_class: MonsterClass) -> Handle<Image> {
-        match monster_class {
-            MonsterClass::SmallZombie => self.small_zombie.clone(),
-            MonsterClass::Zombie => self.zombie.clone(),
-            MonsterClass::BigZombie => self.big_zombie.clone(),
-            MonsterClass::Goblin => self.goblin.clone(),
-            MonsterClass::Orc => self.orc.clone(),
-            MonsterClass::Ogre => self.ogre.clone(),
-            MonsterClass::Imp => self.imp.clone(),
-            MonsterClass::Chort => self.chort.clone(),
-            MonsterClass::BigDemon => self.big_demon.clone(),
-            MonsterClass::Swampy => self.swampy.clone(),
-        }
+    pub fn load(game_data: &GameData, mod_packs: &ModPacks, asset_server: &AssetServer) -> Self {
+        let tilesets = game_data
+            .get_monsters()
+            .iter()
+            .map(|monster| {
+                let tileset = mod_packs.load(asset_server, &monster.sprite);
+                (monster.class.clone(), tileset)
+            })
+            .collect();
+
+        MonstersMaterials { tilesets }
+    }
+
+    pub fn get_texture(&self, monster_class: &MonsterClass) -> Handle<Image> {
+        self.tilesets
+            .get(monster_class)
+            .cloned()
+            .unwrap_or_default()
     }
 }
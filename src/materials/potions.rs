@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 
+#[derive(Default)]
 pub struct PotionsMaterials {
     pub heal: Handle<Image>,
     pub focus: Handle<Image>,
@@ -5,7 +5,7 @@ use crate::materials::heroes::HeroesMaterials;
 use crate::materials::icon::IconMaterials;
 use crate::materials::menu_box::MenuBoxMaterials;
 
-#[derive(Resource)]
+#[derive(Resource, Default)]
 pub struct ScenesMaterials {
     pub main_background_image: Handle<Image>,
     pub sub_background_image: Handle<Image>,
@@ -1,16 +1,47 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
 
-#[derive(Clone)]
+use crate::resources::game_data::GameData;
+use crate::resources::mod_packs::ModPacks;
+use crate::resources::weapon::weapon_type::WeaponType;
+
+// sprites of every weapon in the game data, and of the bullets of those that have their own
+#[derive(Clone, Default)]
 pub struct WeaponsMaterials {
-    pub bow: Handle<Image>,
-    pub short_sword: Handle<Image>,
-    pub sword: Handle<Image>,
-    pub machete: Handle<Image>,
-    pub small_hammer: Handle<Image>,
-    pub mace: Handle<Image>,
-    pub big_hammer: Handle<Image>,
-    pub small_wand: Handle<Image>,
-    pub magic_wand: Handle<Image>,
-    pub magic_sword: Handle<Image>,
-    pub spear: Handle<Image>,
+    weapons: HashMap<WeaponType, Handle<Image>>,
+    bullets: HashMap<WeaponType, Handle<Image>>,
+}
+
+impl WeaponsMaterials {
+    pub fn load(game_data: &GameData, mod_packs: &ModPacks, asset_server: &AssetServer) -> Self {
+        let mut weapons = HashMap::new();
+        let mut bullets = HashMap::new();
+        for weapon in game_data.get_all_weapons() {
+            weapons.insert(
+                weapon.name.clone(),
+                mod_packs.load(asset_server, &weapon.sprite),
+            );
+
+            if let Some(sprite) = weapon
+                .bullet
+                .as_ref()
+                .and_then(|bullet| bullet.sprite.as_ref())
+            {
+                bullets.insert(weapon.name.clone(), mod_packs.load(asset_server, sprite));
+            }
+        }
+
+        WeaponsMaterials { weapons, bullets }
+    }
+
+    pub fn get_texture(&self, weapon_type: &WeaponType) -> Handle<Image> {
+        self.weapons
+            .get(weapon_type)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn get_bullet_texture(&self, weapon_type: &WeaponType) -> Option<Handle<Image>> {
+        self.bullets.get(weapon_type).cloned()
+    }
 }
@@ -1,55 +1,61 @@
 use bevy::prelude::*;
 
+use crate::components::collider::{Collider, CollisionLayer};
 use crate::config::*;
 use crate::materials::ingame::InGameMaterials;
-use crate::plugins::classic_mode::ClassicModeData;
 use crate::resources::dungeon::Dungeon;
 use crate::resources::dungeon::door::{Door, HorizontalDoor, VerticaltDoor};
 use crate::resources::dungeon::doors::Doors;
+use crate::resources::dungeon::room::Room;
 use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
 
 const START_Y: f32 = 0.0 + WINDOW_HEIGHT / 2.0 - TILE_SIZE / 2.0;
 const START_X: f32 = 0.0 - WINDOW_HEIGHT * RESOLUTION / 2.0 + TILE_SIZE / 2.0;
 
 pub fn doors(
-    mut commands: Commands,
-    ingame_materials: Res<InGameMaterials>,
-    mut data: ResMut<ClassicModeData>,
-) {
-    let doors = commands
+    commands: &mut Commands,
+    room: &Room,
+    ingame_materials: &InGameMaterials,
+) -> Entity {
+    commands
         .spawn(Sprite {
             ..Default::default()
         })
         .with_children(|parent| {
             for door in Door::iterator() {
+                let Some((row, column)) = room.door_tile_position(door) else {
+                    continue;
+                };
+
+                let x = START_X + column * TILE_SIZE;
+                let y = START_Y - row * TILE_SIZE;
+
                 match door {
-                    Door::Left | Door::Right => horizontal_door(parent, door, &ingame_materials),
-                    Door::Bottom | Door::Top => vertical_door(parent, door, &ingame_materials),
+                    Door::Left | Door::Right => {
+                        horizontal_door(parent, door, x, y, ingame_materials)
+                    }
+                    Door::Bottom | Door::Top => vertical_door(parent, door, x, y, ingame_materials),
                 }
             }
         })
         .insert(Doors)
         .insert(Name::new("Doors"))
-        .id();
-
-    data.doors = Some(doors);
+        .id()
 }
 
-pub fn horizontal_door(parent: &mut ChildSpawnerCommands, door: &Door, ingame_materials: &InGameMaterials) {
+pub fn horizontal_door(
+    parent: &mut ChildSpawnerCommands,
+    door: &Door,
+    x: f32,
+    y: f32,
+    ingame_materials: &InGameMaterials,
+) {
     let image = if *door == Door::Left {
         ingame_materials.dungeon_materials.wall_border_left.clone()
     } else {
         ingame_materials.dungeon_materials.wall_border_right.clone()
     };
 
-    let x = if *door == Door::Left {
-        START_X
-    } else {
-        START_X + 15.0 * TILE_SIZE
-    };
-
-    let y = START_Y - 4.0 * TILE_SIZE;
-
     let component_name = if *door == Door::Left {
         "Left Door"
     } else {
@@ -67,24 +73,37 @@ pub fn horizontal_door(parent: &mut ChildSpawnerCommands, door: &Door, ingame_ma
         ))
         .insert(Name::new(component_name))
         .insert(HorizontalDoor)
-        .insert(door.clone());
+        .insert(door.clone())
+        .insert(Collider::rectangle(
+            TILE_SIZE,
+            TILE_SIZE,
+            CollisionLayer::Door,
+        ));
 }
 
 pub fn vertical_door(
     grandparent: &mut ChildSpawnerCommands,
     door: &Door,
+    x: f32,
+    y: f32,
     ingame_materials: &InGameMaterials,
 ) {
     let left_part = ingame_materials.dungeon_materials.door_left_part.clone();
     let right_part = ingame_materials.dungeon_materials.door_right_part.clone();
     let door_closed = ingame_materials.dungeon_materials.door_closed.clone();
 
-    let left_door_part_x = -96.0;
-    let right_door_part_x = 96.0;
+    let left_door_part_x = x - 96.0;
+    let right_door_part_x = x + 96.0;
 
-    let y = if *door == Door::Bottom { -224.0 } else { 224.0 };
     let z = if *door == Door::Bottom { 0.2 } else { 0.1 };
 
+    // only the edge of the door that leads out of the room opens it
+    let door_collider = if *door == Door::Top {
+        Collider::rectangle(128.0, 10.0, CollisionLayer::Door)
+    } else {
+        Collider::rectangle(128.0, 36.0, CollisionLayer::Door)
+    };
+
     let component_name = if *door == Door::Bottom {
         "Bottom Door"
     } else {
@@ -124,12 +143,13 @@ pub fn vertical_door(
                         ..Default::default()
                     },
                     Transform {
-                        translation: Vec3::new(0.0, y, z),
+                        translation: Vec3::new(x, y, z),
                         ..Default::default()
                     },
                 ))
                 .insert(Name::new("Main Verticalt Door Part"))
-                .insert(door.clone());
+                .insert(door.clone())
+                .insert(door_collider);
 
             parent
                 .spawn((
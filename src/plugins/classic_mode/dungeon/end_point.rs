@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 
+use crate::components::collider::{Collider, CollisionLayer};
 use crate::config::*;
 use crate::materials::ingame::InGameMaterials;
 use crate::plugins::classic_mode::ClassicModeData;
@@ -36,6 +37,7 @@ pub fn end_point(
             Visibility::Hidden,
         ))
         .insert(EndPoint)
+        .insert(Collider::rectangle(50.0, 50.0, CollisionLayer::EndPoint))
         .insert(Name::new("EndPoint"))
         .id();
 
@@ -2,29 +2,29 @@ use bevy::prelude::*;
 
 use crate::config::*;
 use crate::materials::ingame::InGameMaterials;
-use crate::plugins::classic_mode::ClassicModeData;
-use crate::plugins::classic_mode::dungeon::{TOTAL_TILE_HEIGHT, TOTAL_TILE_WIDTH};
 use crate::resources::dungeon::ground::Ground;
 use crate::resources::dungeon::layer::Layer;
+use crate::resources::dungeon::room::Room;
 
 pub fn ground(
-    mut commands: Commands,
-    ingame_materials: Res<InGameMaterials>,
-    mut data: ResMut<ClassicModeData>,
-) {
+    commands: &mut Commands,
+    room: &Room,
+    ingame_materials: &InGameMaterials,
+) -> Entity {
     let start_y: f32 = 0.0 + WINDOW_HEIGHT / 2.0 - TILE_SIZE / 2.0;
     let start_x: f32 = 0.0 - WINDOW_HEIGHT * RESOLUTION / 2.0 + TILE_SIZE / 2.0;
 
-    let ground = commands
+    commands
         .spawn((Sprite {
             color: Color::BLACK,
             custom_size: Some(Vec2::new(WINDOW_HEIGHT * RESOLUTION, WINDOW_HEIGHT)),
             ..Default::default()
         },))
         .with_children(|parent| {
-            for row_index in 0..TOTAL_TILE_HEIGHT {
-                for column_index in 0..TOTAL_TILE_WIDTH {
-                    if row_index >= 1 && column_index > 0 && column_index < 15 {
+            for (row_index, row) in room.tilemap.iter().enumerate() {
+                for (column_index, value) in row.iter().enumerate() {
+                    // corners and side borders have nothing underneath them
+                    if row_index >= 1 && !matches!(value.abs(), 3..=8) {
                         let x = start_x + column_index as f32 * TILE_SIZE;
                         let y = start_y - row_index as f32 * TILE_SIZE;
 
@@ -48,7 +48,5 @@ pub fn ground(
         })
         .insert(Name::new("Ground"))
         .insert(Ground)
-        .id();
-
-    data.ground = Some(ground);
+        .id()
 }
@@ -1,20 +1,52 @@
 use bevy::prelude::*;
+use rand::Rng;
 
 use crate::config::*;
+use crate::headless::HeadlessFlag;
 use crate::plugins::classic_mode::ClassicModeData;
 use crate::resources::dungeon::Dungeon;
+use crate::resources::dungeon::rooms::Rooms;
 use crate::resources::game_mode::GameMode;
+use crate::resources::mod_packs::ModFloors;
 use crate::resources::monster::monster_spawn_controller::MonsterSpawnController;
 use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
+use crate::resources::profile::Profile;
+use crate::resources::run_rng::RunRng;
+use crate::resources::saved_run::SavedRun;
 use crate::scenes::SceneState;
 
-pub fn initiate_classic_mode(mut commands: Commands, mut state: ResMut<NextState<SceneState>>) {
-    let dungeon = Dungeon::new();
+pub fn initiate_classic_mode(
+    mut commands: Commands,
+    mut state: ResMut<NextState<SceneState>>,
+    profile: Res<Profile>,
+    rooms: Res<Rooms>,
+    mod_floors: Res<ModFloors>,
+    saved_run: Option<Res<SavedRun>>,
+    headless_flag: Option<Res<HeadlessFlag>>,
+) {
+    let mut run_rng = match saved_run.as_ref() {
+        Some(saved_run) => RunRng::resume(profile.seed, &saved_run.run_rng),
+        None => RunRng::new(profile.seed),
+    };
 
-    let player_dungeon_stats = PlayerDungeonStats {
-        current_room_position: dungeon.current_floor.current_position,
-        is_room_cleared: true,
-        current_floor_index: 0,
+    // starting a new run replaces the saved one
+    let (dungeon, player_dungeon_stats) = match saved_run.as_ref() {
+        Some(saved_run) => (
+            saved_run.dungeon.clone(),
+            saved_run.player_dungeon_stats.clone(),
+        ),
+        None => {
+            if headless_flag.is_none() {
+                SavedRun::delete();
+            }
+            let dungeon = Dungeon::new(run_rng.map.r#gen(), &rooms, &mod_floors);
+            let player_dungeon_stats = PlayerDungeonStats {
+                current_room_position: dungeon.current_floor.current_position,
+                is_room_cleared: true,
+                current_floor_index: 0,
+            };
+            (dungeon, player_dungeon_stats)
+        }
     };
 
     let classic_mode_data = ClassicModeData {
@@ -22,6 +54,7 @@ pub fn initiate_classic_mode(mut commands: Commands, mut state: ResMut<NextState
         walls: None,
         ground: None,
         end_point: None,
+        current_room: None,
     };
 
     let start_x: f32 = 0.0 - WINDOW_HEIGHT * RESOLUTION / 2.0 + TILE_SIZE / 2.0;
@@ -32,22 +65,30 @@ pub fn initiate_classic_mode(mut commands: Commands, mut state: ResMut<NextState
     let spawn_area_end_x = start_x + 13.0 * TILE_SIZE;
     let spawn_area_end_y = start_y - 6.0 * TILE_SIZE;
 
+    // monsters which were alive when the run was saved are spawned again
+    let (require_monster, killed_monsters) = match saved_run.as_ref() {
+        Some(saved_run) => (saved_run.require_monsters, saved_run.killed_monsters),
+        None => (5, 0),
+    };
+
     let monster_spawn_controller = MonsterSpawnController {
         game_mode: GameMode::ClassicMode,
         max_avalible_monsters: 4,
-        require_monster: 5,
+        require_monster,
         alive_monsters: 0,
-        killed_monsters: 0,
+        killed_monsters,
         spawn_area_start_x,
         spawn_area_start_y,
         spawn_area_end_x,
         spawn_area_end_y,
+        spawn_positions: Vec::new(),
     };
 
     commands.insert_resource(dungeon);
     commands.insert_resource(player_dungeon_stats);
     commands.insert_resource(classic_mode_data);
     commands.insert_resource(monster_spawn_controller);
+    commands.insert_resource(run_rng);
 
     state.set(SceneState::InGameClassicMode);
 }
@@ -2,6 +2,7 @@ pub mod doors;
 pub mod end_point;
 pub mod ground;
 pub mod initiate;
+pub mod room;
 pub mod walls;
 
 pub const TOTAL_TILE_WIDTH: usize = 16;
@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+
+use crate::config::*;
+use crate::materials::ingame::InGameMaterials;
+use crate::plugins::classic_mode::ClassicModeData;
+use crate::plugins::classic_mode::dungeon::{doors, ground, walls};
+use crate::resources::dungeon::Dungeon;
+use crate::resources::dungeon::navigation_grid::NavigationGrid;
+use crate::resources::dungeon::rooms::Rooms;
+use crate::resources::monster::monster_spawn_controller::MonsterSpawnController;
+use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
+
+const START_Y: f32 = 0.0 + WINDOW_HEIGHT / 2.0 - TILE_SIZE / 2.0;
+const START_X: f32 = 0.0 - WINDOW_HEIGHT * RESOLUTION / 2.0 + TILE_SIZE / 2.0;
+
+// Rebuilds ground, walls and doors from the tilemap of the room the player is in
+pub fn room_handle_system(
+    mut commands: Commands,
+    mut data: ResMut<ClassicModeData>,
+    mut monster_spawn_controller: ResMut<MonsterSpawnController>,
+    player_dungeon_stats: Res<PlayerDungeonStats>,
+    ingame_materials: Res<InGameMaterials>,
+    dungeon: Res<Dungeon>,
+    rooms: Res<Rooms>,
+) {
+    let current_room = (
+        player_dungeon_stats.current_floor_index,
+        dungeon.current_floor.current_position,
+    );
+
+    if data.current_room == Some(current_room) {
+        return;
+    }
+
+    for entity in [data.ground, data.walls, data.doors].into_iter().flatten() {
+        commands.entity(entity).despawn();
+    }
+
+    let current_position = dungeon.current_floor.current_position;
+    let room_id =
+        dungeon.current_floor.map[current_position.row_index][current_position.column_index];
+    let room = rooms.get_room(room_id);
+
+    data.ground = Some(ground::ground(&mut commands, &room, &ingame_materials));
+    data.walls = Some(walls::walls(&mut commands, &room, &ingame_materials));
+    data.doors = Some(doors::doors(&mut commands, &room, &ingame_materials));
+    data.current_room = Some(current_room);
+
+    commands.insert_resource(NavigationGrid::new(&room.tilemap, START_X, START_Y));
+
+    monster_spawn_controller.spawn_positions = room
+        .spawn_tile_positions()
+        .iter()
+        .map(|(row_index, column_index)| {
+            Vec2::new(
+                START_X + *column_index as f32 * TILE_SIZE,
+                START_Y - *row_index as f32 * TILE_SIZE,
+            )
+        })
+        .collect();
+}
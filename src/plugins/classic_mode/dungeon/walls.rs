@@ -2,11 +2,11 @@ use bevy::prelude::*;
 
 use crate::config::*;
 use crate::materials::ingame::InGameMaterials;
-use crate::plugins::classic_mode::ClassicModeData;
 use crate::plugins::classic_mode::dungeon::TOTAL_TILE_HEIGHT;
 use crate::resources::dungeon::Dungeon;
 use crate::resources::dungeon::block_type::BlockType;
-use crate::resources::dungeon::rooms::Rooms;
+use crate::resources::dungeon::navigation_grid::NavigationGrid;
+use crate::resources::dungeon::room::Room;
 use crate::resources::dungeon::wall::Wall;
 use crate::resources::dungeon::wall_type::WallType;
 use crate::resources::dungeon::walls::Walls;
@@ -16,20 +16,11 @@ const START_Y: f32 = 0.0 + WINDOW_HEIGHT / 2.0 - TILE_SIZE / 2.0;
 const START_X: f32 = 0.0 - WINDOW_HEIGHT * RESOLUTION / 2.0 + TILE_SIZE / 2.0;
 
 pub fn walls(
-    mut commands: Commands,
-    dungeon: Res<Dungeon>,
-    rooms: Res<Rooms>,
-    ingame_materials: Res<InGameMaterials>,
-    mut data: ResMut<ClassicModeData>,
-) {
-    let current_floor = dungeon.current_floor.clone();
-    let current_position = current_floor.current_position;
-
-    let room_id = current_floor.map[current_position.row_index][current_position.column_index];
-
-    let room = rooms.get_room(if room_id == 1.0 { 1.0 } else { 1.0 });
-
-    let walls = commands
+    commands: &mut Commands,
+    room: &Room,
+    ingame_materials: &InGameMaterials,
+) -> Entity {
+    commands
         .spawn(Sprite {
             ..Default::default()
         })
@@ -37,16 +28,14 @@ pub fn walls(
             for (row_index, row) in room.tilemap.iter().enumerate() {
                 for (column_index, column) in row.iter().enumerate() {
                     if *column != 0 {
-                        wall(parent, row_index, column_index, *column, &ingame_materials);
+                        wall(parent, row_index, column_index, *column, ingame_materials);
                     }
                 }
             }
         })
         .insert(Walls)
         .insert(Name::new("Walls"))
-        .id();
-
-    data.walls = Some(walls);
+        .id()
 }
 
 fn wall(
@@ -66,6 +55,7 @@ fn wall(
         }
         7 => BlockType::WallLeft,
         8 => BlockType::WallRight,
+        9 => BlockType::Obstacle,
         _ => BlockType::None,
     };
 
@@ -87,6 +77,7 @@ fn wall(
         6 => ingame_materials.dungeon_materials.wall_right.clone(),
         -7 | 7 => ingame_materials.dungeon_materials.wall_border_left.clone(),
         -8 | 8 => ingame_materials.dungeon_materials.wall_border_right.clone(),
+        9 => ingame_materials.dungeon_materials.wall.clone(),
         _ => panic!("Unknow room value: {}", value),
     };
 
@@ -106,36 +97,42 @@ fn wall(
         0.2
     };
 
-    parent
-        .spawn((
-            Sprite {
-                image: image,
-                custom_size: Some(Vec2::new(TILE_SIZE, TILE_SIZE)),
-                ..Default::default()
-            },
-            Transform {
-                translation: Vec3::new(x, y, z),
-                ..Default::default()
-            },
-        ))
-        .insert(Wall {
-            wall_type: if value < 0 {
-                WallType::Temporary
-            } else {
-                WallType::Permanent
-            },
-            row_index,
-            column_index,
-            value,
-        })
-        .insert(block_type)
-        .insert(Name::new(component_name));
+    let collider = block_type.collider();
+
+    let mut wall = parent.spawn((
+        Sprite {
+            image: image,
+            custom_size: Some(Vec2::new(TILE_SIZE, TILE_SIZE)),
+            ..Default::default()
+        },
+        Transform {
+            translation: Vec3::new(x, y, z),
+            ..Default::default()
+        },
+    ));
+    wall.insert(Wall {
+        wall_type: if value < 0 {
+            WallType::Temporary
+        } else {
+            WallType::Permanent
+        },
+        row_index,
+        column_index,
+        value,
+    })
+    .insert(block_type)
+    .insert(Name::new(component_name));
+
+    if let Some(collider) = collider {
+        wall.insert(collider);
+    }
 }
 
 pub fn temporary_walls_system(
     mut wall_query: Query<(&Wall, &mut Visibility)>,
     player_dungeon_stats: Res<PlayerDungeonStats>,
     dungeon: Res<Dungeon>,
+    mut navigation_grid: ResMut<NavigationGrid>,
 ) {
     if player_dungeon_stats.is_changed() {
         let current_floor = dungeon.current_floor.clone();
@@ -207,6 +204,13 @@ pub fn temporary_walls_system(
                         Visibility::Inherited
                     };
                 }
+
+                // hidden temporary walls are open passages
+                navigation_grid.set_blocked(
+                    wall.row_index,
+                    wall.column_index,
+                    *visibility != Visibility::Hidden,
+                );
             }
         }
     }
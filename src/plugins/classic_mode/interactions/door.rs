@@ -1,46 +1,59 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
+use crate::components::collider::CollisionLayer;
 use crate::components::player::PlayerComponent;
 use crate::components::potion::PotionComponent;
-use crate::resources::dungeon::Dungeon;
+use crate::plugins::classic_mode::interactions::{InteractionEvents, RunProgress};
+use crate::plugins::sound::SoundEffect;
 use crate::resources::dungeon::door::{Door, HorizontalDoor, VerticaltDoor};
 use crate::resources::dungeon::position::Position;
 use crate::resources::monster::monster_spawn_controller::MonsterSpawnController;
-use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
-use crate::resources::profile::Profile;
-use crate::utils::collide::collide;
+
+// what going through a door changes, the room the player is in and the potions left behind
+#[derive(SystemParam)]
+pub struct DoorTransition<'w, 's> {
+    monster_spawn_controller: ResMut<'w, MonsterSpawnController>,
+    progress: RunProgress<'w>,
+    potion_query: Query<'w, 's, Entity, With<PotionComponent>>,
+    commands: Commands<'w, 's>,
+}
 
 pub fn horizontal_door_interaction_handle(
-    mut player_query: Query<(&mut Transform, &Sprite), With<PlayerComponent>>,
-    mut door_query: Query<
-        (&Door, &Transform, &Sprite, &Visibility),
-        (With<HorizontalDoor>, Without<PlayerComponent>),
-    >,
-    mut monster_spawn_controller: ResMut<MonsterSpawnController>,
-    mut player_dungeon_stats: ResMut<PlayerDungeonStats>,
-    potion_query: Query<Entity, With<PotionComponent>>,
-    mut dungeon: ResMut<Dungeon>,
-    mut profile: ResMut<Profile>,
-    mut commands: Commands,
+    mut player_query: Query<&mut Transform, With<PlayerComponent>>,
+    door_query: Query<(&Door, &Visibility), With<HorizontalDoor>>,
+    mut events: InteractionEvents,
+    mut transition: DoorTransition,
 ) {
-    let (mut player_transform, player_sprite) = player_query.single_mut().unwrap();
-    let player_translation = player_transform.translation;
-    let player_size = player_sprite.custom_size.unwrap();
+    let mut player_transform = player_query.single_mut().unwrap();
+    let DoorTransition {
+        monster_spawn_controller,
+        progress,
+        potion_query,
+        commands,
+    } = &mut transition;
+    let RunProgress {
+        dungeon,
+        player_dungeon_stats,
+        profile,
+    } = progress;
 
     if player_dungeon_stats.is_room_cleared {
         let current_position = player_dungeon_stats.current_room_position;
         let cleared_positions = dungeon.current_floor.cleared_positions.clone();
 
-        for (door, door_transform, door_sprite, visibility) in door_query.iter_mut() {
-            if visibility == Visibility::Inherited {
+        for collision in events.collision_events.read() {
+            if collision.layer != CollisionLayer::Player
+                || collision.other_layer != CollisionLayer::Door
+            {
                 continue;
             }
 
-            let door_size = door_sprite.custom_size.unwrap();
-
-            let door_translation = door_transform.translation;
+            if let Ok((door, visibility)) = door_query.get(collision.other) {
+                if visibility == Visibility::Inherited {
+                    continue;
+                }
 
-            if collide(player_translation, player_size, door_translation, door_size) {
                 let new_position = Position {
                     row_index: current_position.row_index,
                     column_index: if *door == Door::Right {
@@ -63,7 +76,7 @@ pub fn horizontal_door_interaction_handle(
                 } else {
                     player_dungeon_stats.is_room_cleared = false;
                     monster_spawn_controller.require_monster =
-                        4 + player_dungeon_stats.current_floor_index as i8;
+                        4 + player_dungeon_stats.current_floor_index as i32;
                     monster_spawn_controller.killed_monsters = 0;
                     monster_spawn_controller.alive_monsters = 0;
                     dungeon
@@ -79,6 +92,8 @@ pub fn horizontal_door_interaction_handle(
                     player_transform.translation.x = (player_transform.translation.x * -1.0) + 15.0;
                 }
 
+                events.sound_effects.write(SoundEffect::DoorTransition);
+
                 for potion_entity in potion_query.iter() {
                     commands.entity(potion_entity).despawn();
                 }
@@ -88,84 +103,85 @@ pub fn horizontal_door_interaction_handle(
 }
 
 pub fn vertical_door_interaction_handle(
-    mut player_query: Query<(&mut Transform, &Sprite), With<PlayerComponent>>,
-    mut vertical_door_query: Query<(&Visibility, &Children), With<VerticaltDoor>>,
-    mut door_query: Query<(&Door, &Transform), Without<PlayerComponent>>,
-    mut monster_spawn_controller: ResMut<MonsterSpawnController>,
-    mut player_dungeon_stats: ResMut<PlayerDungeonStats>,
-    potion_query: Query<Entity, With<PotionComponent>>,
-    mut dungeon: ResMut<Dungeon>,
-    mut profile: ResMut<Profile>,
-    mut commands: Commands,
+    mut player_query: Query<&mut Transform, With<PlayerComponent>>,
+    vertical_door_query: Query<&Visibility, With<VerticaltDoor>>,
+    door_query: Query<(&Door, &ChildOf), Without<HorizontalDoor>>,
+    mut events: InteractionEvents,
+    mut transition: DoorTransition,
 ) {
-    let (mut player_transform, player_spirte) = player_query.single_mut().unwrap();
-    let player_translation = player_transform.translation;
-    let player_size = player_spirte.custom_size.unwrap().clone();
+    let mut player_transform = player_query.single_mut().unwrap();
+    let DoorTransition {
+        monster_spawn_controller,
+        progress,
+        potion_query,
+        commands,
+    } = &mut transition;
+    let RunProgress {
+        dungeon,
+        player_dungeon_stats,
+        profile,
+    } = progress;
 
     if player_dungeon_stats.is_room_cleared {
         let current_position = player_dungeon_stats.current_room_position;
         let cleared_positions = dungeon.current_floor.cleared_positions.clone();
 
-        for (visibility, children) in vertical_door_query.iter_mut() {
-            if visibility == Visibility::Hidden {
+        for collision in events.collision_events.read() {
+            if collision.layer != CollisionLayer::Player
+                || collision.other_layer != CollisionLayer::Door
+            {
                 continue;
             }
-            for child in children.iter() {
-                let result = door_query.get_mut(child);
-                if result.is_ok() {
-                    let (door, door_transform) = result.unwrap();
-                    let translation = door_transform.translation;
-
-                    let size = if *door == Door::Top {
-                        Vec2::new(128.0, 10.0)
+
+            if let Ok((door, child_of)) = door_query.get(collision.other) {
+                let visibility = vertical_door_query.get(child_of.parent()).unwrap();
+                if visibility == Visibility::Hidden {
+                    continue;
+                }
+
+                let new_position = Position {
+                    row_index: if *door == Door::Bottom {
+                        current_position.row_index + 1
                     } else {
-                        Vec2::new(128.0, 36.0)
-                    };
-
-                    if collide(player_translation, player_size, translation, size) {
-                        let new_position = Position {
-                            row_index: if *door == Door::Bottom {
-                                current_position.row_index + 1
-                            } else {
-                                current_position.row_index - 1
-                            },
-                            column_index: current_position.column_index,
-                        };
-
-                        player_dungeon_stats.current_room_position = new_position;
-                        dungeon.current_floor.current_position = new_position;
-
-                        if cleared_positions.contains_key(&new_position) {
-                            player_dungeon_stats.is_room_cleared = true;
-                            let cleared_position = cleared_positions.get(&new_position);
-                            let total_entered_time = cleared_position.unwrap();
-                            dungeon
-                                .current_floor
-                                .cleared_positions
-                                .insert(new_position, total_entered_time + 1);
-                        } else {
-                            player_dungeon_stats.is_room_cleared = false;
-                            monster_spawn_controller.require_monster =
-                                6 + player_dungeon_stats.current_floor_index as i8;
-                            monster_spawn_controller.killed_monsters = 0;
-                            monster_spawn_controller.alive_monsters = 0;
-                            dungeon
-                                .current_floor
-                                .cleared_positions
-                                .insert(new_position, 1);
-                            profile.total_cleared_rooms += 1;
-                        }
-
-                        if *door == Door::Top {
-                            player_transform.translation.y = -130.0;
-                        } else {
-                            player_transform.translation.y = 130.0;
-                        }
-
-                        for potion_entity in potion_query.iter() {
-                            commands.entity(potion_entity).despawn();
-                        }
-                    }
+                        current_position.row_index - 1
+                    },
+                    column_index: current_position.column_index,
+                };
+
+                player_dungeon_stats.current_room_position = new_position;
+                dungeon.current_floor.current_position = new_position;
+
+                if cleared_positions.contains_key(&new_position) {
+                    player_dungeon_stats.is_room_cleared = true;
+                    let cleared_position = cleared_positions.get(&new_position);
+                    let total_entered_time = cleared_position.unwrap();
+                    dungeon
+                        .current_floor
+                        .cleared_positions
+                        .insert(new_position, total_entered_time + 1);
+                } else {
+                    player_dungeon_stats.is_room_cleared = false;
+                    monster_spawn_controller.require_monster =
+                        6 + player_dungeon_stats.current_floor_index as i32;
+                    monster_spawn_controller.killed_monsters = 0;
+                    monster_spawn_controller.alive_monsters = 0;
+                    dungeon
+                        .current_floor
+                        .cleared_positions
+                        .insert(new_position, 1);
+                    profile.total_cleared_rooms += 1;
+                }
+
+                if *door == Door::Top {
+                    player_transform.translation.y = -130.0;
+                } else {
+                    player_transform.translation.y = 130.0;
+                }
+
+                events.sound_effects.write(SoundEffect::DoorTransition);
+
+                for potion_entity in potion_query.iter() {
+                    commands.entity(potion_entity).despawn();
                 }
             }
         }
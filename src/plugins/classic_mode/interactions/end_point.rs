@@ -1,25 +1,16 @@
-use crate::components::player::PlayerComponent;
-use crate::components::player_list_effects::PlayerListEffectsComponent;
+use crate::components::collider::CollisionLayer;
 use crate::components::potion::PotionComponent;
-use crate::components::skill::SkillComponent;
-use crate::components::weapon::WeaponComponent;
-use crate::components::weapon_shoot_attack::WeaponShootAttackComponent;
-use crate::components::weapon_swing_attack::WeaponSwingAttackComponent;
 use crate::config::*;
 use crate::materials::font::FontMaterials;
 use crate::materials::menu_box::MenuBoxMaterials;
-use crate::materials::scenes::ScenesMaterials;
 use crate::plugins::classic_mode::ui::CenterTextComponent;
+use crate::plugins::classic_mode::interactions::{InteractionEvents, RunProgress};
+use crate::plugins::player::upgrade::{RewardUi, UpgradePicker};
+use crate::plugins::sound::SoundEffect;
 use crate::resources::dictionary::Dictionary;
-use crate::resources::dungeon::Dungeon;
 use crate::resources::dungeon::end_point::EndPoint;
-use crate::resources::game_data::{GameData, PauseSceneData};
-use crate::resources::hero::hero_class::HeroClass;
-use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
-use crate::resources::profile::Profile;
-use crate::resources::upgrade::upgrade_controller::UpgradeController;
+use crate::resources::game_data::PauseSceneData;
 use crate::resources::upgrade::upgrade_type::UpgradeType;
-use crate::utils::collide::collide;
 use Val::Percent;
 use bevy::color::palettes::css::DARK_GRAY;
 use bevy::prelude::*;
@@ -51,37 +42,36 @@ const REWARDS: [UpgradeType; 4] = [
 pub struct RewardSceneFlag;
 
 pub fn end_point_interaction_handle_system(
-    scenes_materials: Res<ScenesMaterials>,
-    font_materials: Res<FontMaterials>,
-    dictionary: Res<Dictionary>,
+    reward_ui: RewardUi,
     potion_query: Query<Entity, With<PotionComponent>>,
-    mut player_query: Query<(&Transform, &Sprite), With<PlayerComponent>>,
-    mut end_point_query: Query<
-        (&Transform, &Sprite, &Visibility),
-        (With<EndPoint>, Without<PlayerComponent>),
-    >,
-    mut player_dungeon_stats: ResMut<PlayerDungeonStats>,
+    end_point_query: Query<&Visibility, With<EndPoint>>,
+    mut events: InteractionEvents,
+    mut progress: RunProgress,
     mut ui_center_text_query: Query<&mut CenterTextComponent>,
-    mut dungeon: ResMut<Dungeon>,
-    mut profile: ResMut<Profile>,
     mut commands: Commands,
 ) {
+    let RunProgress {
+        dungeon,
+        player_dungeon_stats,
+        profile,
+    } = &mut progress;
+
     let current_position = dungeon.current_floor.current_position;
     let end_room_position = dungeon.current_floor.end_room_position;
 
     // info!("Triggered Endpoint Handle! Current pos: {:?}, end room pos: {:?}, is_room_cleared: {:?}", current_position, end_room_position, player_dungeon_stats.is_room_cleared);
     if current_position == end_room_position && player_dungeon_stats.is_room_cleared {
         info!("triggered endpoint inner logic!");
-        let (player_transform, player_sprite) = player_query.single_mut().unwrap();
-        let (end_point_transform, end_point_sprite, visibility) = end_point_query.single_mut().unwrap();
-
-        let p_translation = player_transform.translation;
-        let p_size = player_sprite.custom_size.unwrap();
-        let ep_translation = end_point_transform.translation;
-        let ep_size = end_point_sprite.custom_size.unwrap();
+        let visibility = end_point_query.single().unwrap();
+        let is_touching_end_point = events.collision_events.read().any(|collision| {
+            collision.layer == CollisionLayer::Player
+                && collision.other_layer == CollisionLayer::EndPoint
+        });
 
         if visibility == Visibility::Visible {
-            if collide(p_translation, p_size, ep_translation, ep_size) {
+            if is_touching_end_point {
+                events.sound_effects.write(SoundEffect::FloorCleared);
+
                 if dungeon.current_floor.is_last_floor {
                     profile.is_run_completed = true;
                     profile.is_run_finished = true;
@@ -94,8 +84,10 @@ pub fn end_point_interaction_handle_system(
                         let start_room_position = dungeon.current_floor.start_room_position;
                         player_dungeon_stats.current_room_position = start_room_position;
 
-                        ui_center_text_query.single_mut().unwrap().timer =
-                            Timer::new(Duration::from_secs(1), TimerMode::Once);
+                        // the floor banner is hud only, headless runs have none
+                        if let Ok(mut center_text) = ui_center_text_query.single_mut() {
+                            center_text.timer = Timer::new(Duration::from_secs(1), TimerMode::Once);
+                        }
                         let upgrade_type =
                             REWARDS[player_dungeon_stats.current_floor_index - 1].clone();
 
@@ -110,11 +102,11 @@ pub fn end_point_interaction_handle_system(
                                 ..Default::default()
                             })
                             .with_children(|parent| {
-                                menu_box(parent, &scenes_materials.menu_box_materials);
+                                menu_box(parent, &reward_ui.scenes_materials.menu_box_materials);
                                 upgrade_information(
                                     parent,
-                                    &font_materials,
-                                    &dictionary,
+                                    &reward_ui.font_materials,
+                                    &reward_ui.dictionary,
                                     upgrade_type,
                                 );
                             })
@@ -267,51 +259,13 @@ pub fn cooldown_handle(
 }
 
 pub fn collect_reward(
-    mut player_query: Query<(
-        &mut PlayerComponent,
-        &mut SkillComponent,
-        &mut PlayerListEffectsComponent,
-    )>,
-    mut weapon_query: Query<(
-        &mut WeaponComponent,
-        &mut WeaponSwingAttackComponent,
-        &mut WeaponShootAttackComponent,
-    )>,
     mut reward_query: Query<&mut RewardComponent>,
-    upgrade_controller: Res<UpgradeController>,
-    game_data: Res<GameData>,
+    mut upgrade_picker: UpgradePicker,
 ) {
     let mut reward = reward_query.single_mut().unwrap();
 
     if !reward.is_collected {
-        let (mut player, mut player_skill, mut player_list_effects) = player_query.single_mut().unwrap();
-        let skill_type = player_skill.skill.name.clone();
-        let hero_class = player.class.clone();
-
-        match reward.upgrade_type {
-            UpgradeType::Weapon => {
-                let (mut weapon, mut swing_attack, mut shoot_attack) = weapon_query.single_mut().unwrap();
-                if weapon.level < 3 || (weapon.level < 1 && hero_class == HeroClass::Elf) {
-                    let raw_weapons = game_data.get_weapons(hero_class);
-                    let raw_weapon = *raw_weapons
-                        .iter()
-                        .find(|raw_weapon| raw_weapon.level == weapon.level + 1)
-                        .expect("Can't find weapon");
-                    weapon.upgrade_weapon(&raw_weapon);
-                    swing_attack.upgrade(&raw_weapon);
-                    shoot_attack.upgrade(&raw_weapon);
-                }
-            }
-            UpgradeType::Stats => {
-                player.upgrade_stats(upgrade_controller.get_stats_upgrade());
-            }
-            UpgradeType::Effect => {
-                player_list_effects.upgrade(upgrade_controller.get_effect_upgrade());
-            }
-            UpgradeType::Skill => {
-                player_skill.upgrade(upgrade_controller.get_skill_upgrade(skill_type));
-            }
-        }
+        upgrade_picker.pick(reward.upgrade_type.clone());
         reward.is_collected = true;
     }
 }
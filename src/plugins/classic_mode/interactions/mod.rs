@@ -1,3 +1,27 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::plugins::collision::CollisionEvent;
+use crate::plugins::sound::SoundEffect;
+use crate::resources::dungeon::Dungeon;
+use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
+use crate::resources::profile::Profile;
+
 pub mod door;
 pub mod end_point;
 pub mod unlock_room;
+
+// what walking into a door or the end point reads and plays
+#[derive(SystemParam)]
+pub struct InteractionEvents<'w, 's> {
+    collision_events: EventReader<'w, 's, CollisionEvent>,
+    sound_effects: EventWriter<'w, SoundEffect>,
+}
+
+// where the player is in the run, moved on by the doors and the end point
+#[derive(SystemParam)]
+pub struct RunProgress<'w> {
+    dungeon: ResMut<'w, Dungeon>,
+    player_dungeon_stats: ResMut<'w, PlayerDungeonStats>,
+    profile: ResMut<'w, Profile>,
+}
@@ -1,10 +1,14 @@
+use crate::resources::dungeon::navigation_grid::NavigationGrid;
+use crate::resources::dungeon::position::Position;
 use crate::resources::game_data::PauseSceneData;
 use bevy::prelude::*;
 
 use crate::scenes::SceneState;
+use crate::scenes::pause_scene::SaveRunFlag;
 
 pub mod dungeon;
 pub mod interactions;
+pub mod save;
 pub mod ui;
 
 pub struct ClassicModePlugin;
@@ -15,6 +19,8 @@ pub struct ClassicModeData {
     pub ground: Option<Entity>,
     pub walls: Option<Entity>,
     pub end_point: Option<Entity>,
+    // floor index and position of the room which is currently built
+    pub current_room: Option<(usize, Position)>,
 }
 
 impl Plugin for ClassicModePlugin {
@@ -24,13 +30,12 @@ impl Plugin for ClassicModePlugin {
             OnEnter(SceneState::PreClassicMode),
             dungeon::initiate::initiate_classic_mode,
         );
+        app.add_systems(OnExit(SceneState::PreClassicMode), save::cleanup_saved_run);
 
         app.add_systems(
             OnEnter(SceneState::InGameClassicMode),
             (
-                dungeon::ground::ground,
-                dungeon::doors::doors,
-                dungeon::walls::walls,
+                dungeon::room::room_handle_system,
                 dungeon::end_point::end_point,
             ),
         );
@@ -38,14 +43,21 @@ impl Plugin for ClassicModePlugin {
         app.add_systems(
             Update,
             (
-                dungeon::doors::horizontal_doors_system,
-                dungeon::doors::vertical_doors_system,
-                dungeon::walls::temporary_walls_system,
+                (
+                    interactions::door::horizontal_door_interaction_handle,
+                    interactions::door::vertical_door_interaction_handle,
+                    interactions::end_point::end_point_interaction_handle_system,
+                    interactions::unlock_room::cleared_room_check,
+                )
+                    .before(dungeon::room::room_handle_system),
+                dungeon::room::room_handle_system,
+                (
+                    dungeon::doors::horizontal_doors_system,
+                    dungeon::doors::vertical_doors_system,
+                    dungeon::walls::temporary_walls_system,
+                )
+                    .after(dungeon::room::room_handle_system),
                 dungeon::end_point::end_point_handle_system,
-                interactions::door::horizontal_door_interaction_handle,
-                interactions::door::vertical_door_interaction_handle,
-                interactions::end_point::end_point_interaction_handle_system,
-                interactions::unlock_room::cleared_room_check,
             )
                 .run_if(
                     in_state(SceneState::InGameClassicMode)
@@ -65,7 +77,13 @@ impl Plugin for ClassicModePlugin {
                 ),
         );
 
-        app.add_systems(OnExit(SceneState::InGameClassicMode), clean_up_classic_mode);
+        app.add_systems(
+            OnExit(SceneState::InGameClassicMode),
+            (
+                clean_up_classic_mode,
+                save::store_run.run_if(resource_exists::<SaveRunFlag>),
+            ),
+        );
     }
 }
 
@@ -85,4 +103,6 @@ fn clean_up_classic_mode(mut commands: Commands, classic_mode_data: Res<ClassicM
     commands
         .entity(classic_mode_data.ground.unwrap())
         .despawn();
+
+    commands.remove_resource::<NavigationGrid>();
 }
@@ -0,0 +1,66 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use chrono::Local;
+
+use crate::components::player::PlayerComponent;
+use crate::components::player_list_effects::PlayerListEffectsComponent;
+use crate::components::skill::SkillComponent;
+use crate::components::weapon::WeaponComponent;
+use crate::resources::dungeon::Dungeon;
+use crate::resources::monster::monster_spawn_controller::MonsterSpawnController;
+use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
+use crate::resources::profile::Profile;
+use crate::resources::run_rng::RunRng;
+use crate::resources::saved_run::{SavedPlayer, SavedRun};
+use crate::scenes::pause_scene::SaveRunFlag;
+
+// the run wide resources a save is built from
+#[derive(SystemParam)]
+pub struct ClassicRun<'w> {
+    dungeon: Res<'w, Dungeon>,
+    player_dungeon_stats: Res<'w, PlayerDungeonStats>,
+    monster_spawn_controller: Res<'w, MonsterSpawnController>,
+    profile: Res<'w, Profile>,
+    run_rng: Res<'w, RunRng>,
+}
+
+pub fn store_run(
+    player_query: Query<(
+        &PlayerComponent,
+        &Transform,
+        &SkillComponent,
+        &PlayerListEffectsComponent,
+    )>,
+    weapon_query: Query<&WeaponComponent>,
+    run: ClassicRun,
+    mut commands: Commands,
+) {
+    let (player, player_transform, player_skill, player_list_effects) =
+        player_query.single().unwrap();
+    let weapon = weapon_query.single().unwrap();
+
+    let saved_run = SavedRun {
+        profile: run.profile.clone(),
+        dungeon: run.dungeon.clone(),
+        player_dungeon_stats: run.player_dungeon_stats.clone(),
+        player: SavedPlayer::new(
+            player,
+            player_transform,
+            player_skill,
+            player_list_effects,
+            weapon,
+        ),
+        require_monsters: run.monster_spawn_controller.require_monster,
+        killed_monsters: run.monster_spawn_controller.killed_monsters,
+        run_rng: run.run_rng.draws(),
+        saved_time: Local::now().to_rfc3339(),
+    };
+    saved_run.store();
+
+    commands.remove_resource::<SaveRunFlag>();
+}
+
+pub fn cleanup_saved_run(mut commands: Commands) {
+    commands.remove_resource::<SavedRun>();
+    commands.remove_resource::<SavedPlayer>();
+}
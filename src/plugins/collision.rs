@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+
+use crate::components::collider::{Collider, CollisionLayer};
+use crate::resources::game_data::PauseSceneData;
+use crate::resources::spatial_hash::SpatialHash;
+use crate::scenes::SceneState;
+
+pub struct CollisionPlugin;
+
+// `entity` collides with `other`, it is only sent when the mask of `entity` holds `other_layer`
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CollisionEvent {
+    pub entity: Entity,
+    pub layer: CollisionLayer,
+    pub other: Entity,
+    pub other_layer: CollisionLayer,
+}
+
+impl Plugin for CollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpatialHash>();
+        app.add_event::<CollisionEvent>();
+
+        // runs before Update, so every gameplay system of the frame sees the same collisions
+        app.add_systems(
+            PreUpdate,
+            (rebuild_spatial_hash, detect_collisions).chain().run_if(
+                in_state(SceneState::InGameClassicMode)
+                    .or(in_state(SceneState::InGameSurvivalMode))
+                    .and(not(resource_exists::<PauseSceneData>)),
+            ),
+        );
+    }
+}
+
+fn rebuild_spatial_hash(
+    mut spatial_hash: ResMut<SpatialHash>,
+    colliders_query: Query<(Entity, &Collider, &Transform)>,
+) {
+    spatial_hash.clear();
+
+    for (entity, collider, transform) in colliders_query.iter() {
+        spatial_hash.insert(entity, transform.translation, collider);
+    }
+}
+
+fn detect_collisions(
+    spatial_hash: Res<SpatialHash>,
+    colliders_query: Query<(Entity, &Collider, &Transform)>,
+    mut collision_events: EventWriter<CollisionEvent>,
+) {
+    for (entity, collider, transform) in colliders_query.iter() {
+        if collider.mask == 0 {
+            continue;
+        }
+
+        for layer in CollisionLayer::iterator() {
+            if !collider.collides_with(*layer) {
+                continue;
+            }
+
+            for entry in spatial_hash.colliding(*layer, transform.translation, collider) {
+                if entry.entity == entity {
+                    continue;
+                }
+
+                collision_events.write(CollisionEvent {
+                    entity,
+                    layer: collider.layer,
+                    other: entry.entity,
+                    other_layer: *layer,
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,511 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{
+    AssetLoader, LoadContext, LoadState, RecursiveDependencyLoadState, UntypedAssetId,
+};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+
+use crate::config::{
+    DATA_FILE, ENGLISH_LANGUAGE_FILE, LIST_ROOM_FILE, UPGRADES_FILE, VIETNAMESE_LANGUAGE_FILE,
+};
+use crate::resources::dictionary::Dictionary;
+use crate::resources::dungeon::room::Room;
+use crate::resources::dungeon::rooms::{self, Rooms};
+use crate::resources::game_data::{GameData, GameDataFragment, join_errors};
+use crate::resources::glossary::Glossary;
+use crate::resources::language::Language;
+use crate::resources::mod_packs::{
+    FLOORS_DIRECTORY, FloorMap, ModPacks, PackContent, ROOMS_DIRECTORY,
+};
+use crate::resources::setting::Setting;
+use crate::resources::upgrade::upgrade_controller::{UpgradeController, UpgradeList};
+use crate::scenes::SceneState;
+
+type LoadError = Box<dyn std::error::Error + Send + Sync>;
+
+// the data files are read through the asset server so they load on the web build too,
+// with the hot_reload feature a changed file is applied while the game runs
+pub struct ContentPlugin;
+
+impl Plugin for ContentPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<GameData>()
+            .init_asset::<GameDataFragment>()
+            .init_asset::<UpgradeList>()
+            .init_asset::<Glossary>()
+            .init_asset::<Room>()
+            .init_asset::<RoomList>()
+            .init_asset::<FloorMap>()
+            .register_asset_loader(JsonLoader::<GameData>::default())
+            .register_asset_loader(JsonLoader::<GameDataFragment>::default())
+            .register_asset_loader(JsonLoader::<UpgradeList>::default())
+            .register_asset_loader(JsonLoader::<Glossary>::default())
+            .register_asset_loader(RoomLoader)
+            .register_asset_loader(RoomListLoader)
+            .register_asset_loader(FloorLoader);
+
+        app.add_systems(OnEnter(SceneState::LoadingScene), load_content);
+        app.add_systems(
+            Update,
+            (
+                insert_dictionary.run_if(not(resource_exists::<Dictionary>)),
+                insert_content.run_if(not(resource_exists::<GameData>)),
+            )
+                .run_if(in_state(SceneState::LoadingScene)),
+        );
+        app.add_systems(Update, reload_content.run_if(resource_exists::<GameData>));
+    }
+}
+
+// list_room.txt, the rooms it names are loaded along with it
+#[derive(Asset, TypePath)]
+pub struct RoomList {
+    #[dependency]
+    rooms: Vec<Handle<Room>>,
+}
+
+// the json files, which one a path is read as comes from the handle type
+struct JsonLoader<A> {
+    asset: PhantomData<fn() -> A>,
+}
+
+impl<A> Default for JsonLoader<A> {
+    fn default() -> Self {
+        JsonLoader { asset: PhantomData }
+    }
+}
+
+impl<A: Asset + DeserializeOwned> AssetLoader for JsonLoader<A> {
+    type Asset = A;
+    type Settings = ();
+    type Error = LoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<A, LoadError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["json"]
+    }
+}
+
+struct RoomLoader;
+
+impl AssetLoader for RoomLoader {
+    type Asset = Room;
+    type Settings = ();
+    type Error = LoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Room, LoadError> {
+        let contents = read_text(reader).await?;
+        Ok(Room::parse(load_context.path(), &contents)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["txt"]
+    }
+}
+
+struct RoomListLoader;
+
+impl AssetLoader for RoomListLoader {
+    type Asset = RoomList;
+    type Settings = ();
+    type Error = LoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<RoomList, LoadError> {
+        let contents = read_text(reader).await?;
+
+        // the base rooms sit in the same directory a pack keeps its rooms in
+        let rooms = rooms::file_names(&contents)
+            .iter()
+            .map(|file_name| load_context.load(format!("{}/{}", ROOMS_DIRECTORY, file_name)))
+            .collect();
+
+        Ok(RoomList { rooms })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["txt"]
+    }
+}
+
+struct FloorLoader;
+
+impl AssetLoader for FloorLoader {
+    type Asset = FloorMap;
+    type Settings = ();
+    type Error = LoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<FloorMap, LoadError> {
+        let contents = read_text(reader).await?;
+        Ok(FloorMap::parse(load_context.path(), &contents)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["txt"]
+    }
+}
+
+async fn read_text(reader: &mut dyn Reader) -> Result<String, LoadError> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes).await?;
+    Ok(String::from_utf8(bytes)?)
+}
+
+struct PackHandles {
+    game_data: Option<Handle<GameDataFragment>>,
+    upgrades: Option<Handle<UpgradeList>>,
+    rooms: Vec<(String, Handle<Room>)>,
+    floors: Vec<(String, Handle<FloorMap>)>,
+}
+
+#[derive(Resource)]
+struct ContentHandles {
+    vi_glossary: Handle<Glossary>,
+    en_glossary: Handle<Glossary>,
+    game_data: Handle<GameData>,
+    upgrades: Handle<UpgradeList>,
+    room_list: Handle<RoomList>,
+    packs: Vec<PackHandles>,
+    // the packs as they were found, their content is applied to a copy of them
+    mod_packs: ModPacks,
+}
+
+fn load_content(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let mod_packs = ModPacks::new();
+
+    let packs = mod_packs
+        .packs()
+        .iter()
+        .map(|pack| PackHandles {
+            game_data: pack
+                .has_file(DATA_FILE)
+                .then(|| asset_server.load(pack.asset_path(DATA_FILE))),
+            upgrades: pack
+                .has_file(UPGRADES_FILE)
+                .then(|| asset_server.load(pack.asset_path(UPGRADES_FILE))),
+            rooms: pack
+                .files(ROOMS_DIRECTORY)
+                .into_iter()
+                .map(|file| {
+                    let handle = asset_server.load(pack.asset_path(&file));
+                    (file, handle)
+                })
+                .collect(),
+            floors: pack
+                .files(FLOORS_DIRECTORY)
+                .into_iter()
+                .map(|file| {
+                    let handle = asset_server.load(pack.asset_path(&file));
+                    (file, handle)
+                })
+                .collect(),
+        })
+        .collect();
+
+    commands.insert_resource(ContentHandles {
+        vi_glossary: asset_server.load(VIETNAMESE_LANGUAGE_FILE),
+        en_glossary: asset_server.load(ENGLISH_LANGUAGE_FILE),
+        game_data: asset_server.load(DATA_FILE),
+        upgrades: asset_server.load(UPGRADES_FILE),
+        room_list: asset_server.load(LIST_ROOM_FILE),
+        packs,
+        mod_packs: mod_packs.clone(),
+    });
+
+    // the pack sprites are looked up before the pack data is loaded
+    commands.insert_resource(mod_packs);
+}
+
+#[derive(SystemParam)]
+struct ContentAssets<'w> {
+    asset_server: Res<'w, AssetServer>,
+    handles: Res<'w, ContentHandles>,
+    game_data: Res<'w, Assets<GameData>>,
+    fragments: Res<'w, Assets<GameDataFragment>>,
+    upgrade_lists: Res<'w, Assets<UpgradeList>>,
+    glossaries: Res<'w, Assets<Glossary>>,
+    rooms: Res<'w, Assets<Room>>,
+    room_lists: Res<'w, Assets<RoomList>>,
+    floors: Res<'w, Assets<FloorMap>>,
+}
+
+impl ContentAssets<'_> {
+    // a file that failed to load counts as done, it is reported when it is read
+    fn is_loaded(&self, handle: impl Into<UntypedAssetId>) -> bool {
+        matches!(
+            self.asset_server
+                .get_recursive_dependency_load_state(handle),
+            Some(RecursiveDependencyLoadState::Loaded | RecursiveDependencyLoadState::Failed(_))
+        )
+    }
+
+    fn are_glossaries_loaded(&self) -> bool {
+        self.is_loaded(&self.handles.vi_glossary) && self.is_loaded(&self.handles.en_glossary)
+    }
+
+    fn is_everything_loaded(&self) -> bool {
+        let handles = &self.handles;
+
+        self.are_glossaries_loaded()
+            && self.is_loaded(&handles.game_data)
+            && self.is_loaded(&handles.upgrades)
+            && self.is_loaded(&handles.room_list)
+            && handles.packs.iter().all(|pack| {
+                pack.game_data.iter().all(|handle| self.is_loaded(handle))
+                    && pack.upgrades.iter().all(|handle| self.is_loaded(handle))
+                    && pack.rooms.iter().all(|(_, handle)| self.is_loaded(handle))
+                    && pack.floors.iter().all(|(_, handle)| self.is_loaded(handle))
+            })
+    }
+
+    // the last version that loaded stays in the assets when a hot reload fails
+    fn read<A: Asset + Clone>(&self, assets: &Assets<A>, handle: &Handle<A>) -> Result<A, String> {
+        if let Some(asset) = assets.get(handle) {
+            return Ok(asset.clone());
+        }
+
+        match self.asset_server.get_load_state(handle) {
+            Some(LoadState::Failed(err)) => Err(err.to_string()),
+            _ => Err("it is not loaded yet".to_string()),
+        }
+    }
+
+    // the base game can't run without its own files
+    fn read_base<A: Asset + Clone>(&self, assets: &Assets<A>, handle: &Handle<A>) -> A {
+        match self.read(assets, handle) {
+            Ok(asset) => asset,
+            Err(err) => panic!("Can't load game content: {}", err),
+        }
+    }
+
+    fn dictionary(&self, current_language: Language) -> Dictionary {
+        Dictionary::from_glossaries(
+            self.read_base(&self.glossaries, &self.handles.vi_glossary),
+            self.read_base(&self.glossaries, &self.handles.en_glossary),
+            current_language,
+        )
+    }
+
+    fn game_data(&self) -> Result<GameData, String> {
+        let game_data = self.read_base(&self.game_data, &self.handles.game_data);
+
+        match game_data.validate() {
+            Ok(()) => Ok(game_data),
+            Err(errors) => Err(format!(
+                "{} is invalid:\n{}",
+                DATA_FILE,
+                join_errors(&errors)
+            )),
+        }
+    }
+
+    fn upgrade_controller(&self) -> UpgradeController {
+        let upgrade_list = self.read_base(&self.upgrade_lists, &self.handles.upgrades);
+
+        UpgradeController {
+            upgrades: upgrade_list.upgrades,
+        }
+    }
+
+    fn rooms(&self) -> Rooms {
+        let room_list = self
+            .room_lists
+            .get(&self.handles.room_list)
+            .expect("Can't load list room file");
+
+        Rooms::from_rooms(
+            room_list
+                .rooms
+                .iter()
+                .map(|handle| self.read_base(&self.rooms, handle))
+                .collect(),
+        )
+    }
+
+    fn pack_contents(&self) -> Vec<PackContent> {
+        self.handles
+            .packs
+            .iter()
+            .map(|pack| PackContent {
+                game_data: pack
+                    .game_data
+                    .as_ref()
+                    .map(|handle| self.read(&self.fragments, handle)),
+                upgrades: pack.upgrades.as_ref().map(|handle| {
+                    self.read(&self.upgrade_lists, handle)
+                        .map(|upgrade_list| upgrade_list.upgrades)
+                }),
+                rooms: pack
+                    .rooms
+                    .iter()
+                    .map(|(file, handle)| (file.clone(), self.read(&self.rooms, handle)))
+                    .collect(),
+                floors: pack
+                    .floors
+                    .iter()
+                    .map(|(file, handle)| (file.clone(), self.read(&self.floors, handle)))
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+// the loading scene text needs the glossaries before the rest is loaded
+fn insert_dictionary(mut commands: Commands, content: ContentAssets, setting: Res<Setting>) {
+    if content.are_glossaries_loaded() {
+        commands.insert_resource(content.dictionary(setting.get_language()));
+    }
+}
+
+fn insert_content(mut commands: Commands, content: ContentAssets) {
+    if !content.is_everything_loaded() {
+        return;
+    }
+
+    let game_data = match content.game_data() {
+        Ok(game_data) => game_data,
+        Err(err) => panic!("{}", err),
+    };
+
+    apply_content(
+        &mut commands,
+        content.handles.mod_packs.clone(),
+        game_data,
+        content.upgrade_controller(),
+        content.rooms(),
+        &content.pack_contents(),
+    );
+}
+
+#[derive(SystemParam)]
+struct ContentEvents<'w, 's> {
+    game_data: EventReader<'w, 's, AssetEvent<GameData>>,
+    fragments: EventReader<'w, 's, AssetEvent<GameDataFragment>>,
+    upgrade_lists: EventReader<'w, 's, AssetEvent<UpgradeList>>,
+    glossaries: EventReader<'w, 's, AssetEvent<Glossary>>,
+    rooms: EventReader<'w, 's, AssetEvent<Room>>,
+    room_lists: EventReader<'w, 's, AssetEvent<RoomList>>,
+    floors: EventReader<'w, 's, AssetEvent<FloorMap>>,
+}
+
+impl ContentEvents<'_, '_> {
+    // every reader is read so an old event does not trigger a second reload later
+    fn is_anything_modified(&mut self) -> bool {
+        [
+            is_modified(&mut self.game_data),
+            is_modified(&mut self.fragments),
+            is_modified(&mut self.upgrade_lists),
+            is_modified(&mut self.glossaries),
+            is_modified(&mut self.rooms),
+            is_modified(&mut self.room_lists),
+            is_modified(&mut self.floors),
+        ]
+        .contains(&true)
+    }
+}
+
+fn is_modified<A: Asset>(events: &mut EventReader<AssetEvent<A>>) -> bool {
+    events
+        .read()
+        .filter(|event| matches!(event, AssetEvent::Modified { .. }))
+        .count()
+        > 0
+}
+
+// everything is applied again from the loaded files, a run that already started keeps
+// the dungeon it generated but new monsters and upgrades use the new data
+fn reload_content(
+    mut commands: Commands,
+    mut events: ContentEvents,
+    content: ContentAssets,
+    dictionary: Res<Dictionary>,
+) {
+    if !events.is_anything_modified() || !content.is_everything_loaded() {
+        return;
+    }
+
+    let game_data = match content.game_data() {
+        Ok(game_data) => game_data,
+        Err(err) => {
+            warn!("Content not reloaded, {}", err);
+            return;
+        }
+    };
+
+    apply_content(
+        &mut commands,
+        content.handles.mod_packs.clone(),
+        game_data,
+        content.upgrade_controller(),
+        content.rooms(),
+        &content.pack_contents(),
+    );
+    commands.insert_resource(content.dictionary(dictionary.get_current_language()));
+
+    info!("Content reloaded");
+}
+
+// reads everything right away, headless runs have no loading scene and every run has
+// to start on the same frame
+pub fn load_data(mut commands: Commands) {
+    let mod_packs = ModPacks::new();
+    let contents = mod_packs.read_content();
+
+    apply_content(
+        &mut commands,
+        mod_packs,
+        GameData::new(),
+        UpgradeController::new(),
+        Rooms::new(),
+        &contents,
+    );
+}
+
+// packs are applied on top of the base data before anything reads it
+fn apply_content(
+    commands: &mut Commands,
+    mut mod_packs: ModPacks,
+    mut game_data: GameData,
+    mut upgrade_controller: UpgradeController,
+    mut rooms: Rooms,
+    contents: &[PackContent],
+) {
+    mod_packs.apply_game_data(&mut game_data, contents);
+    mod_packs.apply_upgrades(&mut upgrade_controller, &game_data, contents);
+    mod_packs.apply_rooms(&mut rooms, contents);
+    let mod_floors = mod_packs.load_floors(&rooms, contents);
+
+    mod_packs.report();
+
+    commands.insert_resource(game_data);
+    commands.insert_resource(upgrade_controller);
+    commands.insert_resource(rooms);
+    commands.insert_resource(mod_floors);
+    commands.insert_resource(mod_packs);
+}
@@ -12,42 +12,62 @@ use crate::components::skill::SkillComponent;
 use crate::components::weapon::WeaponComponent;
 use crate::components::weapon_shoot_attack::WeaponShootAttackComponent;
 use crate::components::weapon_swing_attack::WeaponSwingAttackComponent;
+use crate::plugins::sound::SoundEffect;
 use crate::resources::animation_state::AnimationState;
 use crate::resources::effect::effect_type::EffectType;
+use crate::resources::game_data::GameData;
+use crate::resources::run_rng::RunRng;
 use crate::resources::skill::skill_type::SkillType;
 use crate::resources::weapon::attack_type::AttackType;
-use crate::resources::weapon::weapon_type::WeaponType;
+use crate::utils::damage::{Attacker, DamageSource, Defender, damage_monster, resolve_damage};
 
 pub fn use_skill(
-    mut player_query: Query<(&mut PlayerComponent, &mut SkillComponent)>,
+    mut player_query: Query<(
+        &mut PlayerComponent,
+        &mut SkillComponent,
+        &PlayerListEffectsComponent,
+    )>,
     mut monsters_query: Query<(
         &mut MonsterComponent,
         &mut InvisibleCooldownComponent,
         &mut MonsterListEffectsComponent,
     )>,
     mut keyboard_input: ResMut<ButtonInput<KeyCode>>,
+    mut run_rng: ResMut<RunRng>,
+    mut sound_effects: EventWriter<SoundEffect>,
+    game_data: Res<GameData>,
 ) {
     if keyboard_input.pressed(KeyCode::Space) {
-        let (mut player, mut player_skill) = player_query.single_mut().unwrap();
+        let (mut player, mut player_skill, player_list_effects) =
+            player_query.single_mut().unwrap();
 
-        if player_skill.cooldown.finished() {
+        if player_skill.cooldown.finished() && !player_list_effects.is_active(EffectType::Stun) {
             let skill = player_skill.skill.clone();
 
             match player_skill.skill.name {
                 SkillType::Thunderstorm => {
+                    let attacker = Attacker::player(&player, player.intelligence)
+                        .with_effect(Some(EffectType::Stun), 1.0);
+
                     for (mut monster, mut invincible_cooldown, mut monster_list_effects) in
                         monsters_query.iter_mut()
                     {
-                        let damage = player.intelligence;
-                        monster.current_health_points = if monster.current_health_points < damage {
-                            0.0
-                        } else {
-                            monster.current_health_points - damage
-                        };
+                        let defender = Defender::monster(&monster_list_effects);
+                        let damage = resolve_damage(
+                            &attacker,
+                            &defender,
+                            DamageSource::Thunderstorm,
+                            &mut run_rng.combat,
+                        );
+                        damage_monster(
+                            &damage,
+                            &mut monster,
+                            &mut monster_list_effects,
+                            &game_data,
+                        );
 
                         invincible_cooldown.hurt_duration =
-                            Timer::new(Duration::from_secs_f32(0.2), TimerMode::Once);
-                        monster_list_effects.activate(EffectType::Stun);
+                            Timer::new(damage.hurt_duration(), TimerMode::Once);
                     }
                 }
                 SkillType::TimeToHunt => {
@@ -70,6 +90,7 @@ pub fn use_skill(
 
             let cooldown = skill.cooldown.expect("No skill received. Try archer :)") as u64;
             player_skill.cooldown = Timer::new(Duration::from_secs(cooldown), TimerMode::Once);
+            sound_effects.write(SoundEffect::SkillCast);
         }
         keyboard_input.reset(KeyCode::Space);
     }
@@ -84,42 +105,58 @@ pub fn use_mouse(
     mut player_list_effects_query: Query<&mut PlayerListEffectsComponent>,
     mut buttons: ResMut<ButtonInput<MouseButton>>,
     player_animation_query: Query<&PlayerAnimation>,
+    mut run_rng: ResMut<RunRng>,
+    mut sound_effects: EventWriter<SoundEffect>,
 ) {
     if buttons.just_pressed(MouseButton::Left) {
+        let mut player_list_effects = player_list_effects_query.single_mut().unwrap();
+        if player_list_effects.is_active(EffectType::Stun)
+            || player_list_effects.is_active(EffectType::Disarm)
+        {
+            buttons.clear_just_pressed(MouseButton::Left);
+            return;
+        }
+
         let (weapon_component, mut weapon_swing_attack, mut weapon_shoot_attack) =
             weapon_query.single_mut().unwrap();
         let player_animation = player_animation_query.single().unwrap();
 
+        let mut attacked = false;
+
         match weapon_component.attack_type {
             AttackType::Swing => {
                 if weapon_swing_attack.attack_duration.finished() {
                     weapon_swing_attack.attack_duration =
                         Timer::new(Duration::from_secs_f32(0.5), TimerMode::Once);
+                    sound_effects.write(SoundEffect::Swing);
+                    attacked = true;
                 }
             }
             AttackType::Shoot => {
-                if weapon_shoot_attack.cooldown.finished() {
-                    if weapon_component.name == WeaponType::Spear
-                        || player_animation.animation_state == AnimationState::Idle
-                    {
-                        weapon_shoot_attack.spawn_bullet = true;
-                        weapon_shoot_attack.cooldown = Timer::new(
-                            Duration::from_secs(weapon_shoot_attack.cooldown_second),
-                            TimerMode::Once,
-                        );
-                    }
-
-                    if weapon_component.name == WeaponType::Spear {
-                        let mut player_list_effects = player_list_effects_query.single_mut().unwrap();
-                        let buff_effect = weapon_component.buff_effect.unwrap();
-                        let mut rng = rand::thread_rng();
-                        if rng.gen_range(0.0..1.0) < weapon_component.trigger_chance {
-                            player_list_effects.activate(buff_effect);
-                        }
-                    }
+                if weapon_shoot_attack.cooldown.finished()
+                    && (weapon_component.shoot_while_moving
+                        || player_animation.animation_state == AnimationState::Idle)
+                {
+                    weapon_shoot_attack.spawn_bullet = true;
+                    weapon_shoot_attack.cooldown = Timer::new(
+                        Duration::from_secs(weapon_shoot_attack.cooldown_second),
+                        TimerMode::Once,
+                    );
+                    sound_effects.write(SoundEffect::Shoot);
+                    attacked = true;
                 }
             }
         };
+
+        if attacked
+            && let Some(buff_effect) = weapon_component.buff_effect
+            && run_rng.combat.gen_range(0.0..1.0) < weapon_component.trigger_chance
+        {
+            match weapon_component.effect_bonus {
+                Some(bonus) => player_list_effects.activate_with_bonus(buff_effect, bonus),
+                None => player_list_effects.activate(buff_effect),
+            }
+        }
         buttons.clear_just_pressed(MouseButton::Left);
     }
 }
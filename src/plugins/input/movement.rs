@@ -1,46 +1,85 @@
 use bevy::prelude::*;
 
+use crate::components::collider::Collider;
 use crate::components::player::PlayerComponent;
 use crate::components::player_animation::PlayerAnimation;
+use crate::components::player_list_effects::PlayerListEffectsComponent;
 use crate::config::*;
+use crate::plugins::collision::CollisionEvent;
 use crate::plugins::player::collisions::wall_collision_check;
 use crate::resources::animation_state::AnimationState;
 use crate::resources::dungeon::block_type::BlockType;
+use crate::resources::effect::effect_type::EffectType;
 
 pub fn player_movement_handle_system(
-    mut player_query: Query<(&PlayerComponent, &mut PlayerAnimation, &mut Transform)>,
-    block_type_query: Query<(&BlockType, &Transform), Without<PlayerComponent>>,
+    mut player_query: Query<(
+        Entity,
+        &PlayerComponent,
+        &PlayerListEffectsComponent,
+        &Collider,
+        &mut PlayerAnimation,
+        &mut Transform,
+    )>,
+    block_query: Query<(&BlockType, &Collider, &Transform), Without<PlayerComponent>>,
+    mut collision_events: EventReader<CollisionEvent>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
     time: Res<Time>,
 ) {
-    let (player_stats, mut player_animation, mut transform) = player_query.single_mut().unwrap();
+    let (
+        player_entity,
+        player_stats,
+        player_list_effects,
+        player_collider,
+        mut player_animation,
+        mut transform,
+    ) = player_query.single_mut().unwrap();
 
     let mut delta = Vec3::new(0.0, 0.0, 0.0);
 
     let player_position = transform.translation;
     player_animation.animation_state = AnimationState::Idle;
 
-    let player_availalbe_movement = wall_collision_check(player_position, &block_type_query);
+    if player_list_effects.is_active(EffectType::Stun) {
+        collision_events.clear();
+        return;
+    }
+
+    // confused players walk the opposite way of the pressed key
+    let (up_key, down_key, left_key, right_key) =
+        if player_list_effects.is_active(EffectType::Confuse) {
+            (KeyCode::KeyS, KeyCode::KeyW, KeyCode::KeyD, KeyCode::KeyA)
+        } else {
+            (KeyCode::KeyW, KeyCode::KeyS, KeyCode::KeyA, KeyCode::KeyD)
+        };
+
+    let collisions: Vec<CollisionEvent> = collision_events.read().copied().collect();
+    let player_availalbe_movement = wall_collision_check(
+        player_entity,
+        player_position,
+        player_collider,
+        &collisions,
+        &block_query,
+    );
 
-    if keyboard_input.pressed(KeyCode::KeyW) {
+    if keyboard_input.pressed(up_key) {
         if player_availalbe_movement.can_move_up {
             delta.y += player_stats.speed * TILE_SIZE * time.delta_secs();
         }
     }
 
-    if keyboard_input.pressed(KeyCode::KeyS) {
+    if keyboard_input.pressed(down_key) {
         if player_availalbe_movement.can_move_down {
             delta.y -= player_stats.speed * TILE_SIZE * time.delta_secs();
         }
     }
 
-    if keyboard_input.pressed(KeyCode::KeyA) {
+    if keyboard_input.pressed(left_key) {
         if player_availalbe_movement.can_move_left {
             delta.x -= player_stats.speed * TILE_SIZE * time.delta_secs();
         }
     }
 
-    if keyboard_input.pressed(KeyCode::KeyD) {
+    if keyboard_input.pressed(right_key) {
         if player_availalbe_movement.can_move_right {
             delta.x += player_stats.speed * TILE_SIZE * time.delta_secs();
         }
@@ -1,9 +1,32 @@
+use bevy::app::PluginGroupBuilder;
+use bevy::prelude::*;
+
 pub mod camera;
 pub mod classic_mode;
+pub mod collision;
+pub mod content;
 pub mod debug;
 pub mod input;
 pub mod monster;
+#[cfg(feature = "render")]
 pub mod music;
 pub mod player;
+pub mod sound;
 pub mod survival_mode;
 pub mod weapon;
+
+// everything a run needs to play out, shared by the game window and headless runs
+pub struct GameplayPlugins;
+
+impl PluginGroup for GameplayPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        PluginGroupBuilder::start::<Self>()
+            .add(input::InputHandlePlugin)
+            .add(player::PlayerPlugin)
+            .add(weapon::WeaponPlugin)
+            .add(classic_mode::ClassicModePlugin)
+            .add(survival_mode::SurvivalModePlugin)
+            .add(monster::MonsterPlugin)
+            .add(collision::CollisionPlugin)
+    }
+}
@@ -2,15 +2,23 @@ use bevy::prelude::*;
 use rand::Rng;
 
 use crate::{
-    components::{monster::MonsterComponent, potion::PotionComponent, skill::SkillComponent},
+    components::{
+        collider::{Collider, CollisionLayer},
+        monster::MonsterComponent,
+        player::PlayerComponent,
+        potion::PotionComponent,
+        skill::SkillComponent,
+    },
     materials::ingame::InGameMaterials,
     resources::{
         monster::monster_spawn_controller::MonsterSpawnController,
         player::player_dungeon_stats::PlayerDungeonStats, potion::potion_type::PotionType,
-        profile::Profile, skill::skill_type::SkillType,
+        profile::Profile, run_rng::RunRng, skill::skill_type::SkillType,
     },
 };
 
+const RESTORE_HEALTH_POINTS: f32 = 1.0;
+
 pub fn cleanup_monsters(
     mut monsters_query: Query<Entity, With<MonsterComponent>>,
     mut commands: Commands,
@@ -23,12 +31,13 @@ pub fn cleanup_monsters(
 pub fn cleanup_killed_monsters(
     mut monster_spawn_controller: ResMut<MonsterSpawnController>,
     mut monsters_query: Query<(Entity, &Transform, &MonsterComponent)>,
-    mut player_skill_query: Query<&mut SkillComponent>,
+    mut player_query: Query<(&mut PlayerComponent, &mut SkillComponent)>,
     ingame_materials: Res<InGameMaterials>,
     mut profile: ResMut<Profile>,
+    mut run_rng: ResMut<RunRng>,
     mut commands: Commands,
 ) {
-    let mut player_skill = player_skill_query.single_mut().unwrap();
+    let (mut player, mut player_skill) = player_query.single_mut().unwrap();
     for (monster_entity, monster_transform, monster) in monsters_query.iter_mut() {
         if monster.current_health_points == 0.0 {
             if player_skill.skill.name == SkillType::Armor {
@@ -39,7 +48,14 @@ pub fn cleanup_killed_monsters(
             profile.total_killed_monsters += 1;
             commands.entity(monster_entity).despawn();
 
-            let mut rng = rand::thread_rng();
+            // every kill has a chance to restore one health point
+            if run_rng.combat.gen_range(0.0..1.0) < player.restore_chance {
+                player.current_health_points = (player.current_health_points
+                    + RESTORE_HEALTH_POINTS)
+                    .min(player.max_health_points);
+            }
+
+            let rng = &mut run_rng.loot;
             let chance = rng.gen_range(0.0..1.0);
 
             if chance < 0.25 {
@@ -81,11 +97,12 @@ pub fn cleanup_killed_monsters(
                             ..Default::default()
                         },
                     ))
-                    .insert(PotionComponent {
-                        potion_type,
-                        width: 16.0 * 2.0,
-                        height: 16.0 * 2.0,
-                    })
+                    .insert(PotionComponent { potion_type })
+                    .insert(Collider::rectangle(
+                        16.0 * 2.0,
+                        16.0 * 2.0,
+                        CollisionLayer::Potion,
+                    ))
                     .insert(Name::new(component_name));
             }
         }
@@ -1,10 +1,10 @@
+#[cfg(feature = "render")]
 use bevy::color::palettes::css::*;
 use bevy::prelude::*;
 
-use crate::{
-    components::monster_list_effects::MonsterListEffectsComponent,
-    resources::effect::effect_type::EffectType,
-};
+use crate::components::monster_list_effects::MonsterListEffectsComponent;
+#[cfg(feature = "render")]
+use crate::resources::effect::effect_type::EffectType;
 
 pub fn update_effects(
     mut monsters_effects_query: Query<&mut MonsterListEffectsComponent>,
@@ -19,6 +19,7 @@ pub fn update_effects(
     }
 }
 
+#[cfg(feature = "render")]
 pub fn update_color_of_effects(
     mut monsters_effects_query: Query<(&MonsterListEffectsComponent, &mut Sprite)>,
 ) {
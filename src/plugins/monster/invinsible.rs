@@ -1,3 +1,4 @@
+#[cfg(feature = "render")]
 use bevy::color::palettes::css::*;
 use bevy::prelude::*;
 
@@ -5,17 +6,28 @@ use crate::components::{
     invinsible_cooldown::InvisibleCooldownComponent, monster::MonsterComponent,
 };
 
+// a monster can't be hit by the same swing again until this runs out
+pub fn hurt_duration(
+    mut invincible_cooldown_query: Query<&mut InvisibleCooldownComponent, With<MonsterComponent>>,
+    time: Res<Time>,
+) {
+    for mut invincible_cooldown in invincible_cooldown_query.iter_mut() {
+        if !invincible_cooldown.hurt_duration.finished() {
+            invincible_cooldown.hurt_duration.tick(time.delta());
+        }
+    }
+}
+
+#[cfg(feature = "render")]
 pub fn hurt_duration_color(
     mut invincible_cooldown_query: Query<
-        (&mut InvisibleCooldownComponent, &mut Sprite),
+        (&InvisibleCooldownComponent, &mut Sprite),
         With<MonsterComponent>,
     >,
-    time: Res<Time>,
 ) {
-    for (mut invincible_cooldown, mut texture) in invincible_cooldown_query.iter_mut() {
+    for (invincible_cooldown, mut texture) in invincible_cooldown_query.iter_mut() {
         if !invincible_cooldown.hurt_duration.finished() {
             texture.color = Color::from(RED);
-            invincible_cooldown.hurt_duration.tick(time.delta());
         } else {
             texture.color = Color::default();
         }
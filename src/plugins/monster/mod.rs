@@ -1,13 +1,17 @@
+#[cfg(feature = "render")]
+use crate::headless::HeadlessFlag;
 use crate::resources::game_data::PauseSceneData;
 use bevy::prelude::*;
 
 use crate::scenes::SceneState;
 
+#[cfg(feature = "render")]
 mod animation;
 mod cleanup;
 mod effect;
 mod invinsible;
 mod movement;
+mod skill;
 mod spawn;
 
 pub struct MonsterPlugin;
@@ -17,13 +21,35 @@ impl Plugin for MonsterPlugin {
         app.add_systems(
             Update,
             (
-                animation::animation_handle,
-                movement::move_to_player,
+                movement::update_flow_field,
+                movement::move_to_player.after(movement::update_flow_field),
                 effect::update_effects,
                 movement::change_direction,
-                effect::update_color_of_effects,
+                skill::split_killed_monsters.before(cleanup::cleanup_killed_monsters),
                 cleanup::cleanup_killed_monsters,
-                invinsible::hurt_duration_color.after(effect::update_color_of_effects),
+                invinsible::hurt_duration,
+            )
+                .run_if(
+                    in_state(SceneState::InGameClassicMode)
+                        .or(in_state(SceneState::InGameSurvivalMode))
+                        .and(not(resource_exists::<PauseSceneData>)),
+                ),
+        );
+
+        app.add_systems(
+            Update,
+            (
+                skill::start_skills,
+                skill::telegraph_skills.after(skill::start_skills),
+                (
+                    skill::ranged_skill,
+                    skill::charge_skill,
+                    skill::summon_skill,
+                    skill::heal_skill,
+                    skill::slam_skill,
+                )
+                    .after(skill::telegraph_skills),
+                skill::monster_bullets_collision,
             )
                 .run_if(
                     in_state(SceneState::InGameClassicMode)
@@ -54,5 +80,23 @@ impl Plugin for MonsterPlugin {
             OnExit(SceneState::InGameSurvivalMode),
             cleanup::cleanup_monsters,
         );
+
+        // animations and colors only, monsters behave the same without them
+        #[cfg(feature = "render")]
+        app.add_systems(
+            Update,
+            (
+                animation::animation_handle,
+                effect::update_color_of_effects,
+                invinsible::hurt_duration_color.after(effect::update_color_of_effects),
+                skill::telegraph_color.after(invinsible::hurt_duration_color),
+            )
+                .run_if(
+                    in_state(SceneState::InGameClassicMode)
+                        .or(in_state(SceneState::InGameSurvivalMode))
+                        .and(not(resource_exists::<PauseSceneData>))
+                        .and(not(resource_exists::<HeadlessFlag>)),
+                ),
+        );
     }
 }
@@ -2,26 +2,56 @@ use bevy::prelude::*;
 
 use crate::{
     components::{
-        monster::MonsterComponent, monster_animation::MonsterAnimationComponent,
-        monster_list_effects::MonsterListEffectsComponent, player::PlayerComponent,
+        collider::{Collider, CollisionLayer},
+        monster::MonsterComponent,
+        monster_animation::MonsterAnimationComponent,
+        monster_list_effects::MonsterListEffectsComponent,
+        monster_skill::{MonsterSkillComponent, MonsterSkillState},
+        player::PlayerComponent,
         skill::SkillComponent,
     },
+    plugins::{collision::CollisionEvent, player::collisions::wall_collision_check},
     resources::{
-        animation_state::AnimationState, effect::effect_type::EffectType,
+        animation_state::AnimationState,
+        dungeon::{block_type::BlockType, navigation_grid::NavigationGrid},
+        effect::effect_type::EffectType,
+        monster::monster_skill::MonsterSkill,
+        player::player_available_movement::PlayerAvailableMovement,
         skill::skill_type::SkillType,
+        spatial_hash::SpatialHash,
     },
 };
 
+const MONSTER_BODY_SCALE: f32 = 0.6;
+const SEPARATION_STRENGTH: f32 = 0.8;
+
+pub fn update_flow_field(
+    player_query: Query<&Transform, With<PlayerComponent>>,
+    navigation_grid: Option<ResMut<NavigationGrid>>,
+) {
+    if let Some(mut navigation_grid) = navigation_grid {
+        let player_transform = player_query.single().unwrap();
+        navigation_grid.update_flow_field(player_transform.translation.truncate());
+    }
+}
+
 pub fn move_to_player(
     mut set: ParamSet<(
         Query<(&Transform, &SkillComponent), With<PlayerComponent>>,
         Query<(
+            Entity,
             &MonsterComponent,
+            &Collider,
             &mut MonsterListEffectsComponent,
             &mut MonsterAnimationComponent,
             &mut Transform,
+            Option<&MonsterSkillComponent>,
         )>,
     )>,
+    block_query: Query<(&BlockType, &Collider, &Transform), Without<MonsterComponent>>,
+    mut collision_events: EventReader<CollisionEvent>,
+    spatial_hash: Res<SpatialHash>,
+    navigation_grid: Option<Res<NavigationGrid>>,
 ) {
     let mut should_move = true;
     let mut target = Vec3::new(0.0, 0.0, 0.15);
@@ -36,23 +66,113 @@ pub fn move_to_player(
         target.y = transform.translation.y;
     }
 
-    for (monster_component, monster_list_effects, mut monster_animation_component, mut transform) in
-        set.p1().iter_mut()
+    let collisions: Vec<CollisionEvent> = collision_events.read().copied().collect();
+
+    for (
+        entity,
+        monster_component,
+        collider,
+        monster_list_effects,
+        mut monster_animation_component,
+        mut transform,
+        monster_skill,
+    ) in set.p1().iter_mut()
     {
-        let stun_effect = monster_list_effects
-            .activated_effects
-            .get(&EffectType::Stun);
+        let skill_state = monster_skill.map(|monster_skill| monster_skill.state);
 
-        if !stun_effect.unwrap().finished() || !should_move {
+        let delta = if monster_list_effects.is_active(EffectType::Stun) || !should_move {
+            monster_animation_component.animation_state = AnimationState::Idle;
+            Vec3::ZERO
+        } else if skill_state == Some(MonsterSkillState::Charging) {
+            // a charge keeps the direction it started with instead of following the player
+            let monster_skill = monster_skill.unwrap();
+            monster_animation_component.animation_state = AnimationState::Moving;
+            let charge_speed = match monster_skill.skill {
+                MonsterSkill::Charge { speed, .. } => speed,
+                _ => 0.0,
+            };
+            (monster_skill.direction * charge_speed).extend(0.0)
+        } else if monster_skill.is_some_and(|monster_skill| monster_skill.is_busy()) {
             monster_animation_component.animation_state = AnimationState::Idle;
+            Vec3::ZERO
         } else {
             monster_animation_component.animation_state = AnimationState::Moving;
-            let dir = (target - transform.translation).normalize();
-            let mut new_position = transform.translation + dir * monster_component.speed * 0.2;
-            new_position.z = 0.15;
-            transform.translation = new_position;
+            let dir = navigation_grid
+                .as_ref()
+                .and_then(|navigation_grid| {
+                    navigation_grid.direction(transform.translation.truncate())
+                })
+                .map(|direction| direction.extend(0.0))
+                .unwrap_or_else(|| (target - transform.translation).normalize());
+
+            let separation = separation(
+                entity,
+                transform.translation,
+                &collider.scaled(MONSTER_BODY_SCALE),
+                &spatial_hash,
+            );
+
+            let slow = monster_list_effects.bonus(EffectType::Slow);
+            (dir + separation).clamp_length_max(1.0) * monster_component.speed * (1.0 - slow) * 0.2
+        };
+
+        let available_movement = wall_collision_check(
+            entity,
+            transform.translation,
+            collider,
+            &collisions,
+            &block_query,
+        );
+
+        let mut new_position = transform.translation + block_movement(delta, &available_movement);
+        new_position.z = 0.15;
+        transform.translation = new_position;
+    }
+}
+
+// overlapping monsters push each other apart, so a crowd spreads out around the player.
+// the sprites have empty space around the monster, only the middle of them counts as the body
+fn separation(entity: Entity, position: Vec3, body: &Collider, spatial_hash: &SpatialHash) -> Vec3 {
+    let mut push = Vec2::ZERO;
+
+    for entry in spatial_hash.query(CollisionLayer::Monster, position, body) {
+        let other_body = entry.collider.scaled(MONSTER_BODY_SCALE);
+        if entry.entity == entity || !body.intersects(position, &other_body, entry.translation) {
+            continue;
         }
+
+        let away = (position - entry.translation).truncate();
+        push += if away == Vec2::ZERO {
+            // monsters on the same pixel split along the x axis
+            if entity < entry.entity {
+                Vec2::X
+            } else {
+                Vec2::NEG_X
+            }
+        } else {
+            away.normalize()
+        };
+    }
+
+    (push.normalize_or_zero() * SEPARATION_STRENGTH).extend(0.0)
+}
+
+fn block_movement(delta: Vec3, available_movement: &PlayerAvailableMovement) -> Vec3 {
+    let mut delta = delta;
+
+    if (delta.x < 0.0 && !available_movement.can_move_left)
+        || (delta.x > 0.0 && !available_movement.can_move_right)
+    {
+        delta.x = 0.0;
     }
+
+    if (delta.y < 0.0 && !available_movement.can_move_down)
+        || (delta.y > 0.0 && !available_movement.can_move_up)
+    {
+        delta.y = 0.0;
+    }
+
+    delta
 }
 
 pub fn change_direction(
@@ -0,0 +1,485 @@
+use bevy::color::palettes::css::*;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use rand::Rng;
+use std::time::Duration;
+
+use crate::components::bullet::{BulletComponent, MonsterBulletComponent};
+use crate::components::collider::{Collider, CollisionLayer};
+use crate::components::invinsible_cooldown::InvisibleCooldownComponent;
+use crate::components::monster::MonsterComponent;
+use crate::components::monster_list_effects::MonsterListEffectsComponent;
+use crate::components::monster_skill::{MonsterSkillComponent, MonsterSkillState};
+use crate::components::player::PlayerComponent;
+use crate::components::player_animation::PlayerAnimation;
+use crate::components::player_list_effects::PlayerListEffectsComponent;
+use crate::config::TILE_SIZE;
+use crate::materials::ingame::InGameMaterials;
+use crate::plugins::collision::CollisionEvent;
+use crate::plugins::monster::spawn::spawn_monster;
+use crate::plugins::player::PlayerHurtEvent;
+use crate::plugins::player::dodge::{DodgeEvent, try_dodge};
+use crate::plugins::sound::SoundEffect;
+use crate::resources::effect::effect_type::EffectType;
+use crate::resources::game_data::GameData;
+use crate::resources::game_mode::GameMode;
+use crate::resources::monster::Monster;
+use crate::resources::monster::monster_skill::MonsterSkill;
+use crate::resources::monster::monster_spawn_controller::MonsterSpawnController;
+use crate::resources::run_rng::RunRng;
+use crate::utils::damage::{
+    Attacker, DamageSource, Defender, hurt_player, monster_damage_percent_bonus, resolve_damage,
+};
+
+const MONSTER_BULLET_SIZE: f32 = 16.0 * 2.0;
+
+pub fn split_killed_monsters(
+    monsters_query: Query<(&Transform, &MonsterComponent)>,
+    mut monster_spawn_controller: ResMut<MonsterSpawnController>,
+    mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
+    ingame_materials: Res<InGameMaterials>,
+    game_data: Res<GameData>,
+    mut run_rng: ResMut<RunRng>,
+    mut commands: Commands,
+) {
+    for (monster_transform, monster) in monsters_query.iter() {
+        if monster.current_health_points != 0.0 {
+            continue;
+        }
+
+        let Some(MonsterSkill::Split {
+            monster_class,
+            count,
+        }) = monster.skill.as_ref()
+        else {
+            continue;
+        };
+
+        let raw_monster = game_data.get_monster(monster_class.clone());
+        let death_position = monster_transform.translation.truncate();
+
+        for _ in 0..*count {
+            spawn_minion(
+                &mut commands,
+                &mut texture_atlases,
+                &ingame_materials,
+                &mut monster_spawn_controller,
+                &mut run_rng.spawns,
+                &raw_monster,
+                death_position,
+            );
+        }
+    }
+}
+
+// counts the minion as a monster of the room so classic mode rooms only clear once it is killed too
+fn spawn_minion(
+    commands: &mut Commands,
+    texture_atlases: &mut Assets<TextureAtlasLayout>,
+    ingame_materials: &InGameMaterials,
+    monster_spawn_controller: &mut MonsterSpawnController,
+    rng: &mut impl Rng,
+    raw_monster: &Monster,
+    position: Vec2,
+) {
+    let half_tile = TILE_SIZE / 2.0;
+    let position = position
+        + Vec2::new(
+            rng.gen_range(-half_tile..half_tile),
+            rng.gen_range(-half_tile..half_tile),
+        );
+    let component_name = format!("Monster {}", monster_spawn_controller.alive_monsters);
+
+    spawn_monster(
+        commands,
+        texture_atlases,
+        ingame_materials,
+        raw_monster,
+        position,
+        component_name,
+    );
+
+    monster_spawn_controller.alive_monsters += 1;
+    if monster_spawn_controller.game_mode == GameMode::ClassicMode {
+        monster_spawn_controller.require_monster += 1;
+    }
+}
+
+pub fn start_skills(
+    mut monsters_query: Query<
+        (
+            &MonsterComponent,
+            &MonsterListEffectsComponent,
+            &Transform,
+            &mut MonsterSkillComponent,
+        ),
+        Without<PlayerComponent>,
+    >,
+    player_query: Query<&Transform, With<PlayerComponent>>,
+    monster_spawn_controller: Res<MonsterSpawnController>,
+    time: Res<Time>,
+) {
+    let player_position = player_query.single().unwrap().translation.truncate();
+
+    for (monster, monster_list_effects, transform, mut monster_skill) in monsters_query.iter_mut() {
+        if monster_skill.state != MonsterSkillState::Ready {
+            continue;
+        }
+
+        monster_skill.cooldown.tick(time.delta());
+        if !monster_skill.cooldown.finished() {
+            continue;
+        }
+
+        let is_stunned = monster_list_effects.is_active(EffectType::Stun);
+
+        let distance = transform.translation.truncate().distance(player_position);
+        let is_in_range = match monster_skill.skill.range() {
+            Some(range) => distance <= range,
+            None => true,
+        };
+
+        let is_needed = match monster_skill.skill {
+            MonsterSkill::Heal { .. } => monster.current_health_points < monster.max_health_points,
+            MonsterSkill::Summon { .. } => {
+                monster_spawn_controller.alive_monsters
+                    < monster_spawn_controller.max_avalible_monsters
+            }
+            _ => true,
+        };
+
+        if !is_stunned && is_in_range && is_needed {
+            monster_skill.state = MonsterSkillState::Telegraphing;
+            monster_skill.telegraph.reset();
+            monster_skill.target = player_position;
+        }
+    }
+}
+
+pub fn telegraph_skills(
+    mut monsters_query: Query<(&MonsterListEffectsComponent, &mut MonsterSkillComponent)>,
+    time: Res<Time>,
+) {
+    for (monster_list_effects, mut monster_skill) in monsters_query.iter_mut() {
+        if monster_skill.state != MonsterSkillState::Telegraphing {
+            continue;
+        }
+
+        // a stunned monster holds its telegraph until the stun wears off
+        let is_stunned = monster_list_effects.is_active(EffectType::Stun);
+        if is_stunned {
+            continue;
+        }
+
+        monster_skill.telegraph.tick(time.delta());
+        if monster_skill.telegraph.finished() {
+            monster_skill.state = MonsterSkillState::Casting;
+        }
+    }
+}
+
+#[cfg(feature = "render")]
+pub fn telegraph_color(mut monsters_query: Query<(&MonsterSkillComponent, &mut Sprite)>) {
+    for (monster_skill, mut sprite) in monsters_query.iter_mut() {
+        if monster_skill.state == MonsterSkillState::Telegraphing {
+            sprite.color = Color::from(ORANGE);
+        }
+    }
+}
+
+pub fn ranged_skill(
+    mut monsters_query: Query<(
+        &Transform,
+        &MonsterComponent,
+        &MonsterListEffectsComponent,
+        &mut MonsterSkillComponent,
+    )>,
+    ingame_materials: Res<InGameMaterials>,
+    mut commands: Commands,
+) {
+    for (transform, monster, monster_list_effects, mut monster_skill) in monsters_query.iter_mut() {
+        if monster_skill.state != MonsterSkillState::Casting {
+            continue;
+        }
+
+        let MonsterSkill::Ranged { damage, speed, .. } = monster_skill.skill else {
+            continue;
+        };
+
+        commands
+            .spawn((
+                Sprite {
+                    image: ingame_materials.bullet_materials.bullet.clone(),
+                    custom_size: Some(Vec2::new(MONSTER_BULLET_SIZE, MONSTER_BULLET_SIZE)),
+                    color: Color::from(RED),
+                    ..Default::default()
+                },
+                Transform {
+                    translation: Vec3::new(transform.translation.x, transform.translation.y, 0.2),
+                    ..Default::default()
+                },
+            ))
+            .insert(Name::new("MonsterBullet"))
+            .insert(BulletComponent {
+                target_x: monster_skill.target.x,
+                target_y: monster_skill.target.y,
+                duration: Timer::new(Duration::from_secs(3), TimerMode::Once),
+                speed,
+            })
+            .insert(MonsterBulletComponent {
+                damage,
+                damage_percent_bonus: monster_damage_percent_bonus(monster_list_effects),
+                monster_class: monster.class.clone(),
+            })
+            .insert(Collider::circle(
+                MONSTER_BULLET_SIZE / 2.0,
+                CollisionLayer::MonsterBullet,
+            ));
+
+        monster_skill.finish();
+    }
+}
+
+pub fn charge_skill(
+    mut monsters_query: Query<(&Transform, &mut MonsterSkillComponent)>,
+    time: Res<Time>,
+) {
+    for (transform, mut monster_skill) in monsters_query.iter_mut() {
+        if !matches!(monster_skill.skill, MonsterSkill::Charge { .. }) {
+            continue;
+        }
+
+        match monster_skill.state {
+            MonsterSkillState::Casting => {
+                monster_skill.direction =
+                    (monster_skill.target - transform.translation.truncate()).normalize_or_zero();
+                monster_skill.duration.reset();
+                monster_skill.state = MonsterSkillState::Charging;
+            }
+            MonsterSkillState::Charging => {
+                monster_skill.duration.tick(time.delta());
+                if monster_skill.duration.finished() {
+                    monster_skill.finish();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn summon_skill(
+    mut monsters_query: Query<(&Transform, &mut MonsterSkillComponent)>,
+    mut monster_spawn_controller: ResMut<MonsterSpawnController>,
+    mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
+    ingame_materials: Res<InGameMaterials>,
+    game_data: Res<GameData>,
+    mut run_rng: ResMut<RunRng>,
+    mut commands: Commands,
+) {
+    for (transform, mut monster_skill) in monsters_query.iter_mut() {
+        if monster_skill.state != MonsterSkillState::Casting {
+            continue;
+        }
+
+        let MonsterSkill::Summon {
+            ref monster_class,
+            count,
+            ..
+        } = monster_skill.skill
+        else {
+            continue;
+        };
+
+        let raw_monster = game_data.get_monster(monster_class.clone());
+
+        for _ in 0..count {
+            spawn_minion(
+                &mut commands,
+                &mut texture_atlases,
+                &ingame_materials,
+                &mut monster_spawn_controller,
+                &mut run_rng.spawns,
+                &raw_monster,
+                transform.translation.truncate(),
+            );
+        }
+
+        monster_skill.finish();
+    }
+}
+
+pub fn heal_skill(mut monsters_query: Query<(&mut MonsterComponent, &mut MonsterSkillComponent)>) {
+    for (mut monster, mut monster_skill) in monsters_query.iter_mut() {
+        if monster_skill.state != MonsterSkillState::Casting {
+            continue;
+        }
+
+        let MonsterSkill::Heal { heal_points, .. } = monster_skill.skill else {
+            continue;
+        };
+
+        // a monster killed during the telegraph stays dead
+        if monster.current_health_points > 0.0 {
+            monster.current_health_points =
+                (monster.current_health_points + heal_points).min(monster.max_health_points);
+        }
+
+        monster_skill.finish();
+    }
+}
+
+pub fn slam_skill(
+    mut monsters_query: Query<
+        (
+            &Transform,
+            &MonsterComponent,
+            &MonsterListEffectsComponent,
+            &mut MonsterSkillComponent,
+        ),
+        Without<PlayerComponent>,
+    >,
+    mut player_query: Query<
+        (
+            &mut PlayerComponent,
+            &mut PlayerAnimation,
+            &mut PlayerListEffectsComponent,
+            &mut InvisibleCooldownComponent,
+            &Transform,
+        ),
+        Without<MonsterComponent>,
+    >,
+    mut run_rng: ResMut<RunRng>,
+    mut sound_effects: EventWriter<SoundEffect>,
+    mut dodge_events: EventWriter<DodgeEvent>,
+    mut hurt_events: EventWriter<PlayerHurtEvent>,
+) {
+    let (
+        mut player,
+        mut player_animation,
+        mut player_list_effects,
+        mut invincible_cooldown,
+        player_transform,
+    ) = player_query.single_mut().unwrap();
+
+    for (transform, monster, monster_list_effects, mut monster_skill) in monsters_query.iter_mut() {
+        if monster_skill.state != MonsterSkillState::Casting {
+            continue;
+        }
+
+        let MonsterSkill::Slam { damage, radius, .. } = monster_skill.skill else {
+            continue;
+        };
+
+        let distance = transform
+            .translation
+            .truncate()
+            .distance(player_transform.translation.truncate());
+
+        if distance <= radius && invincible_cooldown.duration.finished() {
+            if try_dodge(&player, &mut invincible_cooldown, &mut run_rng.combat) {
+                dodge_events.write(DodgeEvent {
+                    position: player_transform.translation,
+                });
+            } else {
+                let damage_percent_bonus = monster_damage_percent_bonus(monster_list_effects);
+                let attacker = Attacker::monster(damage, damage_percent_bonus);
+                let defender = Defender::player(&player_list_effects);
+                let damage = resolve_damage(
+                    &attacker,
+                    &defender,
+                    DamageSource::MonsterSlam,
+                    &mut run_rng.combat,
+                );
+                hurt_player(
+                    &damage,
+                    &mut player,
+                    &mut player_animation,
+                    &mut player_list_effects,
+                    &mut invincible_cooldown,
+                );
+                hurt_events.write(PlayerHurtEvent {
+                    monster_class: monster.class.clone(),
+                    amount: damage.amount,
+                });
+                sound_effects.write(SoundEffect::PlayerHurt);
+            }
+        }
+
+        monster_skill.finish();
+    }
+}
+
+// everything a monster bullet reaching the player can lead to
+#[derive(SystemParam)]
+pub struct BulletHitEvents<'w> {
+    sound_effects: EventWriter<'w, SoundEffect>,
+    dodge_events: EventWriter<'w, DodgeEvent>,
+    hurt_events: EventWriter<'w, PlayerHurtEvent>,
+}
+
+pub fn monster_bullets_collision(
+    bullets_query: Query<&MonsterBulletComponent>,
+    mut player_query: Query<(
+        &mut PlayerComponent,
+        &mut PlayerAnimation,
+        &mut PlayerListEffectsComponent,
+        &mut InvisibleCooldownComponent,
+        &Transform,
+    )>,
+    mut collision_events: EventReader<CollisionEvent>,
+    mut run_rng: ResMut<RunRng>,
+    mut hit_events: BulletHitEvents,
+    mut commands: Commands,
+) {
+    let (
+        mut player,
+        mut player_animation,
+        mut player_list_effects,
+        mut invincible_cooldown,
+        player_transform,
+    ) = player_query.single_mut().unwrap();
+
+    for collision in collision_events.read() {
+        if collision.layer != CollisionLayer::Player
+            || collision.other_layer != CollisionLayer::MonsterBullet
+        {
+            continue;
+        }
+
+        if let Ok(monster_bullet) = bullets_query.get(collision.other) {
+            commands.entity(collision.other).despawn();
+
+            if !invincible_cooldown.duration.finished() {
+                continue;
+            }
+
+            if try_dodge(&player, &mut invincible_cooldown, &mut run_rng.combat) {
+                hit_events.dodge_events.write(DodgeEvent {
+                    position: player_transform.translation,
+                });
+            } else {
+                let attacker =
+                    Attacker::monster(monster_bullet.damage, monster_bullet.damage_percent_bonus);
+                let defender = Defender::player(&player_list_effects);
+                let damage = resolve_damage(
+                    &attacker,
+                    &defender,
+                    DamageSource::MonsterBullet,
+                    &mut run_rng.combat,
+                );
+                hurt_player(
+                    &damage,
+                    &mut player,
+                    &mut player_animation,
+                    &mut player_list_effects,
+                    &mut invincible_cooldown,
+                );
+                hit_events.hurt_events.write(PlayerHurtEvent {
+                    monster_class: monster_bullet.monster_class.clone(),
+                    amount: damage.amount,
+                });
+                hit_events.sound_effects.write(SoundEffect::PlayerHurt);
+            }
+        }
+    }
+}
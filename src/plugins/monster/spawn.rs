@@ -1,9 +1,12 @@
 use crate::plugins::player::{PLAYER_SIZE_HEIGHT, PLAYER_SIZE_WIDTH};
 use crate::{
     components::{
-        invinsible_cooldown::InvisibleCooldownComponent, monster::MonsterComponent,
+        collider::{Collider, CollisionLayer},
+        invinsible_cooldown::InvisibleCooldownComponent,
+        monster::MonsterComponent,
         monster_animation::MonsterAnimationComponent,
         monster_list_effects::MonsterListEffectsComponent,
+        monster_skill::MonsterSkillComponent,
     },
     materials::ingame::InGameMaterials,
     resources::{
@@ -11,9 +14,11 @@ use crate::{
         dungeon::wave::Wave,
         game_data::GameData,
         monster::{
-            Monster, monster_class::MonsterClass, monster_spawn_controller::MonsterSpawnController,
+            Monster, monster_skill::MonsterSkill,
+            monster_spawn_controller::MonsterSpawnController,
         },
         player::player_dungeon_stats::PlayerDungeonStats,
+        run_rng::RunRng,
     },
 };
 use bevy::prelude::*;
@@ -26,6 +31,7 @@ pub fn spawn_monsters_classic_mode(
     player_dungeon_stats: Res<PlayerDungeonStats>,
     ingame_materials: Res<InGameMaterials>,
     game_data: Res<GameData>,
+    mut run_rng: ResMut<RunRng>,
     mut commands: Commands,
 ) {
     if player_dungeon_stats.is_room_cleared {
@@ -53,74 +59,28 @@ pub fn spawn_monsters_classic_mode(
             .cloned()
             .collect();
 
-        let start_x = monster_spawn_controller.spawn_area_start_x;
-        let start_y = monster_spawn_controller.spawn_area_start_y;
-        let end_x = monster_spawn_controller.spawn_area_end_x;
-        let end_y = monster_spawn_controller.spawn_area_end_y;
+        let rng = &mut run_rng.spawns;
 
         loop {
             if monster_spawn_controller.alive_monsters < max_avalible_monsters
                 && (require_monsters - (monster_spawn_controller.alive_monsters + killed_monsters)
                     != 0)
             {
-                let mut rng = rand::thread_rng();
                 let random_raw_monster_index = rng.gen_range(0..raw_selected_monsters.len());
 
                 let raw_monster = raw_selected_monsters.get(random_raw_monster_index).unwrap();
 
-                let x = rng.gen_range(start_x..end_x);
-                let y = rng.gen_range(end_y..start_y);
+                let position = monster_spawn_controller.random_spawn_position(rng);
 
-                let (texture_atlas, image) = get_texture(&raw_monster, &ingame_materials);
-                let texture_atlas_handle = texture_atlases.add(texture_atlas);
-                let mut sprite = Sprite::from_atlas_image(
-                    image,
-                    TextureAtlas {
-                        layout: texture_atlas_handle,
-                        index: 0,
-                    },
-                );
-                sprite.custom_size = Some(Vec2::new(
-                    raw_monster.origin_width * 3.5,
-                    raw_monster.origin_height * 3.5,
-                ));
                 let component_name = format!("Monster {}", monster_spawn_controller.alive_monsters);
-
-                commands
-                    .spawn((
-                        sprite,
-                        Transform {
-                            translation: Vec3::new(x, y, 0.16),
-                            ..Default::default()
-                        },
-                    ))
-                    .insert(MonsterComponent {
-                        current_health_points: raw_monster.health_points,
-                        max_health_points: raw_monster.health_points,
-                        damage: raw_monster.damage,
-                        speed: raw_monster.speed,
-                        level: raw_monster.level,
-                        class: raw_monster.class.clone(),
-                        trigger_effect: raw_monster.trigger_effect,
-                        trigger_chance: raw_monster.trigger_chance.unwrap_or(0.0),
-                        skill: raw_monster.skill.clone(),
-                        width: raw_monster.origin_width * 3.5,
-                        height: raw_monster.origin_height * 3.5,
-                    })
-                    .insert(MonsterListEffectsComponent::new())
-                    .insert(MonsterAnimationComponent {
-                        total_tiles: match raw_monster.class {
-                            MonsterClass::Zombie | MonsterClass::Swampy => 4,
-                            _ => 8,
-                        },
-                        animation_state: AnimationState::Idle,
-                        animation_timer: Timer::from_seconds(0.1, TimerMode::Repeating),
-                    })
-                    .insert(InvisibleCooldownComponent {
-                        hurt_duration: Timer::new(Duration::from_secs(0), TimerMode::Once),
-                        duration: Timer::new(Duration::from_secs(0), TimerMode::Once),
-                    })
-                    .insert(Name::new(component_name));
+                spawn_monster(
+                    &mut commands,
+                    &mut texture_atlases,
+                    &ingame_materials,
+                    raw_monster,
+                    position,
+                    component_name,
+                );
 
                 monster_spawn_controller.alive_monsters += 1;
             } else {
@@ -135,6 +95,7 @@ pub fn spawn_monsters_survival_mode(
     mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
     ingame_materials: Res<InGameMaterials>,
     game_data: Res<GameData>,
+    mut run_rng: ResMut<RunRng>,
     mut commands: Commands,
     wave: Res<Wave>,
 ) {
@@ -163,72 +124,25 @@ pub fn spawn_monsters_survival_mode(
             .cloned()
             .collect();
 
-        let start_x = monster_spawn_controller.spawn_area_start_x;
-        let end_x = monster_spawn_controller.spawn_area_end_x;
-
-        let start_y = monster_spawn_controller.spawn_area_start_y;
-        let end_y = monster_spawn_controller.spawn_area_end_y;
+        let rng = &mut run_rng.spawns;
 
         loop {
             if monster_spawn_controller.alive_monsters < max_avalible_monsters {
-                let mut rng = rand::thread_rng();
                 let random_raw_monster_index = rng.gen_range(0..raw_selected_monsters.len());
 
                 let raw_monster = raw_selected_monsters.get(random_raw_monster_index).unwrap();
 
-                let x = rng.gen_range(start_x..end_x);
-                let y = rng.gen_range(end_y..start_y);
+                let position = monster_spawn_controller.random_spawn_position(rng);
 
-                let (texture_atlas, image) = get_texture(&raw_monster, &ingame_materials);
-                let texture_atlas_handle = texture_atlases.add(texture_atlas);
-                let mut sprite = Sprite::from_atlas_image(
-                    image,
-                    TextureAtlas {
-                        layout: texture_atlas_handle,
-                        index: 0,
-                    },
-                );
-                sprite.custom_size = Some(Vec2::new(
-                    raw_monster.origin_width * 3.5,
-                    raw_monster.origin_height * 3.5,
-                ));
                 let component_name = format!("Monster {}", monster_spawn_controller.alive_monsters);
-
-                commands
-                    .spawn((
-                        sprite,
-                        Transform {
-                            translation: Vec3::new(x, y, 0.16),
-                            ..Default::default()
-                        },
-                    ))
-                    .insert(MonsterComponent {
-                        current_health_points: raw_monster.health_points,
-                        max_health_points: raw_monster.health_points,
-                        damage: raw_monster.damage,
-                        speed: raw_monster.speed,
-                        level: raw_monster.level,
-                        class: raw_monster.class.clone(),
-                        trigger_effect: raw_monster.trigger_effect,
-                        trigger_chance: raw_monster.trigger_chance.unwrap_or(0.0),
-                        skill: raw_monster.skill.clone(),
-                        width: raw_monster.origin_width * 3.5,
-                        height: raw_monster.origin_height * 3.5,
-                    })
-                    .insert(MonsterAnimationComponent {
-                        total_tiles: match raw_monster.class {
-                            MonsterClass::Zombie | MonsterClass::Swampy => 4,
-                            _ => 8,
-                        },
-                        animation_state: AnimationState::Idle,
-                        animation_timer: Timer::from_seconds(0.1, TimerMode::Repeating),
-                    })
-                    .insert(MonsterListEffectsComponent::new())
-                    .insert(InvisibleCooldownComponent {
-                        hurt_duration: Timer::new(Duration::from_secs(0), TimerMode::Once),
-                        duration: Timer::new(Duration::from_secs(0), TimerMode::Once),
-                    })
-                    .insert(Name::new(component_name));
+                spawn_monster(
+                    &mut commands,
+                    &mut texture_atlases,
+                    &ingame_materials,
+                    raw_monster,
+                    position,
+                    component_name,
+                );
 
                 monster_spawn_controller.alive_monsters += 1;
             } else {
@@ -238,23 +152,85 @@ pub fn spawn_monsters_survival_mode(
     }
 }
 
+pub fn spawn_monster(
+    commands: &mut Commands,
+    texture_atlases: &mut Assets<TextureAtlasLayout>,
+    ingame_materials: &InGameMaterials,
+    raw_monster: &Monster,
+    position: Vec2,
+    component_name: String,
+) {
+    let (texture_atlas, image) = get_texture(raw_monster, ingame_materials);
+    let texture_atlas_handle = texture_atlases.add(texture_atlas);
+    let mut sprite = Sprite::from_atlas_image(
+        image,
+        TextureAtlas {
+            layout: texture_atlas_handle,
+            index: 0,
+        },
+    );
+    let monster_width = raw_monster.origin_width * 3.5;
+    let monster_height = raw_monster.origin_height * 3.5;
+    sprite.custom_size = Some(Vec2::new(monster_width, monster_height));
+
+    let monster_entity = commands
+        .spawn((
+            sprite,
+            Transform {
+                translation: position.extend(0.16),
+                ..Default::default()
+            },
+        ))
+        .insert(MonsterComponent {
+            current_health_points: raw_monster.health_points,
+            max_health_points: raw_monster.health_points,
+            damage: raw_monster.damage,
+            speed: raw_monster.speed,
+            level: raw_monster.level,
+            class: raw_monster.class.clone(),
+            trigger_effect: raw_monster.trigger_effect,
+            trigger_chance: raw_monster.trigger_chance.unwrap_or(0.0),
+            skill: raw_monster.skill.clone(),
+        })
+        .insert(
+            Collider::rectangle(monster_width, monster_height, CollisionLayer::Monster)
+                .with_mask(&[CollisionLayer::Block]),
+        )
+        .insert(MonsterListEffectsComponent::new())
+        .insert(MonsterAnimationComponent {
+            total_tiles: raw_monster.frames,
+            animation_state: AnimationState::Idle,
+            animation_timer: Timer::from_seconds(0.1, TimerMode::Repeating),
+        })
+        .insert(InvisibleCooldownComponent {
+            hurt_duration: Timer::new(Duration::from_secs(0), TimerMode::Once),
+            duration: Timer::new(Duration::from_secs(0), TimerMode::Once),
+        })
+        .insert(Name::new(component_name))
+        .id();
+
+    // split happens on death, every other skill is used while the monster is alive
+    if let Some(skill) = raw_monster.skill.clone()
+        && !matches!(skill, MonsterSkill::Split { .. })
+    {
+        commands
+            .entity(monster_entity)
+            .insert(MonsterSkillComponent::new(skill));
+    }
+}
+
 fn get_texture(
     monster: &Monster,
     ingame_materials: &InGameMaterials,
 ) -> (TextureAtlasLayout, Handle<Image>) {
     let monster_tileset = ingame_materials
         .monsters_materials
-        .get_texture(monster.class.clone());
-
-    let columns = match monster.class {
-        MonsterClass::Zombie | MonsterClass::Swampy => 4,
-        _ => 8,
-    };
+        .get_texture(&monster.class);
 
     (
         TextureAtlasLayout::from_grid(
             UVec2::new(monster.origin_width as u32, monster.origin_height as u32),
-            columns,
+            monster.frames as u32,
             1,
             None,
             None,
@@ -1,23 +1,94 @@
 use bevy::prelude::*;
-use bevy_kira_audio::prelude::{Audio, AudioControl, AudioSource};
+use bevy_kira_audio::prelude::{Audio, AudioControl, AudioInstance, AudioSource, AudioTween};
+use std::collections::HashMap;
+use std::slice::Iter;
+use std::time::Duration;
 
+use crate::plugins::survival_mode::dungeon::wave::RewardsSceneFlag;
 use crate::resources::setting::Setting;
 use crate::scenes::SceneState;
 
-// Our type for the custom audio channel
+const CROSSFADE_DURATION: Duration = Duration::from_millis(1500);
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum MusicTrack {
+    Menu,
+    Exploration,
+    SurvivalWave,
+    Reward,
+    Result,
+}
+
+impl MusicTrack {
+    pub fn iterator() -> Iter<'static, MusicTrack> {
+        [
+            MusicTrack::Menu,
+            MusicTrack::Exploration,
+            MusicTrack::SurvivalWave,
+            MusicTrack::Reward,
+            MusicTrack::Result,
+        ]
+        .iter()
+    }
+
+    fn path(&self) -> &'static str {
+        match self {
+            MusicTrack::Menu => "sounds/background_music.ogg",
+            MusicTrack::Exploration => "sounds/music/exploration.wav",
+            MusicTrack::SurvivalWave => "sounds/music/survival_wave.wav",
+            MusicTrack::Reward => "sounds/music/reward.wav",
+            MusicTrack::Result => "sounds/music/result.wav",
+        }
+    }
+
+    // None keeps the current track, the pre game mode scenes only last a frame
+    fn from_scene(state: &SceneState, is_rewards_scene: bool) -> Option<MusicTrack> {
+        match state {
+            SceneState::LoadingScene
+            | SceneState::MainMenuScene
+            | SceneState::HighscoreScene
+            | SceneState::OptionsScene
+            | SceneState::HelpScene
+            | SceneState::CreditsScene
+            | SceneState::GameModeSelectScene
+            | SceneState::HeroSelectScene => Some(MusicTrack::Menu),
+            SceneState::PreClassicMode | SceneState::PreSurvivalMode => None,
+            SceneState::InGameClassicMode => Some(MusicTrack::Exploration),
+            SceneState::InGameSurvivalMode => {
+                if is_rewards_scene {
+                    Some(MusicTrack::Reward)
+                } else {
+                    Some(MusicTrack::SurvivalWave)
+                }
+            }
+            SceneState::RewardScene => Some(MusicTrack::Reward),
+            SceneState::ResultScene => Some(MusicTrack::Result),
+        }
+    }
+}
 
 #[derive(Resource)]
 pub struct BackgroundAudioChannel {
-    background_music: Handle<AudioSource>,
-    loop_started: bool,
+    tracks: HashMap<MusicTrack, Handle<AudioSource>>,
+    current_track: Option<MusicTrack>,
+    current_instance: Option<Handle<AudioInstance>>,
     volume: f32,
 }
 
-pub fn background_audio_channel_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+pub fn background_audio_channel_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    setting: Res<Setting>,
+) {
+    let tracks = MusicTrack::iterator()
+        .map(|track| (*track, asset_server.load(track.path())))
+        .collect();
+
     let background_audio_channel = BackgroundAudioChannel {
-        background_music: asset_server.load("sounds/background_music.ogg"),
-        loop_started: false,
-        volume: 0.2,
+        tracks,
+        current_track: None,
+        current_instance: None,
+        volume: setting.get_music_volume(),
     };
 
     commands.insert_resource(background_audio_channel);
@@ -25,28 +96,45 @@ pub fn background_audio_channel_setup(mut commands: Commands, asset_server: Res<
 
 pub fn play_background_music(
     mut background_audio_channel: ResMut<BackgroundAudioChannel>,
+    mut audio_instances: ResMut<Assets<AudioInstance>>,
+    rewards_scene_flag: Option<Res<RewardsSceneFlag>>,
     state: Res<State<SceneState>>,
     setting: Res<Setting>,
     audio: Res<Audio>,
 ) {
-    if setting.get_enable_music() {
-        if !background_audio_channel.loop_started {
-            background_audio_channel.loop_started = true;
-            audio.set_volume(background_audio_channel.volume as f64);
-            audio
-                .play(background_audio_channel.background_music.clone())
-                .looped();
-        } else {
-            match state.get() {
-                SceneState::InGameClassicMode | SceneState::InGameSurvivalMode => {
-                    background_audio_channel.loop_started = false;
-                    audio.stop();
-                }
-                _ => (),
-            }
+    if setting.is_changed() || background_audio_channel.is_added() {
+        background_audio_channel.volume = setting.get_music_volume();
+        audio.set_volume(background_audio_channel.volume as f64);
+    }
+
+    let next_track = if setting.get_enable_music() {
+        match MusicTrack::from_scene(state.get(), rewards_scene_flag.is_some()) {
+            Some(track) => Some(track),
+            None => return,
         }
-    } else if background_audio_channel.loop_started {
-        background_audio_channel.loop_started = false;
-        audio.stop();
+    } else {
+        None
+    };
+
+    if next_track == background_audio_channel.current_track {
+        return;
+    }
+
+    // the old track fades out while the new one fades in
+    if let Some(instance) = background_audio_channel.current_instance.take()
+        && let Some(instance) = audio_instances.get_mut(&instance)
+    {
+        instance.stop(AudioTween::linear(CROSSFADE_DURATION));
     }
+
+    if let Some(track) = next_track {
+        let instance = audio
+            .play(background_audio_channel.tracks[&track].clone())
+            .linear_fade_in(CROSSFADE_DURATION)
+            .looped()
+            .handle();
+        background_audio_channel.current_instance = Some(instance);
+    }
+
+    background_audio_channel.current_track = next_track;
 }
@@ -1,25 +1,34 @@
+use bevy::ecs::query::QueryFilter;
 use bevy::prelude::*;
-use rand::Rng;
-use std::time::Duration;
 
+use crate::components::collider::{Collider, CollisionLayer};
 use crate::components::invinsible_cooldown::InvisibleCooldownComponent;
 use crate::components::monster::MonsterComponent;
+use crate::components::monster_list_effects::MonsterListEffectsComponent;
 use crate::components::player::PlayerComponent;
 use crate::components::player_animation::PlayerAnimation;
 use crate::components::player_list_effects::PlayerListEffectsComponent;
 use crate::components::potion::PotionComponent;
-use crate::config::*;
-use crate::plugins::player::{PLAYER_SIZE_HEIGHT, PLAYER_SIZE_WIDTH};
-use crate::resources::animation_state::AnimationState;
+use crate::plugins::collision::CollisionEvent;
+use crate::plugins::player::PlayerHurtEvent;
+use crate::plugins::player::dodge::{DodgeEvent, try_dodge};
+use crate::plugins::sound::SoundEffect;
 use crate::resources::dungeon::block_type::BlockType;
 use crate::resources::effect::effect_type::EffectType;
 use crate::resources::player::player_available_movement::PlayerAvailableMovement;
 use crate::resources::potion::potion_type::PotionType;
-use crate::utils::collide::collide;
-
-pub fn wall_collision_check(
-    player_position: Vec3,
-    block_type_query: &Query<(&BlockType, &Transform), Without<PlayerComponent>>,
+use crate::resources::run_rng::RunRng;
+use crate::utils::damage::{
+    Attacker, DamageSource, Defender, hurt_player, monster_damage_percent_bonus, resolve_damage,
+};
+
+// also used for monsters, `collisions` are the collision events of the frame
+pub fn wall_collision_check<F: QueryFilter>(
+    entity: Entity,
+    translation: Vec3,
+    collider: &Collider,
+    collisions: &[CollisionEvent],
+    block_query: &Query<(&BlockType, &Collider, &Transform), F>,
 ) -> PlayerAvailableMovement {
     let mut player_available_movement = PlayerAvailableMovement {
         can_move_up: true,
@@ -28,32 +37,42 @@ pub fn wall_collision_check(
         can_move_right: true,
     };
 
-    let player_size = Vec2::new(PLAYER_SIZE_WIDTH, PLAYER_SIZE_HEIGHT);
-
-    for (block_type, block_transform) in block_type_query.iter() {
-        let block_position = match *block_type {
-            BlockType::WallTop => block_transform.translation + Vec3::new(0.0, 64.0, 0.0),
-            _ => block_transform.translation,
-        };
+    let position = collider.centre(translation);
+    let size = collider.size();
 
-        let block_size = match *block_type {
-            BlockType::WallBottom => Vec2::new(TILE_SIZE, TILE_SIZE),
-            BlockType::WallTop => Vec2::new(TILE_SIZE, TILE_SIZE),
-            BlockType::WallLeft => Vec2::new(TILE_SIZE, TILE_SIZE),
-            BlockType::WallRight => Vec2::new(TILE_SIZE, TILE_SIZE),
-            BlockType::None => Vec2::new(0.0, 0.0),
-        };
-
-        if *block_type == BlockType::None {
+    for collision in collisions.iter() {
+        if collision.entity != entity || collision.other_layer != CollisionLayer::Block {
             continue;
         }
 
-        if collide(player_position, player_size, block_position, block_size) {
+        if let Ok((block_type, block_collider, block_transform)) = block_query.get(collision.other)
+        {
+            let block_position = block_collider.centre(block_transform.translation);
+            let block_size = block_collider.size();
+
             match *block_type {
                 BlockType::WallTop => player_available_movement.can_move_up = false,
                 BlockType::WallBottom => player_available_movement.can_move_down = false,
                 BlockType::WallLeft => player_available_movement.can_move_left = false,
                 BlockType::WallRight => player_available_movement.can_move_right = false,
+                BlockType::Obstacle => {
+                    // block the side of the obstacle that is being pushed against
+                    let distance = position - block_position;
+                    let overlap_x = distance.x.abs() / (size.x + block_size.x);
+                    let overlap_y = distance.y.abs() / (size.y + block_size.y);
+
+                    if overlap_x > overlap_y {
+                        if distance.x > 0.0 {
+                            player_available_movement.can_move_left = false;
+                        } else {
+                            player_available_movement.can_move_right = false;
+                        }
+                    } else if distance.y > 0.0 {
+                        player_available_movement.can_move_down = false;
+                    } else {
+                        player_available_movement.can_move_up = false;
+                    }
+                }
                 BlockType::None => {}
             }
         }
@@ -69,7 +88,12 @@ pub fn monsters_collision_check(
         &mut InvisibleCooldownComponent,
         &Transform,
     )>,
-    monsters_query: Query<(&MonsterComponent, &Transform), Without<PlayerComponent>>,
+    monsters_query: Query<(&MonsterComponent, &MonsterListEffectsComponent)>,
+    mut collision_events: EventReader<CollisionEvent>,
+    mut run_rng: ResMut<RunRng>,
+    mut sound_effects: EventWriter<SoundEffect>,
+    mut dodge_events: EventWriter<DodgeEvent>,
+    mut hurt_events: EventWriter<PlayerHurtEvent>,
 ) {
     let (
         mut player,
@@ -78,40 +102,51 @@ pub fn monsters_collision_check(
         mut invincible_cooldown,
         player_transform,
     ) = player_query.single_mut().unwrap();
-    let player_size = Vec2::new(PLAYER_SIZE_WIDTH, PLAYER_SIZE_HEIGHT);
-    let player_position = player_transform.translation;
 
     if !invincible_cooldown.duration.finished() {
         return;
     }
 
-    for (monster_component, transform) in monsters_query.iter() {
-        let monster_size = Vec2::new(monster_component.width, monster_component.height);
-        let monster_position = transform.translation;
-        if collide(player_position, player_size, monster_position, monster_size) {
-            let damage = monster_component.damage;
-
-            player.current_health_points = if damage > player.current_health_points {
-                0.0
-            } else {
-                player.current_health_points - damage
-            };
-
-            let debuff_effect = monster_component.trigger_effect;
-            let trigger_chance = monster_component.trigger_chance;
-
-            if debuff_effect != None && trigger_chance != 0.0 {
-                let mut rng = rand::thread_rng();
-                if rng.gen_range(0.0..1.0) < trigger_chance {
-                    player_list_effects.activate(debuff_effect.unwrap());
-                }
+    for collision in collision_events.read() {
+        if collision.layer != CollisionLayer::Player
+            || collision.other_layer != CollisionLayer::Monster
+        {
+            continue;
+        }
+
+        if let Ok((monster_component, monster_list_effects)) = monsters_query.get(collision.other) {
+            if try_dodge(&player, &mut invincible_cooldown, &mut run_rng.combat) {
+                dodge_events.write(DodgeEvent {
+                    position: player_transform.translation,
+                });
+                break;
             }
 
-            invincible_cooldown.duration =
-                Timer::new(Duration::from_secs_f32(2.0), TimerMode::Once);
-            invincible_cooldown.hurt_duration =
-                Timer::new(Duration::from_secs_f32(0.3), TimerMode::Once);
-            player_animation.animation_state = AnimationState::Hit;
+            let damage_percent_bonus = monster_damage_percent_bonus(monster_list_effects);
+            let attacker = Attacker::monster(monster_component.damage, damage_percent_bonus)
+                .with_effect(
+                    monster_component.trigger_effect,
+                    monster_component.trigger_chance,
+                );
+            let defender = Defender::player(&player_list_effects);
+            let damage = resolve_damage(
+                &attacker,
+                &defender,
+                DamageSource::MonsterTouch,
+                &mut run_rng.combat,
+            );
+            hurt_player(
+                &damage,
+                &mut player,
+                &mut player_animation,
+                &mut player_list_effects,
+                &mut invincible_cooldown,
+            );
+            hurt_events.write(PlayerHurtEvent {
+                monster_class: monster_component.class.clone(),
+                amount: damage.amount,
+            });
+            sound_effects.write(SoundEffect::PlayerHurt);
             break;
         }
     }
@@ -125,35 +160,41 @@ pub fn monsters_collision_check_survival(
         &mut InvisibleCooldownComponent,
         &Transform,
     )>,
-    monsters_query: Query<(&MonsterComponent, &Transform), Without<PlayerComponent>>,
+    monsters_query: Query<(&MonsterComponent, &MonsterListEffectsComponent)>,
+    collision_events: EventReader<CollisionEvent>,
+    run_rng: ResMut<RunRng>,
+    sound_effects: EventWriter<SoundEffect>,
+    dodge_events: EventWriter<DodgeEvent>,
+    hurt_events: EventWriter<PlayerHurtEvent>,
 ) {
-    monsters_collision_check(player_query, monsters_query);
+    monsters_collision_check(
+        player_query,
+        monsters_query,
+        collision_events,
+        run_rng,
+        sound_effects,
+        dodge_events,
+        hurt_events,
+    );
 }
 
 pub fn potions_collision(
     mut commands: Commands,
-    mut player_query: Query<
-        (
-            &mut PlayerComponent,
-            &mut PlayerListEffectsComponent,
-            &Transform,
-        ),
-        (With<PlayerComponent>, Without<PotionComponent>),
-    >,
-    potions_query: Query<
-        (Entity, &PotionComponent, &Transform),
-        (With<PotionComponent>, Without<PlayerComponent>),
-    >,
+    mut player_query: Query<(&mut PlayerComponent, &mut PlayerListEffectsComponent)>,
+    potions_query: Query<&PotionComponent>,
+    mut collision_events: EventReader<CollisionEvent>,
+    mut sound_effects: EventWriter<SoundEffect>,
 ) {
-    let (mut player, mut player_list_effects, player_transform) = player_query.single_mut().unwrap();
-    let player_size = Vec2::new(PLAYER_SIZE_WIDTH, PLAYER_SIZE_HEIGHT);
-    let player_position = player_transform.translation;
+    let (mut player, mut player_list_effects) = player_query.single_mut().unwrap();
 
-    for (potion_entity, potion, potion_transform) in potions_query.iter() {
-        let potion_size = Vec2::new(potion.width, potion.height);
-        let potion_position = potion_transform.translation;
+    for collision in collision_events.read() {
+        if collision.layer != CollisionLayer::Player
+            || collision.other_layer != CollisionLayer::Potion
+        {
+            continue;
+        }
 
-        if collide(player_position, player_size, potion_position, potion_size) {
+        if let Ok(potion) = potions_query.get(collision.other) {
             match potion.potion_type {
                 PotionType::Heal => {
                     player.current_health_points =
@@ -170,7 +211,8 @@ pub fn potions_collision(
                 PotionType::Focus => player_list_effects.activate(EffectType::Focus),
             }
 
-            commands.entity(potion_entity).despawn();
+            commands.entity(collision.other).despawn();
+            sound_effects.write(SoundEffect::PotionPickup);
         }
     }
 }
@@ -0,0 +1,106 @@
+use bevy::prelude::*;
+use rand::Rng;
+use std::time::Duration;
+
+use crate::components::invinsible_cooldown::InvisibleCooldownComponent;
+use crate::components::player::PlayerComponent;
+use crate::materials::font::FontMaterials;
+use crate::plugins::player::PLAYER_SIZE_HEIGHT;
+use crate::resources::dictionary::Dictionary;
+
+// a dodged hit leaves a short grace, otherwise a monster standing on the player rolls again every frame
+const DODGE_GRACE_DURATION: f32 = 0.5;
+const MISS_TEXT_DURATION: f32 = 0.6;
+const MISS_TEXT_SPEED: f32 = 60.0;
+
+#[derive(Event)]
+pub struct DodgeEvent {
+    pub position: Vec3,
+}
+
+#[derive(Component)]
+pub struct MissTextComponent {
+    timer: Timer,
+}
+
+pub fn try_dodge(
+    player: &PlayerComponent,
+    invincible_cooldown: &mut InvisibleCooldownComponent,
+    rng: &mut impl Rng,
+) -> bool {
+    if rng.gen_range(0.0..1.0) >= player.dodge_chance {
+        return false;
+    }
+
+    invincible_cooldown.duration = Timer::new(
+        Duration::from_secs_f32(DODGE_GRACE_DURATION),
+        TimerMode::Once,
+    );
+    true
+}
+
+pub fn spawn_miss_text(
+    mut commands: Commands,
+    mut dodge_events: EventReader<DodgeEvent>,
+    font_materials: Res<FontMaterials>,
+    dictionary: Res<Dictionary>,
+) {
+    if dodge_events.is_empty() {
+        return;
+    }
+
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let glossary = dictionary.get_glossary();
+
+    for dodge_event in dodge_events.read() {
+        commands
+            .spawn((
+                Text2d::new(glossary.ingame_text.miss.clone()),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 30.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                Transform::from_translation(
+                    dodge_event.position + Vec3::new(0.0, PLAYER_SIZE_HEIGHT / 2.0, 1.0),
+                ),
+            ))
+            .insert(MissTextComponent {
+                timer: Timer::from_seconds(MISS_TEXT_DURATION, TimerMode::Once),
+            })
+            .insert(Name::new("MissText"));
+    }
+}
+
+// the text floats up and fades out
+pub fn miss_text_handle(
+    mut commands: Commands,
+    mut miss_text_query: Query<(
+        Entity,
+        &mut MissTextComponent,
+        &mut Transform,
+        &mut TextColor,
+    )>,
+    time: Res<Time>,
+) {
+    for (entity, mut miss_text, mut transform, mut text_color) in miss_text_query.iter_mut() {
+        miss_text.timer.tick(time.delta());
+        if miss_text.timer.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        transform.translation.y += MISS_TEXT_SPEED * time.delta_secs();
+        text_color.0.set_alpha(1.0 - miss_text.timer.fraction());
+    }
+}
+
+pub fn cleanup_miss_texts(
+    mut commands: Commands,
+    miss_text_query: Query<Entity, With<MissTextComponent>>,
+) {
+    for entity in miss_text_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -2,6 +2,7 @@ use std::time::Duration;
 
 use bevy::prelude::*;
 
+use crate::components::collider::{Collider, CollisionLayer};
 use crate::components::invinsible_cooldown::InvisibleCooldownComponent;
 use crate::components::player::PlayerComponent;
 use crate::components::player_animation::PlayerAnimation;
@@ -12,7 +13,7 @@ use crate::plugins::player::PlayerEntity;
 use crate::plugins::player::{PLAYER_SIZE_HEIGHT, PLAYER_SIZE_WIDTH};
 use crate::resources::game_data::GameData;
 use crate::resources::profile::Profile;
-use crate::resources::upgrade::upgrade_controller::UpgradeController;
+use crate::resources::saved_run::SavedPlayer;
 
 const PLAYER_ORIGIN_SIZE_WIDTH: f32 = 16.0;
 const PLAYER_ORIGIN_SIZE_HEIGHT: f32 = 28.0;
@@ -23,17 +24,30 @@ pub fn initiate_player(
     game_data: Res<GameData>,
     mut commands: Commands,
     profile: Res<Profile>,
+    saved_player: Option<Res<SavedPlayer>>,
 ) {
     let class = profile.hero_class.clone();
     let gender = profile.gender.clone();
 
-    let skill = game_data.get_skill(class.clone());
+    let mut player = PlayerComponent::new(class.clone(), game_data.clone());
+    let mut skill = SkillComponent::new(game_data.get_skill(class.clone()));
+    let mut player_list_effects =
+        PlayerListEffectsComponent::new(game_data.get_player_list_effects_information());
+    let mut translation = Vec3::new(0.0, 0.0, 0.15);
 
-    let player = PlayerComponent::new(class.clone(), game_data.clone());
+    if let Some(saved_player) = saved_player {
+        player = saved_player.player.clone();
+        skill = SkillComponent::new(saved_player.skill.clone());
+        skill.monster_counter = saved_player.skill_monster_counter;
+        player_list_effects =
+            PlayerListEffectsComponent::new(saved_player.effects_information.clone());
+        translation.x = saved_player.position.0;
+        translation.y = saved_player.position.1;
+    }
 
     let hero_tileset = ingame_materials
         .heroes_materials
-        .get_texture(class.clone(), gender);
+        .get_texture(&class, &gender);
 
     let texture_atlas = TextureAtlasLayout::from_grid(
         UVec2::new(
@@ -59,23 +73,35 @@ pub fn initiate_player(
         .spawn((
             sprite,
             Transform {
-                translation: Vec3::new(0.0, 0.0, 0.15),
+                translation,
                 ..Default::default()
             },
         ))
         .insert(player)
         .insert(PlayerAnimation::new())
-        .insert(PlayerListEffectsComponent::new(
-            game_data.get_player_list_effects_information(),
-        ))
-        .insert(SkillComponent::new(skill))
+        .insert(player_list_effects)
+        .insert(skill)
         .insert(InvisibleCooldownComponent {
             hurt_duration: Timer::new(Duration::from_secs(0), TimerMode::Once),
             duration: Timer::new(Duration::from_secs_f32(0.5), TimerMode::Once),
         })
+        .insert(
+            Collider::rectangle(
+                PLAYER_SIZE_WIDTH,
+                PLAYER_SIZE_HEIGHT,
+                CollisionLayer::Player,
+            )
+            .with_mask(&[
+                CollisionLayer::Block,
+                CollisionLayer::Monster,
+                CollisionLayer::MonsterBullet,
+                CollisionLayer::Potion,
+                CollisionLayer::Door,
+                CollisionLayer::EndPoint,
+            ]),
+        )
         .insert(Name::new("Player"))
         .id();
 
-    commands.insert_resource(UpgradeController::new());
     commands.insert_resource(PlayerEntity { entity });
 }
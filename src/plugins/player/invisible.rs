@@ -1,10 +1,10 @@
 use crate::components::{invinsible_cooldown::InvisibleCooldownComponent, player::PlayerComponent};
+#[cfg(feature = "render")]
 use bevy::color::palettes::css::*;
 
-use bevy::{
-    prelude::{Color, Query, Res, Time, With},
-    sprite::Sprite,
-};
+use bevy::prelude::{Query, Res, Time, With};
+#[cfg(feature = "render")]
+use bevy::{prelude::Color, sprite::Sprite};
 
 pub fn invincible_cooldown(
     mut invincible_cooldown_query: Query<&mut InvisibleCooldownComponent, With<PlayerComponent>>,
@@ -21,6 +21,7 @@ pub fn invincible_cooldown(
     }
 }
 
+#[cfg(feature = "render")]
 pub fn hurt_duration_color(
     mut invincible_cooldown_query: Query<
         (&InvisibleCooldownComponent, &mut Sprite),
@@ -1,11 +1,19 @@
+#[cfg(feature = "render")]
+use crate::headless::HeadlessFlag;
 use crate::resources::game_data::PauseSceneData;
 use bevy::prelude::*;
 
+use crate::resources::monster::monster_class::MonsterClass;
+use crate::resources::upgrade::Upgrade;
+use crate::resources::upgrade::upgrade_type::UpgradeType;
+use crate::resources::weapon::Weapon;
 use crate::scenes::SceneState;
 
+#[cfg(feature = "render")]
 mod animation;
 mod cleanup;
 pub mod collisions;
+pub mod dodge;
 mod effect;
 mod health;
 mod initiate;
@@ -13,7 +21,9 @@ mod invisible;
 mod profile;
 mod skill;
 pub mod stats;
+#[cfg(feature = "render")]
 mod ui;
+pub mod upgrade;
 
 pub struct PlayerPlugin;
 
@@ -22,11 +32,45 @@ pub struct PlayerEntity {
     pub entity: Entity,
 }
 
+// a monster hit that got through, nothing reads it in game, balance runs count it
+#[derive(Event)]
+pub struct PlayerHurtEvent {
+    pub monster_class: MonsterClass,
+    pub amount: f32,
+}
+
+// a reward the player took, `name` tells apart upgrades of the same type
+#[derive(Event)]
+pub struct UpgradePickedEvent {
+    pub upgrade_type: UpgradeType,
+    pub name: String,
+}
+
+impl UpgradePickedEvent {
+    pub fn new(upgrade: &Upgrade) -> Self {
+        UpgradePickedEvent {
+            upgrade_type: upgrade.upgrade_type(),
+            name: upgrade.name(),
+        }
+    }
+
+    pub fn weapon(weapon: &Weapon) -> Self {
+        UpgradePickedEvent {
+            upgrade_type: UpgradeType::Weapon,
+            name: format!("Weapon {:?}", weapon.name),
+        }
+    }
+}
+
 pub const PLAYER_SIZE_WIDTH: f32 = 16.0 * 3.5;
 pub const PLAYER_SIZE_HEIGHT: f32 = 28.0 * 3.5;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
+        app.add_event::<dodge::DodgeEvent>();
+        app.add_event::<PlayerHurtEvent>();
+        app.add_event::<UpgradePickedEvent>();
+
         app.add_systems(
             OnEnter(SceneState::PreClassicMode),
             initiate::initiate_player,
@@ -36,21 +80,13 @@ impl Plugin for PlayerPlugin {
             initiate::initiate_player,
         );
 
-        app.add_systems(OnEnter(SceneState::InGameClassicMode), ui::setup);
-        app.add_systems(OnEnter(SceneState::InGameSurvivalMode), ui::setup);
-
         app.add_systems(
             Update,
             (
                 invisible::invincible_cooldown,
-                invisible::hurt_duration_color,
                 effect::update_effects,
                 stats::update_stats.after(effect::update_effects),
                 collisions::potions_collision,
-                ui::hearts_handle,
-                ui::skill_duration_handle,
-                ui::skill_cooldown_handle,
-                ui::information_texts_handle.after(stats::update_stats),
                 health::end_run_check,
                 profile::finish_run,
                 skill::cooldown,
@@ -66,34 +102,83 @@ impl Plugin for PlayerPlugin {
 
         app.add_systems(
             Update,
-            (
-                animation::player_animation_system,
-                collisions::monsters_collision_check,
-            )
-                .run_if(in_state(SceneState::InGameClassicMode)),
+            collisions::monsters_collision_check.run_if(in_state(SceneState::InGameClassicMode)),
         );
 
         app.add_systems(
             Update,
-            (
-                collisions::monsters_collision_check_survival,
-                animation::player_animation_system
-                    .after(collisions::monsters_collision_check_survival),
-            )
+            collisions::monsters_collision_check_survival
                 .run_if(in_state(SceneState::InGameSurvivalMode)),
         );
 
         app.add_systems(
             OnExit(SceneState::InGameClassicMode),
-            (cleanup::cleanup_player, ui::cleanup),
+            cleanup::cleanup_player,
         );
         app.add_systems(
             OnExit(SceneState::InGameSurvivalMode),
-            (
-                cleanup::cleanup_player,
-                ui::cleanup,
-                cleanup::save_cleared_waves,
-            ),
+            (cleanup::cleanup_player, cleanup::save_cleared_waves),
         );
+
+        #[cfg(feature = "render")]
+        build_render_systems(app);
     }
 }
+
+// hud, animations and hit feedback, nothing here changes the outcome of a run
+#[cfg(feature = "render")]
+fn build_render_systems(app: &mut App) {
+    let is_rendering = not(resource_exists::<HeadlessFlag>);
+
+    app.add_systems(
+        OnEnter(SceneState::InGameClassicMode),
+        ui::setup.run_if(is_rendering.clone()),
+    );
+    app.add_systems(
+        OnEnter(SceneState::InGameSurvivalMode),
+        ui::setup.run_if(is_rendering.clone()),
+    );
+
+    app.add_systems(
+        Update,
+        (
+            invisible::hurt_duration_color,
+            ui::hearts_handle,
+            ui::skill_duration_handle,
+            ui::skill_cooldown_handle,
+            ui::information_texts_handle.after(stats::update_stats),
+            ui::effect_indicators_handle.after(effect::update_effects),
+            dodge::spawn_miss_text,
+            dodge::miss_text_handle,
+        )
+            .run_if(
+                in_state(SceneState::InGameClassicMode)
+                    .or(in_state(SceneState::InGameSurvivalMode))
+                    .and(not(resource_exists::<PauseSceneData>))
+                    .and(is_rendering.clone()),
+            ),
+    );
+
+    app.add_systems(
+        Update,
+        animation::player_animation_system
+            .after(collisions::monsters_collision_check)
+            .run_if(in_state(SceneState::InGameClassicMode).and(is_rendering.clone())),
+    );
+
+    app.add_systems(
+        Update,
+        animation::player_animation_system
+            .after(collisions::monsters_collision_check_survival)
+            .run_if(in_state(SceneState::InGameSurvivalMode).and(is_rendering.clone())),
+    );
+
+    app.add_systems(
+        OnExit(SceneState::InGameClassicMode),
+        (ui::cleanup, dodge::cleanup_miss_texts).run_if(is_rendering.clone()),
+    );
+    app.add_systems(
+        OnExit(SceneState::InGameSurvivalMode),
+        (ui::cleanup, dodge::cleanup_miss_texts).run_if(is_rendering),
+    );
+}
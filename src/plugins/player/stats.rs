@@ -36,12 +36,7 @@ pub fn update_stats(
 
     for (effect_type, duration) in player_list_effects.activated_effects.iter() {
         if !duration.finished() {
-            let bonus = player_list_effects
-                .information
-                .iter()
-                .find(|effect_information| effect_information.name == *effect_type)
-                .unwrap()
-                .bonus;
+            let bonus = player_list_effects.bonus(*effect_type);
 
             match effect_type {
                 EffectType::SpeedUp | EffectType::Slow => {
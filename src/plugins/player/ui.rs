@@ -4,11 +4,13 @@ use std::slice::Iter;
 use bevy::color::palettes::css::*;
 
 use crate::components::player::PlayerComponent;
+use crate::components::player_list_effects::PlayerListEffectsComponent;
 use crate::components::skill::SkillComponent;
 use crate::config::{RESOLUTION, WINDOW_HEIGHT};
 use crate::materials::font::FontMaterials;
 use crate::materials::ingame::InGameMaterials;
 use crate::resources::dictionary::Dictionary;
+use crate::resources::effect::effect_type::EffectType;
 use crate::resources::skill::skill_type::SkillType;
 
 #[derive(Component, Clone)]
@@ -37,6 +39,15 @@ impl InformationTextComponent {
     }
 }
 
+// crowd control effects shown under the hearts while they last
+const INDICATED_EFFECTS: [EffectType; 3] =
+    [EffectType::Stun, EffectType::Disarm, EffectType::Confuse];
+
+#[derive(Component)]
+pub struct EffectIndicatorComponent {
+    effect_type: EffectType,
+}
+
 #[derive(Component)]
 pub struct HeartComponent {
     index: f32,
@@ -75,6 +86,7 @@ pub fn setup(
         .with_children(|parent| {
             hearts(parent, &ingame_materials);
             information_texts(parent, &font_materials, &dictionary);
+            effect_indicators(parent, &font_materials, &dictionary);
             skill_cooldown(parent);
             skill_duration(parent);
         })
@@ -217,6 +229,90 @@ pub fn information_texts_handle(
     }
 }
 
+pub fn effect_indicators(
+    root: &mut ChildSpawnerCommands,
+    font_materials: &FontMaterials,
+    dictionary: &Dictionary,
+) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let font_size = 20.0;
+
+    root.spawn(Node {
+        ..Default::default()
+    })
+    .with_children(|parent| {
+        for (index, effect_type) in INDICATED_EFFECTS.iter().enumerate() {
+            let left_position = 10.0;
+            let top_position = 70.0 + index as f32 * font_size;
+
+            let color = match *effect_type {
+                EffectType::Stun => Color::from(GRAY),
+                EffectType::Disarm => Color::from(ORANGE),
+                _ => Color::from(VIOLET),
+            };
+
+            parent
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(left_position),
+                        top: Val::Px(top_position),
+                        ..Default::default()
+                    },
+                    Text::new(""),
+                    TextFont {
+                        font: font.clone(),
+                        font_size,
+                        ..Default::default()
+                    },
+                    TextColor(color),
+                    TextLayout {
+                        justify: JustifyText::Left,
+                        linebreak: LineBreak::NoWrap,
+                    },
+                    Visibility::Hidden,
+                ))
+                .insert(Name::new(format!("EffectIndicator:{:?}", effect_type)))
+                .insert(EffectIndicatorComponent {
+                    effect_type: *effect_type,
+                });
+        }
+    })
+    .insert(Name::new("EffectIndicators"));
+}
+
+pub fn effect_indicators_handle(
+    mut indicators_query: Query<(Entity, &EffectIndicatorComponent, &mut Visibility)>,
+    mut writer: TextUiWriter,
+    player_list_effects_query: Query<&PlayerListEffectsComponent>,
+    dictionary: Res<Dictionary>,
+) {
+    let glossary = dictionary.get_glossary();
+    let ingame_gloassary = glossary.ingame_text;
+    let player_list_effects = player_list_effects_query.single().unwrap();
+
+    for (entity, indicator, mut visibility) in indicators_query.iter_mut() {
+        match player_list_effects
+            .activated_effects
+            .get(&indicator.effect_type)
+        {
+            Some(duration) if !duration.finished() => {
+                let name = match indicator.effect_type {
+                    EffectType::Stun => ingame_gloassary.stunned.clone(),
+                    EffectType::Disarm => ingame_gloassary.disarmed.clone(),
+                    _ => ingame_gloassary.confused.clone(),
+                };
+
+                *visibility = Visibility::Visible;
+                *writer.text(entity, 0) = format!("{}: {:.1}s", name, duration.remaining_secs());
+            }
+            _ => {
+                *visibility = Visibility::Hidden;
+            }
+        }
+    }
+}
+
 fn hearts(root: &mut ChildSpawnerCommands, ingame_materials: &InGameMaterials) {
     root.spawn((
         Node {
@@ -0,0 +1,109 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::components::player::PlayerComponent;
+use crate::components::player_list_effects::PlayerListEffectsComponent;
+use crate::components::skill::SkillComponent;
+use crate::components::weapon::WeaponComponent;
+use crate::components::weapon_shoot_attack::WeaponShootAttackComponent;
+use crate::components::weapon_swing_attack::WeaponSwingAttackComponent;
+use crate::materials::font::FontMaterials;
+use crate::materials::scenes::ScenesMaterials;
+use crate::plugins::player::UpgradePickedEvent;
+use crate::resources::dictionary::Dictionary;
+use crate::resources::game_data::GameData;
+use crate::resources::run_rng::RunRng;
+use crate::resources::upgrade::upgrade_controller::UpgradeController;
+use crate::resources::upgrade::upgrade_type::UpgradeType;
+
+// what the reward dialogs of both game modes are drawn with
+#[derive(SystemParam)]
+pub struct RewardUi<'w> {
+    pub scenes_materials: Res<'w, ScenesMaterials>,
+    pub font_materials: Res<'w, FontMaterials>,
+    pub dictionary: Res<'w, Dictionary>,
+}
+
+// rolls and applies the upgrades of the classic floor rewards and the survival wave rewards
+#[derive(SystemParam)]
+pub struct UpgradePicker<'w, 's> {
+    player_query: Query<
+        'w,
+        's,
+        (
+            &'static mut PlayerComponent,
+            &'static mut SkillComponent,
+            &'static mut PlayerListEffectsComponent,
+        ),
+    >,
+    weapon_query: Query<
+        'w,
+        's,
+        (
+            &'static mut WeaponComponent,
+            &'static mut WeaponSwingAttackComponent,
+            &'static mut WeaponShootAttackComponent,
+        ),
+    >,
+    upgrade_controller: Res<'w, UpgradeController>,
+    run_rng: ResMut<'w, RunRng>,
+    game_data: Res<'w, GameData>,
+    upgrade_events: EventWriter<'w, UpgradePickedEvent>,
+}
+
+impl UpgradePicker<'_, '_> {
+    // the weapon is only offered while the hero has a next one
+    pub fn three_upgrades(&mut self) -> Vec<UpgradeType> {
+        let (player, _, _) = self.player_query.single().unwrap();
+        let (weapon, _, _) = self.weapon_query.single().unwrap();
+        let can_upgrade_weapon = self
+            .game_data
+            .get_next_weapon(player.class.clone(), weapon.level)
+            .is_some();
+
+        self.upgrade_controller
+            .get_three_upgrades(can_upgrade_weapon, &mut self.run_rng.upgrades)
+    }
+
+    pub fn pick(&mut self, upgrade_type: UpgradeType) {
+        let (mut player, mut player_skill, mut player_list_effects) =
+            self.player_query.single_mut().unwrap();
+        let hero_class = player.class.clone();
+
+        match upgrade_type {
+            UpgradeType::Weapon => {
+                let (mut weapon, mut swing_attack, mut shoot_attack) =
+                    self.weapon_query.single_mut().unwrap();
+                if let Some(raw_weapon) = self.game_data.get_next_weapon(hero_class, weapon.level) {
+                    weapon.upgrade_weapon(&raw_weapon);
+                    swing_attack.upgrade(&raw_weapon);
+                    shoot_attack.upgrade(&raw_weapon);
+                    self.upgrade_events
+                        .write(UpgradePickedEvent::weapon(&raw_weapon));
+                }
+            }
+            UpgradeType::Stats => {
+                let upgrade = self
+                    .upgrade_controller
+                    .get_stats_upgrade(&mut self.run_rng.upgrades);
+                self.upgrade_events.write(UpgradePickedEvent::new(&upgrade));
+                player.upgrade_stats(upgrade);
+            }
+            UpgradeType::Effect => {
+                let upgrade = self
+                    .upgrade_controller
+                    .get_effect_upgrade(&mut self.run_rng.upgrades);
+                self.upgrade_events.write(UpgradePickedEvent::new(&upgrade));
+                player_list_effects.upgrade(upgrade);
+            }
+            UpgradeType::Skill => {
+                let skill_type = player_skill.skill.name.clone();
+                let upgrade = self
+                    .upgrade_controller
+                    .get_skill_upgrade(skill_type, &mut self.run_rng.upgrades);
+                self.upgrade_events.write(UpgradePickedEvent::new(&upgrade));
+                player_skill.upgrade(upgrade);
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy_kira_audio::prelude::{AudioApp, AudioChannel, AudioControl, AudioSource};
+#[cfg(feature = "render")]
+use std::collections::HashMap;
+use std::slice::Iter;
+
+#[cfg(feature = "render")]
+use crate::resources::setting::Setting;
+
+// gameplay only sends the events, playing them needs the render feature
+pub struct SoundEffectPlugin;
+
+// kira channel for short gameplay sounds, separated from the background music
+#[cfg(feature = "render")]
+#[derive(Resource)]
+pub struct SoundEffectChannel;
+
+#[derive(Event, Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum SoundEffect {
+    Swing,
+    Shoot,
+    MonsterHit,
+    PlayerHurt,
+    PotionPickup,
+    DoorTransition,
+    SkillCast,
+    WaveCleared,
+    FloorCleared,
+}
+
+impl SoundEffect {
+    pub fn iterator() -> Iter<'static, SoundEffect> {
+        [
+            SoundEffect::Swing,
+            SoundEffect::Shoot,
+            SoundEffect::MonsterHit,
+            SoundEffect::PlayerHurt,
+            SoundEffect::PotionPickup,
+            SoundEffect::DoorTransition,
+            SoundEffect::SkillCast,
+            SoundEffect::WaveCleared,
+            SoundEffect::FloorCleared,
+        ]
+        .iter()
+    }
+
+    #[cfg(feature = "render")]
+    fn path(&self) -> &'static str {
+        match self {
+            SoundEffect::Swing => "sounds/effects/swing.wav",
+            SoundEffect::Shoot => "sounds/effects/shoot.wav",
+            SoundEffect::MonsterHit => "sounds/effects/monster_hit.wav",
+            SoundEffect::PlayerHurt => "sounds/effects/player_hurt.wav",
+            SoundEffect::PotionPickup => "sounds/effects/potion_pickup.wav",
+            SoundEffect::DoorTransition => "sounds/effects/door.wav",
+            SoundEffect::SkillCast => "sounds/effects/skill_cast.wav",
+            SoundEffect::WaveCleared => "sounds/effects/wave_cleared.wav",
+            SoundEffect::FloorCleared => "sounds/effects/floor_cleared.wav",
+        }
+    }
+}
+
+#[cfg(feature = "render")]
+#[derive(Resource)]
+struct SoundEffectSources {
+    sources: HashMap<SoundEffect, Handle<AudioSource>>,
+}
+
+impl Plugin for SoundEffectPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SoundEffect>();
+
+        #[cfg(feature = "render")]
+        {
+            app.add_audio_channel::<SoundEffectChannel>();
+            app.add_systems(Startup, setup);
+            app.add_systems(Update, play_sound_effects);
+        }
+    }
+}
+
+#[cfg(feature = "render")]
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let sources = SoundEffect::iterator()
+        .map(|sound_effect| (*sound_effect, asset_server.load(sound_effect.path())))
+        .collect();
+
+    commands.insert_resource(SoundEffectSources { sources });
+}
+
+#[cfg(feature = "render")]
+fn play_sound_effects(
+    mut sound_effect_events: EventReader<SoundEffect>,
+    sound_effect_sources: Res<SoundEffectSources>,
+    sound_effect_channel: Res<AudioChannel<SoundEffectChannel>>,
+    setting: Res<Setting>,
+) {
+    if setting.is_changed() {
+        sound_effect_channel.set_volume(setting.get_sound_volume() as f64);
+    }
+
+    if !setting.get_enable_sound() {
+        sound_effect_events.clear();
+        return;
+    }
+
+    // several hits in the same frame would only make the sound louder
+    let mut played_sound_effects: Vec<SoundEffect> = Vec::new();
+    for sound_effect in sound_effect_events.read() {
+        if played_sound_effects.contains(sound_effect) {
+            continue;
+        }
+
+        played_sound_effects.push(*sound_effect);
+        sound_effect_channel.play(sound_effect_sources.sources[sound_effect].clone());
+    }
+}
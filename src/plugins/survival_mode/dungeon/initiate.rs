@@ -1,20 +1,45 @@
 use bevy::prelude::*;
 
 use crate::config::*;
+use crate::headless::HeadlessFlag;
 use crate::plugins::survival_mode::SurvivalModeData;
 use crate::plugins::survival_mode::dungeon::{TOTAL_TILE_HEIGHT, TOTAL_TILE_WIDTH};
 use crate::resources::dungeon::wave::Wave;
 use crate::resources::game_mode::GameMode;
 use crate::resources::monster::monster_spawn_controller::MonsterSpawnController;
+use crate::resources::profile::Profile;
+use crate::resources::run_rng::RunRng;
+use crate::resources::saved_survival_run::SavedSurvivalRun;
 use crate::scenes::SceneState;
 
-pub fn initiate_survival_mode(mut commands: Commands, mut state: ResMut<NextState<SceneState>>) {
+pub fn initiate_survival_mode(
+    mut commands: Commands,
+    mut state: ResMut<NextState<SceneState>>,
+    profile: Res<Profile>,
+    saved_survival_run: Option<Res<SavedSurvivalRun>>,
+    headless_flag: Option<Res<HeadlessFlag>>,
+) {
     let dungeon_mode_data = SurvivalModeData {
         walls: None,
         ground: None,
     };
 
-    let wave = Wave::new();
+    // starting a new run replaces the saved one
+    let (wave, run_rng) = match saved_survival_run {
+        Some(saved_survival_run) => (
+            Wave::resume(
+                saved_survival_run.wave_number,
+                saved_survival_run.wave_duration,
+            ),
+            RunRng::resume(profile.seed, &saved_survival_run.run_rng),
+        ),
+        None => {
+            if headless_flag.is_none() {
+                SavedSurvivalRun::delete();
+            }
+            (Wave::new(), RunRng::new(profile.seed))
+        }
+    };
 
     let start_x = 0.0 - (TOTAL_TILE_WIDTH * TILE_SIZE / 2.0 - TILE_SIZE / 2.0);
     let start_y = 0.0 + (TOTAL_TILE_HEIGHT * TILE_SIZE / 2.0 - TILE_SIZE / 2.0);
@@ -34,11 +59,13 @@ pub fn initiate_survival_mode(mut commands: Commands, mut state: ResMut<NextStat
         spawn_area_start_y,
         spawn_area_end_x,
         spawn_area_end_y,
+        spawn_positions: Vec::new(),
     };
 
     commands.insert_resource(monster_spawn_controller);
     commands.insert_resource(dungeon_mode_data);
     commands.insert_resource(wave);
+    commands.insert_resource(run_rng);
 
     state.set(SceneState::InGameSurvivalMode);
 }
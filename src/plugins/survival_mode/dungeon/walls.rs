@@ -5,6 +5,7 @@ use crate::materials::ingame::InGameMaterials;
 use crate::plugins::survival_mode::SurvivalModeData;
 use crate::plugins::survival_mode::dungeon::{TOTAL_TILE_HEIGHT, TOTAL_TILE_WIDTH};
 use crate::resources::dungeon::block_type::BlockType;
+use crate::resources::dungeon::navigation_grid::NavigationGrid;
 use crate::resources::dungeon::rooms::Rooms;
 use crate::resources::dungeon::wall::Wall;
 use crate::resources::dungeon::wall_type::WallType;
@@ -36,6 +37,10 @@ pub fn walls(
         .id();
 
     data.walls = Some(walls);
+
+    let start_x = 0.0 - TOTAL_TILE_WIDTH * TILE_SIZE / 2.0 - TILE_SIZE / 2.0;
+    let start_y = 0.0 + (TOTAL_TILE_HEIGHT * TILE_SIZE / 2.0 + TILE_SIZE / 2.0);
+    commands.insert_resource(NavigationGrid::new(&room.tilemap, start_x, start_y));
 }
 
 fn wall(
@@ -58,6 +63,7 @@ fn wall(
         }
         7 => BlockType::WallLeft,
         8 => BlockType::WallRight,
+        9 => BlockType::Obstacle,
         _ => BlockType::None,
     };
 
@@ -79,6 +85,7 @@ fn wall(
         6 => ingame_materials.dungeon_materials.wall_right.clone(),
         -7 | 7 => ingame_materials.dungeon_materials.wall_border_left.clone(),
         -8 | 8 => ingame_materials.dungeon_materials.wall_border_right.clone(),
+        9 => ingame_materials.dungeon_materials.wall.clone(),
         _ => panic!("Unknow room value: {}", value),
     };
 
@@ -94,28 +101,33 @@ fn wall(
         0.2
     };
 
-    parent
-        .spawn((
-            Sprite {
-                image: image,
-                custom_size: Some(Vec2::new(TILE_SIZE, TILE_SIZE)),
-                ..Default::default()
-            },
-            Transform {
-                translation: Vec3::new(x, y, z),
-                ..Default::default()
-            },
-        ))
-        .insert(Wall {
-            wall_type: if value < 0 {
-                WallType::Temporary
-            } else {
-                WallType::Permanent
-            },
-            row_index,
-            column_index,
-            value,
-        })
-        .insert(block_type)
-        .insert(Name::new(component_name));
+    let collider = block_type.collider();
+
+    let mut wall = parent.spawn((
+        Sprite {
+            image: image,
+            custom_size: Some(Vec2::new(TILE_SIZE, TILE_SIZE)),
+            ..Default::default()
+        },
+        Transform {
+            translation: Vec3::new(x, y, z),
+            ..Default::default()
+        },
+    ));
+    wall.insert(Wall {
+        wall_type: if value < 0 {
+            WallType::Temporary
+        } else {
+            WallType::Permanent
+        },
+        row_index,
+        column_index,
+        value,
+    })
+    .insert(block_type)
+    .insert(Name::new(component_name));
+
+    if let Some(collider) = collider {
+        wall.insert(collider);
+    }
 }
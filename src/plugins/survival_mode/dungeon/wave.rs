@@ -1,21 +1,14 @@
-use crate::components::player::PlayerComponent;
-use crate::components::player_list_effects::PlayerListEffectsComponent;
-use crate::components::skill::SkillComponent;
-use crate::components::weapon::WeaponComponent;
-use crate::components::weapon_shoot_attack::WeaponShootAttackComponent;
-use crate::components::weapon_swing_attack::WeaponSwingAttackComponent;
 use crate::config::*;
 use crate::materials::font::FontMaterials;
 use crate::materials::menu_box::MenuBoxMaterials;
-use crate::materials::scenes::ScenesMaterials;
+use crate::plugins::player::upgrade::{RewardUi, UpgradePicker};
+use crate::plugins::sound::SoundEffect;
 use crate::resources::dictionary::Dictionary;
 use bevy::prelude::*;
 use std::slice::Iter;
 
 use crate::resources::dungeon::wave::Wave;
-use crate::resources::game_data::{GameData, PauseSceneData};
-use crate::resources::hero::hero_class::HeroClass;
-use crate::resources::upgrade::upgrade_controller::UpgradeController;
+use crate::resources::game_data::PauseSceneData;
 use crate::resources::upgrade::upgrade_type::UpgradeType;
 use bevy::color::palettes::css::*;
 
@@ -54,21 +47,16 @@ impl RewardsSceneButton {
 pub fn countdown(
     time: Res<Time>,
     mut wave: ResMut<Wave>,
-    upgrade_controller: Res<UpgradeController>,
-    scenes_materials: Res<ScenesMaterials>,
-    weapon_query: Query<&WeaponComponent>,
-    player_query: Query<&PlayerComponent>,
-    font_materials: Res<FontMaterials>,
-    dictionary: Res<Dictionary>,
+    mut upgrade_picker: UpgradePicker,
+    reward_ui: RewardUi,
     mut commands: Commands,
+    mut sound_effects: EventWriter<SoundEffect>,
 ) {
     wave.timer.tick(time.delta());
     if wave.timer.finished() {
-        let player = player_query.single().unwrap();
-        let hero_class = player.class.clone();
-        let weapon_component = weapon_query.single().unwrap();
-        let three_upgrades =
-            upgrade_controller.get_three_upgrades(hero_class, weapon_component.level);
+        sound_effects.write(SoundEffect::WaveCleared);
+
+        let three_upgrades = upgrade_picker.three_upgrades();
 
         let user_interface_root = commands
             .spawn((
@@ -81,8 +69,13 @@ pub fn countdown(
                 BackgroundColor(Color::NONE),
             ))
             .with_children(|parent| {
-                menu_box(parent, &scenes_materials.menu_box_materials);
-                buttons(parent, &font_materials, &dictionary, three_upgrades);
+                menu_box(parent, &reward_ui.scenes_materials.menu_box_materials);
+                buttons(
+                    parent,
+                    &reward_ui.font_materials,
+                    &reward_ui.dictionary,
+                    three_upgrades,
+                );
             })
             .insert(Name::new("RewardsUI"))
             .id();
@@ -208,59 +201,19 @@ pub fn button_handle_system(
         (&Interaction, &Reward, &Children),
         (Changed<Interaction>, With<RewardsSceneButton>),
     >,
-    mut writer: TextUiWriter,
-    mut text_query: Query<Entity>,
-    mut player_query: Query<(
-        &mut PlayerComponent,
-        &mut SkillComponent,
-        &mut PlayerListEffectsComponent,
-    )>,
-    mut weapon_query: Query<(
-        &mut WeaponComponent,
-        &mut WeaponSwingAttackComponent,
-        &mut WeaponShootAttackComponent,
-    )>,
-    upgrade_controller: Res<UpgradeController>,
+    mut text_query: Query<&mut TextColor>,
+    mut upgrade_picker: UpgradePicker,
     rewards_scene_data: Res<PauseSceneData>,
-    game_data: Res<GameData>,
     mut wave: ResMut<Wave>,
     mut commands: Commands,
 ) {
     for (interaction, reward, children) in button_query.iter_mut() {
-        let entity = text_query.get_mut(children[0]).unwrap();
+        let mut text_color = text_query.get_mut(children[0]).unwrap();
         match *interaction {
-            Interaction::None => *writer.color(entity, 0) = TextColor::from(GRAY),
-            Interaction::Hovered => *writer.color(entity, 0) = TextColor::BLACK,
+            Interaction::None => *text_color = TextColor::from(GRAY),
+            Interaction::Hovered => *text_color = TextColor::BLACK,
             Interaction::Pressed => {
-                let (mut player, mut player_skill, mut player_list_effects) =
-                    player_query.single_mut().unwrap();
-                let hero_class = player.class.clone();
-                match reward.upgrade_type {
-                    UpgradeType::Weapon => {
-                        let (mut weapon, mut swing_attack, mut shoot_attack) =
-                            weapon_query.single_mut().unwrap();
-                        if weapon.level < 3 || (weapon.level < 1 && hero_class == HeroClass::Elf) {
-                            let raw_weapons = game_data.get_weapons(hero_class);
-                            let raw_weapon = *raw_weapons
-                                .iter()
-                                .find(|raw_weapon| raw_weapon.level == weapon.level + 1)
-                                .expect("Can't find weapon");
-                            weapon.upgrade_weapon(&raw_weapon);
-                            swing_attack.upgrade(&raw_weapon);
-                            shoot_attack.upgrade(&raw_weapon);
-                        }
-                    }
-                    UpgradeType::Stats => {
-                        player.upgrade_stats(upgrade_controller.get_stats_upgrade());
-                    }
-                    UpgradeType::Effect => {
-                        player_list_effects.upgrade(upgrade_controller.get_effect_upgrade());
-                    }
-                    UpgradeType::Skill => {
-                        let skill_type = player_skill.skill.name.clone();
-                        player_skill.upgrade(upgrade_controller.get_skill_upgrade(skill_type));
-                    }
-                }
+                upgrade_picker.pick(reward.upgrade_type.clone());
                 wave.next_wave();
                 commands
                     .entity(rewards_scene_data.user_interface_root)
@@ -1,9 +1,12 @@
+use crate::headless::HeadlessFlag;
+use crate::resources::dungeon::navigation_grid::NavigationGrid;
 use crate::resources::game_data::PauseSceneData;
 use bevy::prelude::*;
 
 use crate::scenes::SceneState;
 
 pub mod dungeon;
+pub mod save;
 pub mod ui;
 
 #[derive(Resource)]
@@ -19,6 +22,7 @@ impl Plugin for SurvivalModePlugin {
             OnEnter(SceneState::PreSurvivalMode),
             dungeon::initiate::initiate_survival_mode,
         );
+        app.add_systems(OnExit(SceneState::PreSurvivalMode), save::cleanup_saved_run);
 
         app.add_systems(
             OnEnter(SceneState::InGameSurvivalMode),
@@ -33,6 +37,15 @@ impl Plugin for SurvivalModePlugin {
             ),
         );
 
+        app.add_systems(
+            Update,
+            save::store_run.run_if(
+                in_state(SceneState::InGameSurvivalMode)
+                    .and(resource_removed::<dungeon::wave::RewardsSceneFlag>)
+                    .and(not(resource_exists::<HeadlessFlag>)),
+            ),
+        );
+
         app.add_systems(
             Update,
             dungeon::wave::countdown.run_if(
@@ -43,7 +56,10 @@ impl Plugin for SurvivalModePlugin {
 
         app.add_systems(
             OnExit(SceneState::InGameSurvivalMode),
-            cleanup_survival_mode_data,
+            (
+                cleanup_survival_mode_data,
+                save::finish_saved_run.run_if(not(resource_exists::<HeadlessFlag>)),
+            ),
         );
     }
 }
@@ -56,4 +72,6 @@ fn cleanup_survival_mode_data(mut commands: Commands, survival_mode_data: Res<Su
     commands
         .entity(survival_mode_data.ground.unwrap())
         .despawn();
+
+    commands.remove_resource::<NavigationGrid>();
 }
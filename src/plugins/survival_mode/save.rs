@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use chrono::Local;
+
+use crate::components::player::PlayerComponent;
+use crate::components::player_list_effects::PlayerListEffectsComponent;
+use crate::components::skill::SkillComponent;
+use crate::components::weapon::WeaponComponent;
+use crate::resources::dungeon::wave::Wave;
+use crate::resources::profile::Profile;
+use crate::resources::run_rng::RunRng;
+use crate::resources::saved_run::SavedPlayer;
+use crate::resources::saved_survival_run::SavedSurvivalRun;
+use crate::scenes::pause_scene::SaveRunFlag;
+
+// runs right after a reward is picked, the wave has already moved on to the next one
+pub fn store_run(
+    player_query: Query<(
+        &PlayerComponent,
+        &Transform,
+        &SkillComponent,
+        &PlayerListEffectsComponent,
+    )>,
+    weapon_query: Query<&WeaponComponent>,
+    wave: Res<Wave>,
+    profile: Res<Profile>,
+    run_rng: Res<RunRng>,
+) {
+    let (player, player_transform, player_skill, player_list_effects) =
+        player_query.single().unwrap();
+    let weapon = weapon_query.single().unwrap();
+
+    let saved_survival_run = SavedSurvivalRun {
+        profile: profile.clone(),
+        wave_number: wave.wave_number,
+        wave_duration: wave.wave_duration,
+        player: SavedPlayer::new(
+            player,
+            player_transform,
+            player_skill,
+            player_list_effects,
+            weapon,
+        ),
+        run_rng: run_rng.draws(),
+        saved_time: Local::now().to_rfc3339(),
+    };
+    saved_survival_run.store();
+}
+
+// the last autosave is kept on save and quit, any other way out of the run ends it
+pub fn finish_saved_run(save_run_flag: Option<Res<SaveRunFlag>>, mut commands: Commands) {
+    if save_run_flag.is_none() {
+        SavedSurvivalRun::delete();
+    }
+
+    commands.remove_resource::<SaveRunFlag>();
+}
+
+pub fn cleanup_saved_run(mut commands: Commands) {
+    commands.remove_resource::<SavedSurvivalRun>();
+    commands.remove_resource::<SavedPlayer>();
+}
@@ -4,12 +4,11 @@ use std::f32::consts::PI;
 use std::time::Duration;
 
 use crate::components::bullet::BulletComponent;
+use crate::components::collider::{Collider, CollisionLayer};
 use crate::components::weapon::WeaponComponent;
 use crate::components::weapon_shoot_attack::WeaponShootAttackComponent;
 use crate::materials::ingame::InGameMaterials;
 use crate::resources::weapon::attack_type::AttackType;
-use crate::resources::weapon::weapon_type::WeaponType;
-use bevy::color::palettes::css::*;
 
 pub fn spawn_bullet(
     mut weapon_query: Query<(
@@ -24,11 +23,10 @@ pub fn spawn_bullet(
 
     if weapon_component.attack_type == AttackType::Shoot {
         if weapon_shoot_attack.spawn_bullet {
-            let texture = match weapon_component.name {
-                WeaponType::Bow => ingame_materials.bullet_materials.arrow.clone(),
-                WeaponType::Spear => ingame_materials.weapons_materials.spear.clone(),
-                _ => ingame_materials.bullet_materials.bullet.clone(),
-            };
+            let texture = ingame_materials
+                .weapons_materials
+                .get_bullet_texture(&weapon_component.name)
+                .unwrap_or_else(|| ingame_materials.bullet_materials.bullet.clone());
 
             let bullet_information = weapon_shoot_attack.bullet_information.clone();
 
@@ -53,12 +51,12 @@ pub fn spawn_bullet(
                 PI / 2.0 + angle
             };
 
-            let color = match weapon_component.name {
-                WeaponType::Bow | WeaponType::Spear => Color::default(),
-                WeaponType::SmallWand => Color::from(GRAY),
-                WeaponType::MagicWand => Color::default(),
-                _ => Color::from(AQUA),
-            };
+            // the color is checked when the game data is loaded
+            let color = bullet_information
+                .color
+                .as_ref()
+                .and_then(|color| Srgba::hex(color).ok())
+                .map_or(Color::default(), Color::from);
 
             weapon_shoot_attack.spawn_bullet = false;
 
@@ -85,7 +83,15 @@ pub fn spawn_bullet(
                     target_y,
                     duration: Timer::new(Duration::from_secs(3), TimerMode::Once),
                     speed: bullet_information.speed,
-                });
+                })
+                .insert(
+                    Collider::rectangle(
+                        bullet_information.width * bullet_information.scale,
+                        bullet_information.height * bullet_information.scale,
+                        CollisionLayer::Bullet,
+                    )
+                    .with_mask(&[CollisionLayer::Monster]),
+                );
         }
     }
 }
@@ -1,158 +1,135 @@
-use std::time::Duration;
+use std::collections::HashSet;
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
-use rand::Rng;
 
-use crate::components::bullet::BulletComponent;
+use crate::components::collider::CollisionLayer;
 use crate::components::invinsible_cooldown::InvisibleCooldownComponent;
 use crate::components::monster_list_effects::MonsterListEffectsComponent;
-use crate::components::weapon_shoot_attack::WeaponShootAttackComponent;
 use crate::components::{
     monster::MonsterComponent, player::PlayerComponent, weapon::WeaponComponent,
 };
-use crate::resources::hero::power::Power;
+use crate::plugins::collision::CollisionEvent;
+use crate::plugins::sound::SoundEffect;
+use crate::resources::game_data::GameData;
+use crate::resources::run_rng::RunRng;
 use crate::resources::weapon::attack_type::AttackType;
-use crate::utils::collide::collide;
+use crate::utils::damage::{Attacker, DamageSource, Defender, damage_monster, resolve_damage};
+
+// the player and weapon a hit comes from, and the game data its debuff durations come from
+#[derive(SystemParam)]
+pub struct WeaponAttack<'w, 's> {
+    player_query: Query<'w, 's, &'static PlayerComponent>,
+    weapon_query: Query<'w, 's, &'static WeaponComponent>,
+    game_data: Res<'w, GameData>,
+}
 
 pub fn bullet_collision(
     mut commands: Commands,
-    player_query: Query<&PlayerComponent>,
-    weapon_query: Query<(&WeaponComponent, &WeaponShootAttackComponent)>,
-    mut bullets_query: Query<
-        (Entity, &Transform),
-        (Without<MonsterComponent>, With<BulletComponent>),
-    >,
-    mut monsters_query: Query<
-        (
-            &mut MonsterComponent,
-            &mut MonsterListEffectsComponent,
-            &mut InvisibleCooldownComponent,
-            &Transform,
-        ),
-        (Without<BulletComponent>, With<MonsterComponent>),
-    >,
+    weapon_attack: WeaponAttack,
+    mut monsters_query: Query<(
+        &mut MonsterComponent,
+        &mut MonsterListEffectsComponent,
+        &mut InvisibleCooldownComponent,
+    )>,
+    mut collision_events: EventReader<CollisionEvent>,
+    mut run_rng: ResMut<RunRng>,
+    mut sound_effects: EventWriter<SoundEffect>,
 ) {
-    let (weapon, weapon_shoot_attack) = weapon_query.single().unwrap();
+    let weapon = weapon_attack.weapon_query.single().unwrap();
 
     if weapon.attack_type == AttackType::Shoot {
-        let player = player_query.single().unwrap();
-
-        let mut damage = if player.power == Power::Intelligence {
-            player.intelligence + weapon.intelligence
-        } else {
-            player.strength + weapon.strength
-        };
+        let player = weapon_attack.player_query.single().unwrap();
 
-        let mut rng = rand::thread_rng();
-        if rng.gen_range(0.0..1.0) < player.critical_chance {
-            damage += 1.0;
-        }
+        let attacker = Attacker::player_with_weapon(player, weapon);
 
-        for (bullet_entity, bullet_transform) in bullets_query.iter_mut() {
-            let mut bullet_position = bullet_transform.translation;
-            bullet_position.z = 0.16;
+        // a bullet only hits the first monster it touches
+        let mut used_bullets: HashSet<Entity> = HashSet::new();
 
-            let bullet_size = Vec2::new(
-                weapon_shoot_attack.bullet_information.width
-                    * weapon_shoot_attack.bullet_information.scale,
-                weapon_shoot_attack.bullet_information.height
-                    * weapon_shoot_attack.bullet_information.scale,
-            );
+        for collision in collision_events.read() {
+            if collision.layer != CollisionLayer::Bullet
+                || collision.other_layer != CollisionLayer::Monster
+                || used_bullets.contains(&collision.entity)
+            {
+                continue;
+            }
 
-            for (mut monster, mut monster_list_effects, mut invincible_cooldown, transform) in
-                monsters_query.iter_mut()
+            if let Ok((mut monster, mut monster_list_effects, mut invincible_cooldown)) =
+                monsters_query.get_mut(collision.other)
             {
-                let monster_size = Vec2::new(monster.width, monster.height);
-                let monster_position = transform.translation;
-
-                if collide(bullet_position, bullet_size, monster_position, monster_size) {
-                    let debuff_effect = weapon.debuff_effect;
-                    let trigger_chance = weapon.trigger_chance;
-
-                    if debuff_effect != None && trigger_chance > 0.0 {
-                        if rng.gen_range(0.0..1.0) < trigger_chance {
-                            monster_list_effects.activate(debuff_effect.unwrap());
-                        }
-                    }
-
-                    invincible_cooldown.hurt_duration =
-                        Timer::new(Duration::from_secs_f32(0.3), TimerMode::Once);
-
-                    monster.current_health_points = if damage > monster.current_health_points {
-                        0.0
-                    } else {
-                        monster.current_health_points - damage
-                    };
-
-                    commands.entity(bullet_entity).despawn();
-                    break;
-                }
+                let defender = Defender::monster(&monster_list_effects);
+                let damage = resolve_damage(
+                    &attacker,
+                    &defender,
+                    DamageSource::Bullet,
+                    &mut run_rng.combat,
+                );
+                damage_monster(
+                    &damage,
+                    &mut monster,
+                    &mut monster_list_effects,
+                    &weapon_attack.game_data,
+                );
+
+                invincible_cooldown.hurt_duration =
+                    Timer::new(damage.hurt_duration(), TimerMode::Once);
+
+                used_bullets.insert(collision.entity);
+                commands.entity(collision.entity).despawn();
+                sound_effects.write(SoundEffect::MonsterHit);
             }
         }
     }
 }
 
 pub fn swing_weapon_collision(
-    player_query: Query<&PlayerComponent>,
-    weapon_query: Query<
-        (&WeaponComponent, &Transform),
-        (Without<MonsterComponent>, With<WeaponComponent>),
-    >,
-    mut monsters_query: Query<
-        (
-            &mut MonsterComponent,
-            &mut MonsterListEffectsComponent,
-            &mut InvisibleCooldownComponent,
-            &Transform,
-        ),
-        (Without<WeaponComponent>, With<MonsterComponent>),
-    >,
+    weapon_attack: WeaponAttack,
+    mut monsters_query: Query<(
+        &mut MonsterComponent,
+        &mut MonsterListEffectsComponent,
+        &mut InvisibleCooldownComponent,
+    )>,
+    mut collision_events: EventReader<CollisionEvent>,
+    mut run_rng: ResMut<RunRng>,
+    mut sound_effects: EventWriter<SoundEffect>,
 ) {
-    let (weapon, weapon_transform) = weapon_query.single().unwrap();
+    let weapon = weapon_attack.weapon_query.single().unwrap();
     if weapon.attack_type == AttackType::Swing {
-        let player = player_query.single().unwrap();
-
-        let mut weapon_position = weapon_transform.translation;
-        weapon_position.z = 0.16;
-        let weapon_size = Vec2::new(
-            weapon.size_width * weapon.scale,
-            weapon.size_height * weapon.scale,
-        );
-
-        let mut damage = if player.power == Power::Intelligence {
-            player.intelligence + weapon.intelligence
-        } else {
-            player.strength + weapon.strength
-        };
-
-        let mut rng = rand::thread_rng();
-        if rng.gen_range(0.0..1.0) < player.critical_chance {
-            damage += 1.0;
-        }
+        let player = weapon_attack.player_query.single().unwrap();
+
+        let attacker = Attacker::player_with_weapon(player, weapon);
+
+        for collision in collision_events.read() {
+            if collision.layer != CollisionLayer::Weapon
+                || collision.other_layer != CollisionLayer::Monster
+            {
+                continue;
+            }
 
-        for (mut monster, mut monster_list_effects, mut invincible_cooldown, transform) in
-            monsters_query.iter_mut()
-        {
-            let monster_size = Vec2::new(monster.width, monster.height);
-            let monster_position = transform.translation;
-            if collide(weapon_position, weapon_size, monster_position, monster_size) {
-                let debuff_effect = weapon.debuff_effect;
-                let trigger_chance = weapon.trigger_chance;
-
-                if debuff_effect != None && trigger_chance > 0.0 {
-                    if rng.gen_range(0.0..1.0) < trigger_chance {
-                        monster_list_effects.activate(debuff_effect.unwrap());
-                    }
+            if let Ok((mut monster, mut monster_list_effects, mut invincible_cooldown)) =
+                monsters_query.get_mut(collision.other)
+            {
+                let defender = Defender::monster(&monster_list_effects);
+                let damage = resolve_damage(
+                    &attacker,
+                    &defender,
+                    DamageSource::Swing,
+                    &mut run_rng.combat,
+                );
+                damage_monster(
+                    &damage,
+                    &mut monster,
+                    &mut monster_list_effects,
+                    &weapon_attack.game_data,
+                );
+
+                // the swing overlaps a monster for several frames, only the first one is a new hit
+                if invincible_cooldown.hurt_duration.finished() {
+                    sound_effects.write(SoundEffect::MonsterHit);
                 }
 
                 invincible_cooldown.hurt_duration =
-                    Timer::new(Duration::from_secs_f32(0.3), TimerMode::Once);
-
-                monster.current_health_points = if damage > monster.current_health_points {
-                    0.0
-                } else {
-                    monster.current_health_points - damage
-                };
+                    Timer::new(damage.hurt_duration(), TimerMode::Once);
             }
         }
     }
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
+#[cfg(feature = "render")]
 use bevy::sprite::Anchor;
+#[cfg(feature = "render")]
 use bevy::window::PrimaryWindow;
 use std::f32::consts::PI;
 
@@ -7,10 +9,12 @@ use crate::components::player::PlayerComponent;
 use crate::components::weapon::WeaponComponent;
 use crate::components::weapon_shoot_attack::WeaponShootAttackComponent;
 use crate::components::weapon_swing_attack::WeaponSwingAttackComponent;
+#[cfg(feature = "render")]
 use crate::materials::ingame::InGameMaterials;
+#[cfg(feature = "render")]
 use crate::plugins::camera::Orthographic2DCamera;
+use crate::plugins::weapon::AimTarget;
 use crate::resources::weapon::attack_type::AttackType;
-use crate::resources::weapon::weapon_type::WeaponType;
 
 pub fn attach_to_player(
     mut weapon_query: Query<(&WeaponComponent, &mut Transform), Without<PlayerComponent>>,
@@ -29,21 +33,17 @@ pub fn attach_to_player(
         player_transform.translation.x - weapon_size_width / 2.0 * scale;
 }
 
-pub fn aim(
+// turns the cursor position into the world position the weapon aims at
+#[cfg(feature = "render")]
+pub fn cursor_aim_target(
     q_camera: Query<(&Camera, &GlobalTransform), With<Orthographic2DCamera>>,
-    mut weapon_query: Query<(
-        &WeaponComponent,
-        &mut WeaponShootAttackComponent,
-        &mut WeaponSwingAttackComponent,
-        &mut Transform,
-    )>,
     primary_query: Query<&Window, With<PrimaryWindow>>,
-    time: Res<Time>,
+    mut aim_target: ResMut<AimTarget>,
 ) {
     let (camera, camera_transform) = q_camera.single().unwrap();
     let wnd = primary_query.single().unwrap();
 
-    if let Some(screen_pos) = wnd.cursor_position() {
+    aim_target.position = wnd.cursor_position().map(|screen_pos| {
         let window_size = Vec2::new(wnd.width() as f32, wnd.height() as f32);
 
         // translate y coord of cursor, according to https://bevyengine.org/learn/migration-guides/0.10-0.11/#consistent-screen-space-coordinates
@@ -52,8 +52,21 @@ pub fn aim(
 
         let ndc_to_world = camera_transform.compute_matrix() * camera.clip_from_view().inverse();
         let world_pos = ndc_to_world.project_point3(ndc.extend(-1.0));
-        let mouse_pos: Vec2 = world_pos.truncate();
+        world_pos.truncate()
+    });
+}
 
+pub fn aim(
+    mut weapon_query: Query<(
+        &WeaponComponent,
+        &mut WeaponShootAttackComponent,
+        &mut WeaponSwingAttackComponent,
+        &mut Transform,
+    )>,
+    aim_target: Res<AimTarget>,
+    time: Res<Time>,
+) {
+    if let Some(mouse_pos) = aim_target.position {
         let (
             weapon_component,
             mut weapon_shoot_attack,
@@ -96,16 +109,9 @@ pub fn aim(
                     weapon_shoot_attack.cooldown.tick(time.delta());
                 }
 
-                if weapon_component.name == WeaponType::Bow {
-                    weapon_transform.rotation = Quat::from_rotation_z(rotate_z + PI / 2.0);
-                }
-
-                if weapon_component.name == WeaponType::Spear {
-                    weapon_transform.rotation = Quat::from_rotation_z(rotate_z);
-                }
-
-                if weapon_component.name == WeaponType::MagicSword {
-                    weapon_transform.rotation = Quat::from_rotation_z(rotate_z);
+                if let Some(aim_rotation) = weapon_component.aim_rotation {
+                    weapon_transform.rotation =
+                        Quat::from_rotation_z(rotate_z + aim_rotation.to_radians());
                 }
 
                 weapon_shoot_attack.bullet_target_x = mouse_pos.x;
@@ -115,6 +121,7 @@ pub fn aim(
     }
 }
 
+#[cfg(feature = "render")]
 pub fn change_weapon_texture(
     mut weapon_query: Query<(Ref<WeaponComponent>, &mut Sprite)>,
     ingame_materials: Res<InGameMaterials>,
@@ -131,18 +138,8 @@ pub fn change_weapon_texture(
             AttackType::Shoot => Anchor::Center,
         };
 
-        sprite.image = match weapon.name {
-            WeaponType::ShortSword => ingame_materials.weapons_materials.short_sword.clone(),
-            WeaponType::Sword => ingame_materials.weapons_materials.sword.clone(),
-            WeaponType::BigMachete => ingame_materials.weapons_materials.machete.clone(),
-            WeaponType::SmallWand => ingame_materials.weapons_materials.small_wand.clone(),
-            WeaponType::MagicWand => ingame_materials.weapons_materials.magic_wand.clone(),
-            WeaponType::MagicSword => ingame_materials.weapons_materials.magic_sword.clone(),
-            WeaponType::Mace => ingame_materials.weapons_materials.mace.clone(),
-            WeaponType::BigHammer => ingame_materials.weapons_materials.big_hammer.clone(),
-            WeaponType::SmallHammer => ingame_materials.weapons_materials.small_hammer.clone(),
-            WeaponType::Bow => ingame_materials.weapons_materials.bow.clone(),
-            WeaponType::Spear => ingame_materials.weapons_materials.spear.clone(),
-        };
+        sprite.image = ingame_materials
+            .weapons_materials
+            .get_texture(&weapon.name);
     }
 }
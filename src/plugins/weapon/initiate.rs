@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use bevy::sprite::Anchor;
 use std::time::Duration;
 
+use crate::components::collider::{Collider, CollisionLayer};
 use crate::components::weapon::WeaponComponent;
 use crate::components::weapon_shoot_attack::WeaponShootAttackComponent;
 use crate::components::weapon_swing_attack::WeaponSwingAttackComponent;
@@ -9,29 +10,26 @@ use crate::materials::ingame::InGameMaterials;
 use crate::plugins::weapon::WeaponEntity;
 use crate::resources::game_data::GameData;
 use crate::resources::profile::Profile;
+use crate::resources::saved_run::SavedPlayer;
 use crate::resources::weapon::attack_type::AttackType;
 use crate::resources::weapon::bullet::Bullet;
-use crate::resources::weapon::weapon_type::WeaponType;
 
 pub fn initiate_weapon(
     ingame_materials: Res<InGameMaterials>,
     game_data: Res<GameData>,
     mut commands: Commands,
     profile: Res<Profile>,
+    saved_player: Option<Res<SavedPlayer>>,
 ) {
     let class = profile.hero_class.clone();
-    let weapon = game_data.get_weapon(class);
+    let weapon = game_data.get_weapon(class.clone());
 
     let weapon_width = weapon.width;
     let weapon_height = weapon.height;
 
-    let weapon_texture = match weapon.name {
-        WeaponType::Bow => ingame_materials.weapons_materials.bow.clone(),
-        WeaponType::ShortSword => ingame_materials.weapons_materials.short_sword.clone(),
-        WeaponType::SmallWand => ingame_materials.weapons_materials.small_wand.clone(),
-        WeaponType::SmallHammer => ingame_materials.weapons_materials.small_hammer.clone(),
-        _ => ingame_materials.weapons_materials.short_sword.clone(),
-    };
+    let weapon_texture = ingame_materials
+        .weapons_materials
+        .get_texture(&weapon.name);
 
     let scale = weapon.scale;
 
@@ -46,8 +44,41 @@ pub fn initiate_weapon(
         height: 0.0,
         speed: 0.0,
         scale: 0.0,
+        sprite: None,
+        color: None,
     });
 
+    let mut weapon_component = WeaponComponent::new(&weapon);
+
+    let mut swing_attack = WeaponSwingAttackComponent {
+        attack_duration,
+        swing_speed: weapon.swing_speed.unwrap_or(0.0),
+        is_swinging: false,
+    };
+
+    let mut shoot_attack = WeaponShootAttackComponent {
+        bullet_information: bullet,
+        spawn_bullet: false,
+        bullet_target_x: 0.0,
+        bullet_target_y: 0.0,
+        cooldown_second: weapon.cooldown.unwrap_or(0),
+        cooldown,
+    };
+
+    // a resumed run gets back the weapon it was upgraded to, the texture follows the change
+    if let Some(saved_player) = saved_player
+        && saved_player.weapon_level != weapon.level
+    {
+        let raw_weapons = game_data.get_weapons(class);
+        let raw_weapon = raw_weapons
+            .iter()
+            .find(|raw_weapon| raw_weapon.level == saved_player.weapon_level)
+            .expect("Can't find weapon");
+        weapon_component.upgrade_weapon(raw_weapon);
+        swing_attack.upgrade(raw_weapon);
+        shoot_attack.upgrade(raw_weapon);
+    }
+
     let weapon_entity = commands
         .spawn((
             Sprite {
@@ -65,35 +96,31 @@ pub fn initiate_weapon(
             },
         ))
         .insert(Name::new("Weapon"))
-        .insert(WeaponComponent {
-            strength: weapon.strength,
-            intelligence: weapon.intelligence,
-            level: weapon.level,
-            name: weapon.name.clone(),
-            attack_type: weapon.attack_type.clone(),
-            scale,
-            size_width: weapon_width,
-            size_height: weapon_height,
-            trigger_chance: weapon.trigger_chance.unwrap_or(0.0),
-            buff_effect: None,
-            debuff_effect: None,
-        })
-        .insert(WeaponSwingAttackComponent {
-            attack_duration,
-            swing_speed: weapon.swing_speed.unwrap_or(0.0),
-            is_swinging: false,
-        })
-        .insert(WeaponShootAttackComponent {
-            bullet_information: bullet,
-            spawn_bullet: false,
-            bullet_target_x: 0.0,
-            bullet_target_y: 0.0,
-            cooldown_second: weapon.cooldown.unwrap_or(0),
-            cooldown,
-        })
+        .insert(weapon_collider(&weapon_component))
+        .insert(weapon_component)
+        .insert(swing_attack)
+        .insert(shoot_attack)
         .id();
 
     commands.insert_resource(WeaponEntity {
         entity: weapon_entity,
     });
 }
+
+pub fn weapon_collider(weapon: &WeaponComponent) -> Collider {
+    Collider::rectangle(
+        weapon.size_width * weapon.scale,
+        weapon.size_height * weapon.scale,
+        CollisionLayer::Weapon,
+    )
+    .with_mask(&[CollisionLayer::Monster])
+}
+
+// upgrades change the size of the weapon, the collider follows it
+pub fn update_weapon_collider(
+    mut weapon_query: Query<(&WeaponComponent, &mut Collider), Changed<WeaponComponent>>,
+) {
+    for (weapon, mut collider) in weapon_query.iter_mut() {
+        *collider = weapon_collider(weapon);
+    }
+}
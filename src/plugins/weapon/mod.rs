@@ -1,3 +1,5 @@
+#[cfg(feature = "render")]
+use crate::headless::HeadlessFlag;
 use crate::resources::game_data::PauseSceneData;
 use bevy::prelude::*;
 
@@ -16,8 +18,16 @@ pub struct WeaponEntity {
     pub entity: Entity,
 }
 
+// world position the weapon points at, set from the cursor or by a headless run
+#[derive(Resource, Default)]
+pub struct AimTarget {
+    pub position: Option<Vec2>,
+}
+
 impl Plugin for WeaponPlugin {
     fn build(&self, app: &mut App) {
+        app.init_resource::<AimTarget>();
+
         app.add_systems(
             OnEnter(SceneState::PreClassicMode),
             initiate::initiate_weapon,
@@ -32,7 +42,7 @@ impl Plugin for WeaponPlugin {
             (
                 feature::attach_to_player,
                 feature::aim,
-                feature::change_weapon_texture,
+                initiate::update_weapon_collider,
                 bullet::spawn_bullet,
                 bullet::bullet_handle,
                 collisions::bullet_collision,
@@ -45,6 +55,21 @@ impl Plugin for WeaponPlugin {
                 ),
         );
 
+        #[cfg(feature = "render")]
+        app.add_systems(
+            Update,
+            (
+                feature::cursor_aim_target.before(feature::aim),
+                feature::change_weapon_texture,
+            )
+                .run_if(
+                    in_state(SceneState::InGameClassicMode)
+                        .or(in_state(SceneState::InGameSurvivalMode))
+                        .and(not(resource_exists::<PauseSceneData>))
+                        .and(not(resource_exists::<HeadlessFlag>)),
+                ),
+        );
+
         app.add_systems(
             OnExit(SceneState::InGameClassicMode),
             (cleanup::cleanup_weapon, cleanup::cleanup_bullet),
@@ -3,7 +3,6 @@ use serde::{Deserialize, Serialize};
 
 use crate::resources::glossary::Glossary;
 use crate::resources::language::Language;
-use crate::resources::setting::Setting;
 
 #[derive(Resource, Serialize, Deserialize, Debug, Clone)]
 pub struct Dictionary {
@@ -14,9 +13,21 @@ pub struct Dictionary {
 
 impl Dictionary {
     pub fn new(current_language: Language) -> Self {
+        Dictionary::from_glossaries(
+            Glossary::new(Language::VI),
+            Glossary::new(Language::EN),
+            current_language,
+        )
+    }
+
+    pub fn from_glossaries(
+        vi_glossary: Glossary,
+        en_glossary: Glossary,
+        current_language: Language,
+    ) -> Self {
         Dictionary {
-            vi_glossary: Glossary::new(Language::VI),
-            en_glossary: Glossary::new(Language::EN),
+            vi_glossary,
+            en_glossary,
             current_language,
         }
     }
@@ -36,10 +47,3 @@ impl Dictionary {
         self.current_language = language;
     }
 }
-
-impl FromWorld for Dictionary {
-    fn from_world(world: &mut World) -> Self {
-        let setting = world.get_resource_mut::<Setting>().unwrap();
-        Dictionary::new(setting.get_language())
-    }
-}
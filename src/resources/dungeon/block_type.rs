@@ -1,6 +1,9 @@
 use bevy::prelude::*;
 use bevy_inspector_egui::InspectorOptions;
 
+use crate::components::collider::{Collider, CollisionLayer};
+use crate::config::TILE_SIZE;
+
 #[derive(Component, PartialEq, Eq, Debug, InspectorOptions)]
 pub enum BlockType {
     None,
@@ -8,4 +11,26 @@ pub enum BlockType {
     WallBottom,
     WallLeft,
     WallRight,
+    Obstacle,
+}
+
+impl BlockType {
+    // the top walls block the tile above the one they are placed on
+    pub fn collider(&self) -> Option<Collider> {
+        match *self {
+            BlockType::None => None,
+            BlockType::WallTop => Some(
+                Collider::rectangle(TILE_SIZE, TILE_SIZE, CollisionLayer::Block)
+                    .with_offset(Vec2::new(0.0, TILE_SIZE)),
+            ),
+            BlockType::WallBottom
+            | BlockType::WallLeft
+            | BlockType::WallRight
+            | BlockType::Obstacle => Some(Collider::rectangle(
+                TILE_SIZE,
+                TILE_SIZE,
+                CollisionLayer::Block,
+            )),
+        }
+    }
 }
@@ -1,11 +1,9 @@
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
 use crate::resources::dungeon::position::Position;
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Floor {
     pub map_name: String,
     pub map: Vec<Vec<f32>>,
@@ -14,25 +12,13 @@ pub struct Floor {
     pub current_position: Position,
     pub end_room_position: Position,
     pub start_room_position: Position,
+    #[serde(with = "cleared_positions")]
     pub cleared_positions: HashMap<Position, usize>,
     pub is_last_floor: bool,
 }
 
-const PREFIX: &str = "./assets/floors/";
-
 impl Floor {
-    pub fn new(file_name: String, is_last_floor: bool) -> Floor {
-        let path = format!("{}{}", PREFIX, file_name);
-        let map_name = path.clone();
-        let file = match File::open(path) {
-            Ok(file) => file,
-            Err(err) => panic!("Can't open map file {}: {}", file_name, err.to_string()),
-        };
-
-        let reader = BufReader::new(file);
-
-        let mut map: Vec<Vec<f32>> = Vec::new();
-
+    pub fn from_map(map_name: String, map: Vec<Vec<f32>>, is_last_floor: bool) -> Floor {
         let mut start_room_position: Position = Position {
             row_index: 0,
             column_index: 0,
@@ -42,29 +28,20 @@ impl Floor {
             column_index: 0,
         };
 
-        for (row_index, line) in reader.lines().enumerate() {
-            let str_line = line.unwrap();
-            let str_numbers = str_line.split(" ");
-
-            let mut row: Vec<f32> = Vec::new();
-
-            for (column_index, str_number) in str_numbers.enumerate() {
-                let number: f32 = str_number.parse::<f32>().unwrap();
-
-                if number == number.floor() + 0.1 {
+        for (row_index, row) in map.iter().enumerate() {
+            for (column_index, number) in row.iter().enumerate() {
+                if *number == number.floor() + 0.1 {
                     start_room_position = Position {
                         row_index,
                         column_index,
                     };
-                } else if number == number.floor() + 0.2 {
+                } else if *number == number.floor() + 0.2 {
                     end_room_position = Position {
                         row_index,
                         column_index,
                     }
                 }
-                row.push(number);
             }
-            map.push(row);
         }
 
         let total_rows = map.len();
@@ -86,3 +63,26 @@ impl Floor {
         }
     }
 }
+
+// json object keys have to be strings, so the visited rooms are stored as a list of pairs
+mod cleared_positions {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::HashMap;
+
+    use crate::resources::dungeon::position::Position;
+
+    pub fn serialize<S: Serializer>(
+        cleared_positions: &HashMap<Position, usize>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let pairs: Vec<(&Position, &usize)> = cleared_positions.iter().collect();
+        pairs.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<Position, usize>, D::Error> {
+        let pairs: Vec<(Position, usize)> = Vec::deserialize(deserializer)?;
+        Ok(pairs.into_iter().collect())
+    }
+}
@@ -0,0 +1,218 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::VecDeque;
+
+use crate::resources::dungeon::floor::Floor;
+use crate::resources::dungeon::position::Position;
+
+pub const START_ROOM_MARK: f32 = 0.1;
+pub const END_ROOM_MARK: f32 = 0.2;
+
+const BASE_GRID_SIZE: usize = 4;
+const MAX_GRID_SIZE: usize = 7;
+const BASE_TOTAL_ROOMS: usize = 6;
+const ROOMS_PER_FLOOR: usize = 2;
+
+pub struct FloorGenerator {
+    rng: StdRng,
+    // room ids from list_room.txt and mod packs that classic floors can use
+    room_ids: Vec<f32>,
+}
+
+impl FloorGenerator {
+    pub fn new(seed: u64, room_ids: Vec<f32>) -> Self {
+        FloorGenerator {
+            rng: StdRng::seed_from_u64(seed),
+            room_ids,
+        }
+    }
+
+    // Grows a connected group of rooms from a random cell, then marks the two
+    // rooms furthest apart (by number of doors) as the start and end rooms.
+    pub fn generate(&mut self, floor_index: usize, is_last_floor: bool) -> Floor {
+        let grid_size = (BASE_GRID_SIZE + floor_index / 2).min(MAX_GRID_SIZE);
+        let total_rooms =
+            (BASE_TOTAL_ROOMS + floor_index * ROOMS_PER_FLOOR).min(grid_size * grid_size);
+
+        let mut map: Vec<Vec<f32>> = vec![vec![0.0; grid_size]; grid_size];
+
+        let first_room = Position {
+            row_index: self.rng.gen_range(0..grid_size),
+            column_index: self.rng.gen_range(0..grid_size),
+        };
+        map[first_room.row_index][first_room.column_index] = self.random_room_id();
+
+        let mut rooms: Vec<Position> = vec![first_room];
+        while rooms.len() < total_rooms {
+            let from = rooms[self.rng.gen_range(0..rooms.len())];
+            let empty_neighbours: Vec<Position> = neighbours(from, grid_size)
+                .into_iter()
+                .filter(|position| map[position.row_index][position.column_index] == 0.0)
+                .collect();
+
+            if empty_neighbours.is_empty() {
+                continue;
+            }
+
+            let room = empty_neighbours[self.rng.gen_range(0..empty_neighbours.len())];
+            map[room.row_index][room.column_index] = self.random_room_id();
+            rooms.push(room);
+        }
+
+        let start_room_position = furthest_room(&map, first_room);
+        let end_room_position = furthest_room(&map, start_room_position);
+
+        map[start_room_position.row_index][start_room_position.column_index] += START_ROOM_MARK;
+        map[end_room_position.row_index][end_room_position.column_index] += END_ROOM_MARK;
+
+        Floor::from_map(
+            format!("generated_floor_{:02}", floor_index + 1),
+            map,
+            is_last_floor,
+        )
+    }
+
+    fn random_room_id(&mut self) -> f32 {
+        self.room_ids[self.rng.gen_range(0..self.room_ids.len())]
+    }
+}
+
+fn neighbours(position: Position, grid_size: usize) -> Vec<Position> {
+    let mut neighbours = Vec::new();
+
+    if position.row_index > 0 {
+        neighbours.push(Position {
+            row_index: position.row_index - 1,
+            column_index: position.column_index,
+        });
+    }
+
+    if position.row_index < grid_size - 1 {
+        neighbours.push(Position {
+            row_index: position.row_index + 1,
+            column_index: position.column_index,
+        });
+    }
+
+    if position.column_index > 0 {
+        neighbours.push(Position {
+            row_index: position.row_index,
+            column_index: position.column_index - 1,
+        });
+    }
+
+    if position.column_index < grid_size - 1 {
+        neighbours.push(Position {
+            row_index: position.row_index,
+            column_index: position.column_index + 1,
+        });
+    }
+
+    neighbours
+}
+
+fn furthest_room(map: &[Vec<f32>], from: Position) -> Position {
+    let grid_size = map.len();
+    let mut distances: Vec<Vec<Option<usize>>> = vec![vec![None; grid_size]; grid_size];
+    distances[from.row_index][from.column_index] = Some(0);
+
+    let mut furthest = from;
+    let mut furthest_distance = 0;
+
+    let mut queue = VecDeque::from([from]);
+    while let Some(position) = queue.pop_front() {
+        let distance = distances[position.row_index][position.column_index].unwrap();
+        if distance > furthest_distance {
+            furthest = position;
+            furthest_distance = distance;
+        }
+
+        for neighbour in neighbours(position, grid_size) {
+            let (row_index, column_index) = (neighbour.row_index, neighbour.column_index);
+            if map[row_index][column_index] != 0.0 && distances[row_index][column_index].is_none() {
+                distances[row_index][column_index] = Some(distance + 1);
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    furthest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROOM_IDS: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+
+    fn generate_floors(seed: u64, total_floors: usize) -> Vec<Floor> {
+        let mut floor_generator = FloorGenerator::new(seed, ROOM_IDS.to_vec());
+        (0..total_floors)
+            .map(|floor_index| {
+                floor_generator.generate(floor_index, floor_index + 1 == total_floors)
+            })
+            .collect()
+    }
+
+    fn total_rooms(floor: &Floor) -> usize {
+        floor
+            .map
+            .iter()
+            .flatten()
+            .filter(|room_id| **room_id != 0.0)
+            .count()
+    }
+
+    // rooms that can be walked to from the start room, neighbouring rooms share a door
+    fn reachable_rooms(floor: &Floor) -> Vec<Position> {
+        let mut reachable = vec![floor.start_room_position];
+        let mut queue = VecDeque::from([floor.start_room_position]);
+        while let Some(position) = queue.pop_front() {
+            for neighbour in neighbours(position, floor.map.len()) {
+                if floor.map[neighbour.row_index][neighbour.column_index] != 0.0
+                    && !reachable.contains(&neighbour)
+                {
+                    reachable.push(neighbour);
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+        reachable
+    }
+
+    #[test]
+    fn the_same_seed_gives_the_same_floors() {
+        for seed in 0..20 {
+            let floors = generate_floors(seed, 5);
+            let same_floors = generate_floors(seed, 5);
+
+            for (floor, same_floor) in floors.iter().zip(same_floors.iter()) {
+                assert_eq!(floor.map, same_floor.map);
+                assert_eq!(floor.start_room_position, same_floor.start_room_position);
+                assert_eq!(floor.end_room_position, same_floor.end_room_position);
+            }
+        }
+    }
+
+    #[test]
+    fn the_end_room_can_be_reached_from_the_start_room() {
+        for seed in 0..20 {
+            for floor in generate_floors(seed, 5) {
+                assert_ne!(floor.start_room_position, floor.end_room_position);
+                assert!(reachable_rooms(&floor).contains(&floor.end_room_position));
+            }
+        }
+    }
+
+    #[test]
+    fn floors_grow_with_the_floor_index() {
+        for seed in 0..20 {
+            let floors = generate_floors(seed, 5);
+
+            for (floor, next_floor) in floors.iter().zip(floors.iter().skip(1)) {
+                assert!(next_floor.total_rows >= floor.total_rows);
+                assert!(total_rooms(next_floor) > total_rooms(floor));
+            }
+        }
+    }
+}
@@ -1,16 +1,16 @@
 use bevy::prelude::*;
-
-use rand::distributions::{Distribution, Uniform};
-use std::fs::File;
-use std::io::{self, BufRead};
+use serde::{Deserialize, Serialize};
 
 pub mod block_type;
 pub mod door;
 pub mod doors;
 pub mod end_point;
 pub mod floor;
+pub mod floor_generator;
 pub mod ground;
 pub mod layer;
+pub mod navigation_grid;
+pub mod parse_error;
 pub mod position;
 pub mod room;
 pub mod rooms;
@@ -19,59 +19,41 @@ pub mod wall_type;
 pub mod walls;
 pub mod wave;
 
-use crate::config::LIST_FLOOR_FILE;
+use crate::resources::mod_packs::ModFloors;
 use floor::Floor;
+use floor_generator::FloorGenerator;
+use rooms::Rooms;
 
 const TOTAL_DUNGEON_FLOORS: usize = 5;
 
-#[derive(Resource)]
+#[derive(Resource, Serialize, Deserialize, Clone)]
 pub struct Dungeon {
     pub floors: Vec<Floor>,
     pub current_floor: Floor,
 }
 
 impl Dungeon {
-    pub fn new() -> Self {
-        let file = match File::open(LIST_FLOOR_FILE) {
-            Ok(file) => file,
-            Err(err) => panic!("Can't open list floor file: {}", err.to_string()),
-        };
-
-        let buffered = io::BufReader::new(file);
-        let lines: Vec<_> = buffered.lines().collect();
-        let total_available_floors: usize = lines.len();
-
-        let mut floors: Vec<Floor> = Vec::new();
-
-        let mut rng = rand::thread_rng();
-        let dice = Uniform::from(0..total_available_floors);
-
-        let mut floors_indexes: Vec<usize> = Vec::new();
-        loop {
-            let throw = dice.sample(&mut rng);
-
-            if !floors_indexes.contains(&throw) {
-                floors_indexes.push(throw);
-            }
-
-            if floors_indexes.len() == TOTAL_DUNGEON_FLOORS {
-                break;
-            }
-        }
-
-        let mut floor_index = 0;
-
-        for (index, line) in lines.into_iter().enumerate() {
-            if floors_indexes.contains(&index) {
-                let map_name = line.unwrap();
-                if floor_index == TOTAL_DUNGEON_FLOORS - 1 {
-                    floors.push(Floor::new(map_name, true));
-                } else {
-                    floors.push(Floor::new(map_name, false));
+    pub fn new(seed: u64, rooms: &Rooms, mod_floors: &ModFloors) -> Self {
+        let mut floor_generator = FloorGenerator::new(seed, rooms.classic_room_ids());
+        let total_floors = TOTAL_DUNGEON_FLOORS.max(mod_floors.last_floor_number());
+
+        // every floor is still generated so the same seed gives the same dungeon
+        // with or without mod floors replacing some of them
+        let floors: Vec<Floor> = (0..total_floors)
+            .map(|floor_index| {
+                let is_last_floor = floor_index == total_floors - 1;
+                let floor = floor_generator.generate(floor_index, is_last_floor);
+
+                match mod_floors.get(floor_index + 1) {
+                    Some(map) => Floor::from_map(
+                        format!("mod_floor_{:02}", floor_index + 1),
+                        map.clone(),
+                        is_last_floor,
+                    ),
+                    None => floor,
                 }
-                floor_index += 1;
-            }
-        }
+            })
+            .collect();
 
         let current_floor = floors[0].clone();
 
@@ -0,0 +1,190 @@
+use bevy::prelude::*;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::config::TILE_SIZE;
+
+const STRAIGHT_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+
+const NEIGHBOURS: [(i32, i32); 8] = [
+    (-1, 0),
+    (1, 0),
+    (0, -1),
+    (0, 1),
+    (-1, -1),
+    (-1, 1),
+    (1, -1),
+    (1, 1),
+];
+
+// Walkable tiles of the current room and a flow field towards the player.
+// The field is shared by every monster, so it is only rebuilt when the player
+// moves to another tile or a passage opens or closes.
+#[derive(Resource)]
+pub struct NavigationGrid {
+    // centre of the tile at row 0, column 0
+    start_x: f32,
+    start_y: f32,
+    total_rows: usize,
+    total_columns: usize,
+    blocked: Vec<Vec<bool>>,
+    distances: Vec<Vec<u32>>,
+    target_tile: Option<(usize, usize)>,
+    is_dirty: bool,
+}
+
+impl NavigationGrid {
+    pub fn new(tilemap: &[Vec<i32>], start_x: f32, start_y: f32) -> Self {
+        let total_rows = tilemap.len();
+        let total_columns = tilemap[0].len();
+
+        NavigationGrid {
+            start_x,
+            start_y,
+            total_rows,
+            total_columns,
+            blocked: tilemap
+                .iter()
+                .map(|row| row.iter().map(|value| *value != 0).collect())
+                .collect(),
+            distances: vec![vec![u32::MAX; total_columns]; total_rows],
+            target_tile: None,
+            is_dirty: true,
+        }
+    }
+
+    pub fn set_blocked(&mut self, row_index: usize, column_index: usize, is_blocked: bool) {
+        if self.blocked[row_index][column_index] != is_blocked {
+            self.blocked[row_index][column_index] = is_blocked;
+            self.is_dirty = true;
+        }
+    }
+
+    pub fn tile(&self, position: Vec2) -> Option<(usize, usize)> {
+        let column_index = ((position.x - self.start_x) / TILE_SIZE).round();
+        let row_index = ((self.start_y - position.y) / TILE_SIZE).round();
+
+        if row_index < 0.0
+            || column_index < 0.0
+            || row_index as usize >= self.total_rows
+            || column_index as usize >= self.total_columns
+        {
+            return None;
+        }
+
+        Some((row_index as usize, column_index as usize))
+    }
+
+    pub fn tile_centre(&self, row_index: usize, column_index: usize) -> Vec2 {
+        Vec2::new(
+            self.start_x + column_index as f32 * TILE_SIZE,
+            self.start_y - row_index as f32 * TILE_SIZE,
+        )
+    }
+
+    pub fn update_flow_field(&mut self, target: Vec2) {
+        let target_tile = self.tile(target);
+        if target_tile == self.target_tile && !self.is_dirty {
+            return;
+        }
+
+        self.target_tile = target_tile;
+        self.is_dirty = false;
+
+        for row in self.distances.iter_mut() {
+            row.fill(u32::MAX);
+        }
+
+        let Some((target_row, target_column)) = target_tile else {
+            return;
+        };
+
+        let mut queue = BinaryHeap::new();
+        self.distances[target_row][target_column] = 0;
+        queue.push(Reverse((0, target_row, target_column)));
+
+        while let Some(Reverse((distance, row_index, column_index))) = queue.pop() {
+            if distance > self.distances[row_index][column_index] {
+                continue;
+            }
+
+            for (neighbour, cost) in self.walkable_neighbours(row_index, column_index) {
+                let (neighbour_row, neighbour_column) = neighbour;
+                let neighbour_distance = distance + cost;
+                if neighbour_distance < self.distances[neighbour_row][neighbour_column] {
+                    self.distances[neighbour_row][neighbour_column] = neighbour_distance;
+                    queue.push(Reverse((
+                        neighbour_distance,
+                        neighbour_row,
+                        neighbour_column,
+                    )));
+                }
+            }
+        }
+    }
+
+    // None when the monster shares the tile of the player or there is no path,
+    // the monster then walks straight to the player
+    pub fn direction(&self, position: Vec2) -> Option<Vec2> {
+        let (row_index, column_index) = self.tile(position)?;
+        let distance = self.distances[row_index][column_index];
+        if distance == 0 || distance == u32::MAX {
+            return None;
+        }
+
+        let ((next_row, next_column), _) = self
+            .walkable_neighbours(row_index, column_index)
+            .into_iter()
+            .min_by_key(|((row, column), _)| self.distances[*row][*column])?;
+
+        if self.distances[next_row][next_column] >= distance {
+            return None;
+        }
+
+        Some((self.tile_centre(next_row, next_column) - position).normalize_or_zero())
+    }
+
+    fn is_walkable(&self, row_index: i32, column_index: i32) -> bool {
+        row_index >= 0
+            && column_index >= 0
+            && (row_index as usize) < self.total_rows
+            && (column_index as usize) < self.total_columns
+            && !self.blocked[row_index as usize][column_index as usize]
+    }
+
+    // diagonal steps are only allowed when they do not cut the corner of a wall
+    fn walkable_neighbours(
+        &self,
+        row_index: usize,
+        column_index: usize,
+    ) -> Vec<((usize, usize), u32)> {
+        let row_index = row_index as i32;
+        let column_index = column_index as i32;
+
+        NEIGHBOURS
+            .iter()
+            .filter(|(row_offset, column_offset)| {
+                let is_diagonal = *row_offset != 0 && *column_offset != 0;
+                self.is_walkable(row_index + row_offset, column_index + column_offset)
+                    && (!is_diagonal
+                        || (self.is_walkable(row_index + row_offset, column_index)
+                            && self.is_walkable(row_index, column_index + column_offset)))
+            })
+            .map(|(row_offset, column_offset)| {
+                let cost = if *row_offset != 0 && *column_offset != 0 {
+                    DIAGONAL_COST
+                } else {
+                    STRAIGHT_COST
+                };
+                (
+                    (
+                        (row_index + row_offset) as usize,
+                        (column_index + column_offset) as usize,
+                    ),
+                    cost,
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,37 @@
+use std::error::Error;
+use std::fmt;
+
+// why a room or floor file can't be used, `line` starts at 1 and is missing when the
+// problem is about the file as a whole
+#[derive(Debug, Clone)]
+pub struct ParseError {
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: String) -> Self {
+        ParseError {
+            line: None,
+            message,
+        }
+    }
+
+    pub fn at(line: usize, message: String) -> Self {
+        ParseError {
+            line: Some(line),
+            message,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for ParseError {}
@@ -1,7 +1,8 @@
 use bevy_inspector_egui::InspectorOptions;
+use serde::{Deserialize, Serialize};
 use std::hash::{Hash, Hasher};
 
-#[derive(Copy, Clone, PartialEq, Eq, Debug, InspectorOptions, Default)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, InspectorOptions, Default, Serialize, Deserialize)]
 pub struct Position {
     pub row_index: usize,
     pub column_index: usize,
@@ -1,10 +1,14 @@
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
+use bevy::prelude::*;
+use std::fs;
+use std::path::Path;
 
-const PREFIX: &str = "./assets/rooms/";
+use crate::plugins::classic_mode::dungeon::{TOTAL_TILE_HEIGHT, TOTAL_TILE_WIDTH};
+use crate::resources::dungeon::door::Door;
+use crate::resources::dungeon::parse_error::ParseError;
 
-#[derive(Clone)]
+pub const PREFIX: &str = "./assets/rooms/";
+
+#[derive(Asset, TypePath, Clone)]
 pub struct Room {
     pub id: f32,
     pub tilemap: Vec<Vec<i32>>,
@@ -12,33 +16,155 @@ pub struct Room {
 
 impl Room {
     pub fn new(file_name: String) -> Self {
-        let spilt_file_name: Vec<&str> = file_name.split(".").collect();
-
-        let id = spilt_file_name[0].parse::<f32>().unwrap();
-
         let path = format!("{}{}", PREFIX, file_name);
-        let file = match File::open(path) {
-            Ok(file) => file,
-            Err(err) => panic!("Can't open room file {}: {}", file_name, err.to_string()),
-        };
+        match Room::load(Path::new(&path)) {
+            Ok(room) => room,
+            Err(err) => panic!("Can't open room file {}: {}", file_name, err),
+        }
+    }
 
-        let reader = BufReader::new(file);
+    pub fn load(path: &Path) -> Result<Room, String> {
+        let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+        Room::parse(path, &contents).map_err(|err| err.to_string())
+    }
+
+    // the room id is the file name, e.g. 03.txt is room 3
+    pub fn parse(path: &Path, contents: &str) -> Result<Room, ParseError> {
+        let id = path
+            .file_stem()
+            .and_then(|file_stem| file_stem.to_str())
+            .and_then(|file_stem| file_stem.parse::<f32>().ok())
+            .ok_or(ParseError::new(format!(
+                "{} is not named after a room id",
+                path.display()
+            )))?;
 
         let mut tilemap: Vec<Vec<i32>> = Vec::new();
 
-        for line in reader.lines() {
-            let str_line = line.unwrap();
+        for (index, str_line) in contents.lines().enumerate() {
+            let line = index + 1;
             let str_numbers = str_line.split(" ");
 
             let mut row: Vec<i32> = Vec::new();
 
             for str_number in str_numbers {
-                let number: i32 = str_number.parse::<i32>().unwrap();
+                let number: i32 = str_number
+                    .parse::<i32>()
+                    .map_err(|_| ParseError::at(line, format!("{} is not a tile", str_number)))?;
+                if !is_known_tile(number) {
+                    return Err(ParseError::at(
+                        line,
+                        format!("{} is not a known wall code", number),
+                    ));
+                }
                 row.push(number);
             }
+
+            if let Some(first_row) = tilemap.first()
+                && first_row.len() != row.len()
+            {
+                return Err(ParseError::at(
+                    line,
+                    format!(
+                        "row has {} tiles, the first one has {}",
+                        row.len(),
+                        first_row.len()
+                    ),
+                ));
+            }
             tilemap.push(row);
         }
 
-        Room { id, tilemap }
+        if tilemap.is_empty() {
+            return Err(ParseError::new("it has no tiles".to_string()));
+        }
+
+        // classic mode draws every room on the same grid, only the survival arena (room 0)
+        // has its own size
+        let total_rows = tilemap.len();
+        let total_columns = tilemap[0].len();
+        if id != 0.0 && (total_columns != TOTAL_TILE_WIDTH || total_rows != TOTAL_TILE_HEIGHT) {
+            return Err(ParseError::new(format!(
+                "room is {}x{} tiles, classic rooms have to be {}x{}",
+                total_columns, total_rows, TOTAL_TILE_WIDTH, TOTAL_TILE_HEIGHT
+            )));
+        }
+
+        Ok(Room { id, tilemap })
+    }
+
+    pub fn total_rows(&self) -> usize {
+        self.tilemap.len()
+    }
+
+    pub fn total_columns(&self) -> usize {
+        self.tilemap[0].len()
     }
+
+    // Centre (row, column) of the temporary wall tiles which open towards the door
+    pub fn door_tile_position(&self, door: &Door) -> Option<(f32, f32)> {
+        let half_rows = self.total_rows() / 2;
+
+        let tiles: Vec<(usize, usize)> = self
+            .tilemap
+            .iter()
+            .enumerate()
+            .flat_map(|(row_index, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(move |(column_index, value)| (row_index, column_index, *value))
+            })
+            .filter(|(row_index, _, value)| match door {
+                Door::Left => *value == -7,
+                Door::Right => *value == -8,
+                Door::Top => (*value == -1 || *value == -2) && *row_index < half_rows,
+                Door::Bottom => (*value == -1 || *value == -2) && *row_index >= half_rows,
+            })
+            .map(|(row_index, column_index, _)| (row_index, column_index))
+            .collect();
+
+        if tiles.is_empty() {
+            return None;
+        }
+
+        let total_tiles = tiles.len() as f32;
+        let row = tiles
+            .iter()
+            .map(|(row_index, _)| *row_index as f32)
+            .sum::<f32>()
+            / total_tiles;
+        let column = tiles
+            .iter()
+            .map(|(_, column_index)| *column_index as f32)
+            .sum::<f32>()
+            / total_tiles;
+
+        Some((row, column))
+    }
+
+    // Tiles which are far enough from any wall for a monster to appear on
+    pub fn spawn_tile_positions(&self) -> Vec<(usize, usize)> {
+        let mut positions = Vec::new();
+
+        for row_index in 1..self.total_rows() - 1 {
+            for column_index in 1..self.total_columns() - 1 {
+                let is_open_area = (row_index - 1..=row_index + 1).all(|row| {
+                    (column_index - 1..=column_index + 1)
+                        .all(|column| self.tilemap[row][column] == 0)
+                });
+
+                if is_open_area {
+                    positions.push((row_index, column_index));
+                }
+            }
+        }
+
+        positions
+    }
+}
+
+// 0 is the floor, the others are the wall codes the walls and doors are built from,
+// negative ones are the temporary walls in front of a door
+pub fn is_known_tile(value: i32) -> bool {
+    matches!(value, -8 | -7 | -2..=9)
 }
@@ -1,8 +1,8 @@
 use bevy::prelude::*;
-use std::fs::File;
-use std::io::{self, BufRead};
+use std::fs;
+use std::path::Path;
 
-use crate::config::LIST_ROOM_FILE;
+use crate::config::{ASSETS_DIRECTORY, LIST_ROOM_FILE};
 use crate::resources::dungeon::room::Room;
 
 #[derive(Resource, Clone)]
@@ -11,32 +11,69 @@ pub struct Rooms {
 }
 
 impl Rooms {
+    // reads the rooms right away, the game loads them through the asset server instead
     pub fn new() -> Self {
-        let file = match File::open(LIST_ROOM_FILE) {
-            Ok(file) => file,
-            Err(err) => panic!("Can't open list room file: {}", err.to_string()),
+        let path = Path::new(ASSETS_DIRECTORY).join(LIST_ROOM_FILE);
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) => panic!("Can't open list room file: {}", err),
         };
 
-        let buffered = io::BufReader::new(file);
-        let lines: Vec<_> = buffered.lines().collect();
+        let rooms: Vec<Room> = file_names(&contents).into_iter().map(Room::new).collect();
 
-        let mut rooms: Vec<Room> = Vec::new();
+        Rooms { rooms }
+    }
+
+    pub fn from_rooms(rooms: Vec<Room>) -> Self {
+        Rooms { rooms }
+    }
 
-        for line in lines {
-            let file_name = line.unwrap();
-            rooms.push(Room::new(file_name));
+    // replaces the room with the same id, returns true when one was replaced
+    pub fn insert(&mut self, room: Room) -> bool {
+        match self.rooms.iter().position(|other| other.id == room.id) {
+            Some(index) => {
+                self.rooms[index] = room;
+                true
+            }
+            None => {
+                self.rooms.push(room);
+                false
+            }
         }
+    }
 
-        Rooms { rooms }
+    pub fn has_room(&self, room_id: f32) -> bool {
+        self.rooms.iter().any(|room| room.id == room_id.floor())
+    }
+
+    pub fn find_room(&self, room_id: f32) -> Option<&Room> {
+        self.rooms.iter().find(|room| room.id == room_id.floor())
+    }
+
+    // every room but the survival arena (room 0) can be picked for classic floors
+    pub fn classic_room_ids(&self) -> Vec<f32> {
+        self.rooms
+            .iter()
+            .map(|room| room.id)
+            .filter(|room_id| *room_id != 0.0)
+            .collect()
     }
 
     pub fn get_room(&self, room_id: f32) -> Room {
         let result = self
-            .rooms
-            .iter()
-            .find(|room| room.id == room_id.floor())
+            .find_room(room_id)
             .expect(format!("Can't find room: {}", room_id).as_str());
 
         result.clone()
     }
 }
+
+// one room file name per line of the list room file
+pub fn file_names(contents: &str) -> Vec<String> {
+    contents
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.to_string())
+        .collect()
+}
@@ -20,6 +20,16 @@ impl Wave {
         }
     }
 
+    pub fn resume(wave_number: usize, wave_duration: i64) -> Self {
+        let timer = Timer::new(Duration::from_secs(wave_duration as u64), TimerMode::Once);
+
+        Wave {
+            wave_number,
+            wave_duration,
+            timer,
+        }
+    }
+
     pub fn next_wave(&mut self) {
         self.wave_number += 1;
         self.wave_duration += 15;
@@ -12,3 +12,21 @@ pub enum EffectType {
     Confuse,
     Stun,
 }
+
+impl EffectType {
+    // buffs land on the player, everything else is a debuff for whoever gets hit
+    pub fn is_buff(&self) -> bool {
+        matches!(
+            self,
+            EffectType::SpeedUp | EffectType::EvasionUp | EffectType::Focus
+        )
+    }
+
+    // the debuffs monsters know how to take, the rest only make sense on the player
+    pub fn is_monster_debuff(&self) -> bool {
+        matches!(
+            self,
+            EffectType::Slow | EffectType::ReduceDamage | EffectType::Stun
+        )
+    }
+}
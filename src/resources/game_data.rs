@@ -1,15 +1,22 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::fs::File;
-use std::io::prelude::*;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
 
-use crate::config::DATA_FILE;
+use crate::config::{ASSETS_DIRECTORY, DATA_FILE};
 use crate::resources::effect::Effect;
+use crate::resources::effect::effect_type::EffectType;
 use crate::resources::hero::Hero;
 use crate::resources::hero::hero_class::HeroClass;
 use crate::resources::monster::Monster;
+use crate::resources::monster::monster_class::MonsterClass;
+use crate::resources::monster::monster_skill::MonsterSkill;
 use crate::resources::skill::Skill;
+use crate::resources::skill::skill_type::SkillType;
 use crate::resources::weapon::Weapon;
+use crate::resources::weapon::attack_type::AttackType;
 use crate::resources::weapon::weapon_type::WeaponType;
 
 #[derive(Resource)]
@@ -17,25 +24,91 @@ pub struct PauseSceneData {
     pub(crate) user_interface_root: Entity,
 }
 
-#[derive(Resource, Serialize, Deserialize, Debug, Clone)]
+// an entry of the data by its position in the collection, e.g. the third weapon
+#[derive(Debug, Clone, Copy)]
+pub enum DataEntry {
+    Hero(usize),
+    Weapon(usize),
+    Skill(usize),
+    Effect(usize),
+    Monster(usize),
+}
+
+impl DataEntry {
+    // the collection the entry is in, as it is named in data.json
+    pub fn collection(&self) -> &'static str {
+        match self {
+            DataEntry::Hero(_) => "heroes",
+            DataEntry::Weapon(_) => "weapons",
+            DataEntry::Skill(_) => "skills",
+            DataEntry::Effect(_) => "player_list_effects_information",
+            DataEntry::Monster(_) => "monsters",
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        match self {
+            DataEntry::Hero(index)
+            | DataEntry::Weapon(index)
+            | DataEntry::Skill(index)
+            | DataEntry::Effect(index)
+            | DataEntry::Monster(index) => *index,
+        }
+    }
+}
+
+// `entry` is missing when the problem is about the data as a whole
+#[derive(Debug, Clone)]
+pub struct DataError {
+    pub entry: Option<DataEntry>,
+    pub message: String,
+}
+
+impl DataError {
+    fn new(entry: Option<DataEntry>, message: String) -> Self {
+        DataError { entry, message }
+    }
+}
+
+impl fmt::Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+#[derive(Resource, Asset, TypePath, Serialize, Deserialize, Debug, Clone)]
 pub struct GameData {
-    heroes: [Hero; 4],
-    weapons: [Weapon; 11],
-    skills: [Skill; 4],
-    player_list_effects_information: [Effect; 8],
-    monsters: [Monster; 10],
+    heroes: Vec<Hero>,
+    weapons: Vec<Weapon>,
+    skills: Vec<Skill>,
+    player_list_effects_information: Vec<Effect>,
+    monsters: Vec<Monster>,
+}
+
+// the part of a data.json a mod pack provides, missing collections are left untouched
+#[derive(Asset, TypePath, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct GameDataFragment {
+    heroes: Vec<Hero>,
+    weapons: Vec<Weapon>,
+    skills: Vec<Skill>,
+    player_list_effects_information: Vec<Effect>,
+    monsters: Vec<Monster>,
 }
 
 impl GameData {
+    // reads the data right away, the game loads it through the asset server instead
     pub fn new() -> Self {
-        let data = match File::open(DATA_FILE) {
-            Ok(mut file) => {
-                let mut contents = String::new();
-                file.read_to_string(&mut contents).unwrap();
-                serde_json::from_str(&contents).expect("JSON was not well-formatted")
-            }
-            Err(err) => panic!("Can't find language file: {}", err),
+        let path = Path::new(ASSETS_DIRECTORY).join(DATA_FILE);
+        let data: GameData = match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).expect("JSON was not well-formatted"),
+            Err(err) => panic!("Can't open data file: {}", err),
         };
+
+        if let Err(errors) = data.validate() {
+            panic!("{} is invalid:\n{}", DATA_FILE, join_errors(&errors));
+        }
+
         data
     }
 
@@ -56,50 +129,413 @@ impl GameData {
             .clone()
     }
 
+    // the first weapon of the hero progression is the starting one
     pub fn get_weapon(&self, hero_class: HeroClass) -> Weapon {
+        self.get_weapons(hero_class)
+            .first()
+            .expect("Hero has no weapons")
+            .clone()
+    }
+
+    // weapons of a hero in the order they are upgraded to
+    pub fn get_weapons(&self, hero_class: HeroClass) -> Vec<Weapon> {
         let hero = self.get_hero(hero_class);
-        *self
-            .weapons
+        hero.weapons
             .iter()
-            .find(|weapon| weapon.name == hero.weapon)
-            .unwrap()
+            .filter_map(|weapon_type| self.find_weapon(weapon_type))
+            .collect()
     }
 
-    pub fn get_weapons(&self, hero_class: HeroClass) -> Vec<Weapon> {
-        let weapons_type: Vec<WeaponType> = match hero_class {
-            HeroClass::Elf => [WeaponType::Bow, WeaponType::Spear].to_vec(),
-            HeroClass::Knight => [
-                WeaponType::ShortSword,
-                WeaponType::Sword,
-                WeaponType::BigMachete,
-            ]
-            .to_vec(),
-            HeroClass::Wizard => [
-                WeaponType::SmallWand,
-                WeaponType::MagicWand,
-                WeaponType::MagicSword,
-            ]
-            .to_vec(),
-            HeroClass::Lizard => [
-                WeaponType::SmallHammer,
-                WeaponType::Mace,
-                WeaponType::BigHammer,
-            ]
-            .to_vec(),
-        };
+    pub fn get_next_weapon(&self, hero_class: HeroClass, level: u8) -> Option<Weapon> {
+        self.get_weapons(hero_class)
+            .into_iter()
+            .find(|weapon| weapon.level == level + 1)
+    }
 
+    fn find_weapon(&self, weapon_type: &WeaponType) -> Option<Weapon> {
         self.weapons
             .iter()
-            .filter(|weapon| weapons_type.contains(&weapon.name))
+            .find(|weapon| weapon.name == *weapon_type)
             .cloned()
-            .collect()
+    }
+
+    // checks every cross reference so a broken data file fails at load with a readable list
+    pub fn validate(&self) -> Result<(), Vec<DataError>> {
+        let mut errors: Vec<DataError> = Vec::new();
+
+        if self.heroes.is_empty() {
+            errors.push(DataError::new(None, "there is no hero".to_string()));
+        }
+
+        for (index, hero) in self.heroes.iter().enumerate() {
+            let entry = Some(DataEntry::Hero(index));
+
+            if self.heroes[..index]
+                .iter()
+                .any(|other| other.hero_class == hero.hero_class)
+            {
+                errors.push(DataError::new(
+                    entry,
+                    format!("hero {} is declared twice", hero.hero_class),
+                ));
+            }
+
+            if !self.skills.iter().any(|skill| skill.name == hero.skill) {
+                errors.push(DataError::new(
+                    entry,
+                    format!(
+                        "hero {} uses skill {:?} which is not in skills",
+                        hero.hero_class, hero.skill
+                    ),
+                ));
+            }
+
+            if hero.weapons.is_empty() {
+                errors.push(DataError::new(
+                    entry,
+                    format!("hero {} has no weapons", hero.hero_class),
+                ));
+            }
+
+            for (level, weapon_type) in hero.weapons.iter().enumerate() {
+                let expected_level = level as u8 + 1;
+                let message = match self.find_weapon(weapon_type) {
+                    None => format!(
+                        "hero {} uses weapon {} which is not in weapons",
+                        hero.hero_class, weapon_type
+                    ),
+                    Some(weapon) if weapon.level != expected_level => format!(
+                        "hero {} weapon {} has level {} but comes at level {} of the progression",
+                        hero.hero_class, weapon_type, weapon.level, expected_level
+                    ),
+                    _ => continue,
+                };
+                errors.push(DataError::new(entry, message));
+            }
+        }
+
+        for (index, weapon) in self.weapons.iter().enumerate() {
+            let entry = Some(DataEntry::Weapon(index));
+
+            if self.weapons[..index]
+                .iter()
+                .any(|other| other.name == weapon.name)
+            {
+                errors.push(DataError::new(
+                    entry,
+                    format!("weapon {} is declared twice", weapon.name),
+                ));
+            }
+
+            match weapon.attack_type {
+                AttackType::Shoot => {
+                    if weapon.bullet.is_none() || weapon.cooldown.is_none() {
+                        errors.push(DataError::new(
+                            entry,
+                            format!(
+                                "weapon {} shoots but has no bullet or cooldown",
+                                weapon.name
+                            ),
+                        ));
+                    }
+                }
+                AttackType::Swing => {
+                    if weapon.swing_speed.is_none() {
+                        errors.push(DataError::new(
+                            entry,
+                            format!("weapon {} swings but has no swing_speed", weapon.name),
+                        ));
+                    }
+                }
+            }
+
+            if let Some(color) = weapon
+                .bullet
+                .as_ref()
+                .and_then(|bullet| bullet.color.as_ref())
+                && Srgba::hex(color).is_err()
+            {
+                errors.push(DataError::new(
+                    entry,
+                    format!(
+                        "weapon {} has the bullet color {} which is not a hex color",
+                        weapon.name, color
+                    ),
+                ));
+            }
+
+            if let Some(effect) = weapon.effect {
+                if !effect.is_buff() && !effect.is_monster_debuff() {
+                    errors.push(DataError::new(
+                        entry,
+                        format!(
+                            "weapon {} debuffs with {:?} which monsters can't take",
+                            weapon.name, effect
+                        ),
+                    ));
+                }
+
+                if !self.has_effect_information(effect) {
+                    errors.push(DataError::new(
+                        entry,
+                        format!(
+                            "weapon {} triggers {:?} which is not in player_list_effects_information",
+                            weapon.name, effect
+                        ),
+                    ));
+                }
+            }
+        }
+
+        for (index, skill) in self.skills.iter().enumerate() {
+            if self.skills[..index]
+                .iter()
+                .any(|other| other.name == skill.name)
+            {
+                errors.push(DataError::new(
+                    Some(DataEntry::Skill(index)),
+                    format!("skill {:?} is declared twice", skill.name),
+                ));
+            }
+
+            // thunderstorm stuns every monster it hits
+            if skill.name == SkillType::Thunderstorm
+                && !self.has_effect_information(EffectType::Stun)
+            {
+                errors.push(DataError::new(
+                    Some(DataEntry::Skill(index)),
+                    format!(
+                        "skill {:?} stuns but Stun is not in player_list_effects_information",
+                        skill.name
+                    ),
+                ));
+            }
+        }
+
+        for (index, effect) in self.player_list_effects_information.iter().enumerate() {
+            if self.player_list_effects_information[..index]
+                .iter()
+                .any(|other| other.name == effect.name)
+            {
+                errors.push(DataError::new(
+                    Some(DataEntry::Effect(index)),
+                    format!("effect {:?} is declared twice", effect.name),
+                ));
+            }
+
+            if effect.duration < 0 {
+                errors.push(DataError::new(
+                    Some(DataEntry::Effect(index)),
+                    format!(
+                        "effect {:?} has a negative duration of {}",
+                        effect.name, effect.duration
+                    ),
+                ));
+            }
+        }
+
+        // the first floors and waves only spawn level 1 monsters
+        if !self.monsters.iter().any(|monster| monster.level == 1) {
+            errors.push(DataError::new(
+                None,
+                "there is no level 1 monster".to_string(),
+            ));
+        }
+
+        for (index, monster) in self.monsters.iter().enumerate() {
+            let entry = Some(DataEntry::Monster(index));
+
+            if self.monsters[..index]
+                .iter()
+                .any(|other| other.class == monster.class)
+            {
+                errors.push(DataError::new(
+                    entry,
+                    format!("monster {} is declared twice", monster.class),
+                ));
+            }
+
+            if monster.frames == 0 {
+                errors.push(DataError::new(
+                    entry,
+                    format!("monster {} has a sprite without frames", monster.class),
+                ));
+            }
+
+            if let Some(effect) = monster.trigger_effect
+                && !self.has_effect_information(effect)
+            {
+                errors.push(DataError::new(
+                    entry,
+                    format!(
+                        "monster {} triggers {:?} which is not in player_list_effects_information",
+                        monster.class, effect
+                    ),
+                ));
+            }
+
+            let Some(skill) = monster.skill.as_ref() else {
+                continue;
+            };
+
+            // a skill can telegraph right away, every other number has to be above 0
+            for (name, value) in skill.values() {
+                let is_valid = match name {
+                    "telegraph" => value >= 0.0,
+                    _ => value > 0.0,
+                };
+                if !is_valid || !value.is_finite() {
+                    errors.push(DataError::new(
+                        entry,
+                        format!(
+                            "monster {} has a skill {} of {}",
+                            monster.class, name, value
+                        ),
+                    ));
+                }
+            }
+
+            if let Some(summoned_class) = skill.monster_class()
+                && !self
+                    .monsters
+                    .iter()
+                    .any(|other| other.class == *summoned_class)
+            {
+                errors.push(DataError::new(
+                    entry,
+                    format!(
+                        "monster {} summons {} which is not in monsters",
+                        monster.class, summoned_class
+                    ),
+                ));
+            }
+
+            // a monster that splits back into itself, even through other monsters, would
+            // keep splitting forever
+            let mut split_classes = vec![monster.class.clone()];
+            let mut split_skill = Some(skill);
+            while let Some(MonsterSkill::Split { monster_class, .. }) = split_skill {
+                if split_classes.contains(monster_class) {
+                    errors.push(DataError::new(
+                        entry,
+                        format!(
+                            "monster {} never stops splitting, it splits back into {}",
+                            monster.class, monster_class
+                        ),
+                    ));
+                    break;
+                }
+
+                split_classes.push(monster_class.clone());
+                split_skill = self
+                    .monsters
+                    .iter()
+                    .find(|other| other.class == *monster_class)
+                    .and_then(|other| other.skill.as_ref());
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    // entries with the same key replace the existing one, the others are appended,
+    // returns the keys of every entry the fragment touched
+    pub fn merge(&mut self, fragment: GameDataFragment) -> Vec<String> {
+        let mut keys = Vec::new();
+        keys.extend(merge_entries(&mut self.heroes, fragment.heroes, |hero| {
+            format!("hero {}", hero.hero_class)
+        }));
+        keys.extend(merge_entries(
+            &mut self.weapons,
+            fragment.weapons,
+            |weapon| format!("weapon {}", weapon.name),
+        ));
+        keys.extend(merge_entries(&mut self.skills, fragment.skills, |skill| {
+            format!("skill {:?}", skill.name)
+        }));
+        keys.extend(merge_entries(
+            &mut self.player_list_effects_information,
+            fragment.player_list_effects_information,
+            |effect| format!("effect {:?}", effect.name),
+        ));
+        keys.extend(merge_entries(
+            &mut self.monsters,
+            fragment.monsters,
+            |monster| format!("monster {}", monster.class),
+        ));
+        keys
+    }
+
+    pub fn has_skill(&self, skill_type: SkillType) -> bool {
+        self.skills.iter().any(|skill| skill.name == skill_type)
+    }
+
+    pub fn has_effect_information(&self, effect_type: EffectType) -> bool {
+        self.player_list_effects_information
+            .iter()
+            .any(|effect| effect.name == effect_type)
+    }
+
+    // looked up when an effect lands, so every monster reads the same table
+    pub fn get_effect_duration(&self, effect_type: EffectType) -> Option<Duration> {
+        self.player_list_effects_information
+            .iter()
+            .find(|effect| effect.name == effect_type)
+            .map(|effect| Duration::from_secs(effect.duration as u64))
     }
 
     pub fn get_player_list_effects_information(&self) -> Vec<Effect> {
         self.player_list_effects_information.to_vec()
     }
 
+    pub fn get_heroes(&self) -> Vec<Hero> {
+        self.heroes.to_vec()
+    }
+
+    pub fn get_all_weapons(&self) -> Vec<Weapon> {
+        self.weapons.to_vec()
+    }
+
     pub fn get_monsters(&self) -> Vec<Monster> {
         self.monsters.to_vec()
     }
+
+    pub fn get_monster(&self, monster_class: MonsterClass) -> Monster {
+        self.monsters
+            .iter()
+            .find(|monster| monster.class == monster_class)
+            .unwrap()
+            .clone()
+    }
+}
+
+fn merge_entries<T>(
+    entries: &mut Vec<T>,
+    additions: Vec<T>,
+    key: impl Fn(&T) -> String,
+) -> Vec<String> {
+    additions
+        .into_iter()
+        .map(|addition| {
+            let addition_key = key(&addition);
+            match entries.iter().position(|entry| key(entry) == addition_key) {
+                Some(index) => entries[index] = addition,
+                None => entries.push(addition),
+            }
+            addition_key
+        })
+        .collect()
+}
+
+// one error per line, for the messages shown when the data can't be used
+pub fn join_errors(errors: &[DataError]) -> String {
+    errors
+        .iter()
+        .map(|error| error.to_string())
+        .collect::<Vec<String>>()
+        .join("\n")
 }
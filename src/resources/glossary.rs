@@ -1,11 +1,12 @@
+use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::fs::File;
-use std::io::prelude::*;
+use std::fs;
+use std::path::Path;
 
 use crate::config::*;
 use crate::resources::language::Language;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Asset, TypePath, Serialize, Deserialize, Debug, Clone)]
 pub struct Glossary {
     pub highscore_scene_text: HighscoreSceneText,
     pub main_menu_scene_text: MainMenuSceneText,
@@ -25,6 +26,7 @@ pub struct MainMenuSceneText {
     pub help: String,
     pub credits: String,
     pub quit: String,
+    pub continue_survival: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -50,6 +52,11 @@ pub struct OptionsSceneText {
     pub enable_music: String,
     pub enable_sound: String,
     pub language: String,
+    pub sound_volume: String,
+    pub music_volume: String,
+    pub mods: String,
+    pub no_mods: String,
+    pub mod_problems: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -74,6 +81,8 @@ pub struct SharedText {
     pub select_hero: String,
     pub continue_: String,
     pub quit: String,
+    pub seed: String,
+    pub save_and_quit: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -104,17 +113,18 @@ pub struct InGameText {
     pub dodge_chance: String,
     pub restore_chance: String,
     pub damage_percent_bonus: String,
+    pub miss: String,
+    pub stunned: String,
+    pub disarmed: String,
+    pub confused: String,
 }
 
 impl Glossary {
+    // reads the glossary right away, the game loads it through the asset server instead
     pub fn new(language: Language) -> Self {
-        let file_name = match language {
-            Language::VI => VIETNAMESE_LANGUAGE_FILE,
-            Language::EN => ENGLISH_LANGUAGE_FILE,
-        };
-
-        match File::open(file_name) {
-            Ok(mut file) => {
+        let path = Path::new(ASSETS_DIRECTORY).join(Glossary::file_name(language));
+        match fs::read_to_string(path) {
+            Ok(contents) => {
                 let error_message = format!(
                     "{}: JSON was not well-formatted",
                     if language == Language::VI {
@@ -124,8 +134,6 @@ impl Glossary {
                     }
                 );
 
-                let mut contents = String::new();
-                file.read_to_string(&mut contents).unwrap();
                 // let glossary = serde_json::from_str(&contents).unwrap_or(|_| { panic!("{}", error_message) });
                 match serde_json::from_str::<Glossary>(&contents) {
                     Ok(glossary) => glossary,
@@ -135,4 +143,11 @@ impl Glossary {
             Err(err) => panic!("Can't find language file: {}", err),
         }
     }
+
+    pub fn file_name(language: Language) -> &'static str {
+        match language {
+            Language::VI => VIETNAMESE_LANGUAGE_FILE,
+            Language::EN => ENGLISH_LANGUAGE_FILE,
+        }
+    }
 }
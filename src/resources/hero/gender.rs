@@ -2,7 +2,7 @@ use bevy_inspector_egui::InspectorOptions;
 use serde::{Deserialize, Serialize};
 use std::slice::Iter;
 
-#[derive(Serialize, Deserialize, Debug, Clone, InspectorOptions)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, InspectorOptions)]
 pub enum Gender {
     Male,
     Female,
@@ -1,23 +1,23 @@
-use bevy_inspector_egui::InspectorOptions;
 use serde::{Deserialize, Serialize};
-use std::slice::Iter;
+use std::fmt;
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, InspectorOptions)]
-pub enum HeroClass {
-    Elf,
-    Knight,
-    Wizard,
-    Lizard,
-}
+// the id of a hero in data.json, a new hero only needs a new entry there
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct HeroClass(String);
 
 impl HeroClass {
-    pub fn iterator() -> Iter<'static, HeroClass> {
-        [
-            HeroClass::Elf,
-            HeroClass::Knight,
-            HeroClass::Wizard,
-            HeroClass::Lizard,
-        ]
-        .iter()
+    pub fn new(id: &str) -> Self {
+        HeroClass(id.to_string())
+    }
+
+    pub fn id(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for HeroClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
     }
 }
@@ -8,6 +8,7 @@ pub mod hero_class;
 pub mod power;
 pub mod stats;
 
+use gender::Gender;
 use hero_class::HeroClass;
 use power::Power;
 use stats::Stats;
@@ -17,6 +18,25 @@ pub struct Hero {
     pub hero_class: HeroClass,
     pub stats: Stats,
     pub power: Power,
-    pub weapon: WeaponType,
+    // weapon progression, one weapon per level starting from level 1
+    pub weapons: Vec<WeaponType>,
     pub skill: SkillType,
+    // tilesets used in game and in the hero select scene, portraits for the highscores
+    pub sprites: GenderSprites,
+    pub portraits: GenderSprites,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GenderSprites {
+    pub male: String,
+    pub female: String,
+}
+
+impl GenderSprites {
+    pub fn get(&self, gender: &Gender) -> &str {
+        match gender {
+            Gender::Male => &self.male,
+            Gender::Female => &self.female,
+        }
+    }
 }
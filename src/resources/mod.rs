@@ -7,12 +7,17 @@ pub mod game_mode;
 pub mod glossary;
 pub mod hero;
 pub mod language;
+pub mod mod_packs;
 pub mod monster;
 pub mod player;
 pub mod potion;
 pub mod profile;
+pub mod run_rng;
+pub mod saved_run;
+pub mod saved_survival_run;
 pub mod setting;
 pub mod skill;
+pub mod spatial_hash;
 pub mod stored_profile;
 pub mod tile_size;
 pub mod upgrade;
@@ -0,0 +1,613 @@
+use bevy::prelude::*;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::{DATA_FILE, MODS_DIRECTORY, UPGRADES_FILE};
+use crate::resources::dungeon::door::Door;
+use crate::resources::dungeon::floor_generator::{END_ROOM_MARK, START_ROOM_MARK};
+use crate::resources::dungeon::parse_error::ParseError;
+use crate::resources::dungeon::room::Room;
+use crate::resources::dungeon::rooms::Rooms;
+use crate::resources::game_data::{GameData, GameDataFragment};
+use crate::resources::upgrade::Upgrade;
+use crate::resources::upgrade::upgrade_controller::{UpgradeController, UpgradeList};
+
+// registered in main, sprites of a pack are loaded from mods://<pack>/sprites/...
+pub const MODS_ASSET_SOURCE: &str = "mods";
+
+pub const ROOMS_DIRECTORY: &str = "rooms";
+pub const FLOORS_DIRECTORY: &str = "floors";
+const SPRITES_DIRECTORY: &str = "sprites";
+
+#[derive(Clone)]
+pub struct ModPack {
+    pub name: String,
+    path: PathBuf,
+}
+
+// content packs found in the mods directory, applied in alphabetical order so the
+// last pack wins when two of them change the same entry
+#[derive(Resource, Default, Clone)]
+pub struct ModPacks {
+    packs: Vec<ModPack>,
+    sprites: HashMap<String, String>,
+    problems: Vec<PackProblem>,
+}
+
+// something a pack got wrong, `file` is relative to the pack and `entry` is the upgrade
+// or floor row the problem is about, the validator turns them into a line of the file
+#[derive(Clone, Debug)]
+pub struct PackProblem {
+    pub pack: String,
+    pub file: String,
+    pub entry: Option<usize>,
+    pub message: String,
+}
+
+// hand made floors from the packs, keyed by floor number starting from 1
+#[derive(Resource, Default, Clone)]
+pub struct ModFloors {
+    floors: HashMap<usize, Vec<Vec<f32>>>,
+}
+
+// the data files of one pack once they are read, in pack order, a file that could not
+// be read keeps its error so it is reported like any other problem
+#[derive(Default)]
+pub struct PackContent {
+    pub game_data: Option<Result<GameDataFragment, String>>,
+    pub upgrades: Option<Result<Vec<Upgrade>, String>>,
+    pub rooms: Vec<(String, Result<Room, String>)>,
+    pub floors: Vec<(String, Result<FloorMap, String>)>,
+}
+
+// a hand made floor, floors/03.txt is the third floor
+#[derive(Asset, TypePath, Clone)]
+pub struct FloorMap {
+    pub number: usize,
+    pub map: Vec<Vec<f32>>,
+}
+
+impl ModFloors {
+    pub fn get(&self, floor_number: usize) -> Option<&Vec<Vec<f32>>> {
+        self.floors.get(&floor_number)
+    }
+
+    pub fn last_floor_number(&self) -> usize {
+        self.floors.keys().copied().max().unwrap_or(0)
+    }
+}
+
+impl ModPack {
+    pub fn has_file(&self, file: &str) -> bool {
+        self.path.join(file).exists()
+    }
+
+    // pack relative paths of the text files in one of the pack directories
+    pub fn files(&self, directory: &str) -> Vec<String> {
+        files(&self.path.join(directory), "txt")
+            .iter()
+            .filter_map(|path| path.file_name())
+            .map(|file_name| format!("{}/{}", directory, file_name.to_string_lossy()))
+            .collect()
+    }
+
+    pub fn asset_path(&self, file: &str) -> String {
+        format!("{}://{}/{}", MODS_ASSET_SOURCE, self.name, file)
+    }
+}
+
+impl PackProblem {
+    fn new(pack: &ModPack, file: &str, message: String) -> Self {
+        PackProblem {
+            pack: pack.name.clone(),
+            file: file.to_string(),
+            entry: None,
+            message,
+        }
+    }
+
+    fn at_entry(mut self, entry: usize) -> Self {
+        self.entry = Some(entry);
+        self
+    }
+}
+
+impl fmt::Display for PackProblem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}: {}", self.pack, self.file, self.message)
+    }
+}
+
+impl ModPacks {
+    pub fn new() -> Self {
+        let mut mod_packs = ModPacks::default();
+
+        // no mods directory simply means no packs
+        let Ok(entries) = fs::read_dir(MODS_DIRECTORY) else {
+            return mod_packs;
+        };
+
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let name = path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string();
+            mod_packs.packs.push(ModPack { name, path });
+        }
+
+        mod_packs.index_sprites();
+        mod_packs
+    }
+
+    pub fn packs(&self) -> &[ModPack] {
+        &self.packs
+    }
+
+    pub fn problems(&self) -> &[PackProblem] {
+        &self.problems
+    }
+
+    // path to load an asset from, a pack sprite with the same relative path replaces it
+    pub fn asset_path(&self, path: &str) -> String {
+        self.sprites
+            .get(path)
+            .cloned()
+            .unwrap_or_else(|| path.to_string())
+    }
+
+    pub fn load<A: Asset>(&self, asset_server: &AssetServer, path: &str) -> Handle<A> {
+        asset_server.load(self.asset_path(path))
+    }
+
+    // reads every pack right away, the game loads them through the asset server instead
+    pub fn read_content(&self) -> Vec<PackContent> {
+        self.packs
+            .iter()
+            .map(|pack| PackContent {
+                game_data: pack
+                    .has_file(DATA_FILE)
+                    .then(|| read_json(&pack.path.join(DATA_FILE))),
+                upgrades: pack.has_file(UPGRADES_FILE).then(|| {
+                    read_json::<UpgradeList>(&pack.path.join(UPGRADES_FILE))
+                        .map(|upgrade_list| upgrade_list.upgrades)
+                }),
+                rooms: pack
+                    .files(ROOMS_DIRECTORY)
+                    .into_iter()
+                    .map(|file| {
+                        let room = Room::load(&pack.path.join(&file));
+                        (file, room)
+                    })
+                    .collect(),
+                floors: pack
+                    .files(FLOORS_DIRECTORY)
+                    .into_iter()
+                    .map(|file| {
+                        let floor = FloorMap::load(&pack.path.join(&file));
+                        (file, floor)
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    // a pack whose data would leave the game data invalid is skipped as a whole
+    pub fn apply_game_data(&mut self, game_data: &mut GameData, contents: &[PackContent]) {
+        let mut owners: HashMap<String, String> = HashMap::new();
+
+        for (pack, content) in self.packs.iter().zip(contents) {
+            let fragment = match &content.game_data {
+                Some(Ok(fragment)) => fragment.clone(),
+                Some(Err(err)) => {
+                    self.problems.push(PackProblem::new(
+                        pack,
+                        DATA_FILE,
+                        format!("is invalid: {}", err),
+                    ));
+                    continue;
+                }
+                None => continue,
+            };
+
+            let mut merged = game_data.clone();
+            let keys = merged.merge(fragment);
+
+            if let Err(errors) = merged.validate() {
+                for error in errors {
+                    self.problems.push(PackProblem::new(
+                        pack,
+                        DATA_FILE,
+                        format!("skipped, {}", error),
+                    ));
+                }
+                continue;
+            }
+
+            for key in keys {
+                if let Some(owner) = owners.insert(key.clone(), pack.name.clone()) {
+                    self.problems.push(PackProblem::new(
+                        pack,
+                        DATA_FILE,
+                        format!("{} overrides the one from {}", key, owner),
+                    ));
+                }
+            }
+
+            *game_data = merged;
+        }
+    }
+
+    // upgrades have no key, they are appended and exact duplicates are dropped
+    pub fn apply_upgrades(
+        &mut self,
+        upgrade_controller: &mut UpgradeController,
+        game_data: &GameData,
+        contents: &[PackContent],
+    ) {
+        for (pack, content) in self.packs.iter().zip(contents) {
+            let upgrades = match &content.upgrades {
+                Some(Ok(upgrades)) => upgrades,
+                Some(Err(err)) => {
+                    self.problems.push(PackProblem::new(
+                        pack,
+                        UPGRADES_FILE,
+                        format!("is invalid: {}", err),
+                    ));
+                    continue;
+                }
+                None => continue,
+            };
+
+            for (index, upgrade) in upgrades.iter().enumerate() {
+                let message = if let Err(err) = upgrade.validate(game_data) {
+                    format!("upgrade {} skipped, {}", index, err)
+                } else if upgrade_controller.upgrades.contains(upgrade) {
+                    format!("upgrade {} skipped, it already exists", index)
+                } else {
+                    upgrade_controller.upgrades.push(upgrade.clone());
+                    continue;
+                };
+
+                self.problems
+                    .push(PackProblem::new(pack, UPGRADES_FILE, message).at_entry(index));
+            }
+        }
+    }
+
+    // rooms are named after their id, the same id replaces a room, a new one adds it
+    pub fn apply_rooms(&mut self, rooms: &mut Rooms, contents: &[PackContent]) {
+        let mut owners: HashMap<String, String> = HashMap::new();
+
+        for (pack, content) in self.packs.iter().zip(contents) {
+            for (file, room) in content.rooms.iter() {
+                let room = match room {
+                    Ok(room) => room.clone(),
+                    Err(err) => {
+                        self.problems.push(PackProblem::new(
+                            pack,
+                            file,
+                            format!("skipped, {}", err),
+                        ));
+                        continue;
+                    }
+                };
+
+                let key = format!("room {}", room.id);
+                if let Some(owner) = owners.insert(key.clone(), pack.name.clone()) {
+                    self.problems.push(PackProblem::new(
+                        pack,
+                        file,
+                        format!("{} overrides the one from {}", key, owner),
+                    ));
+                }
+
+                rooms.insert(room);
+            }
+        }
+    }
+
+    // floors/03.txt replaces the third generated floor, numbers past the last one add floors
+    pub fn load_floors(&mut self, rooms: &Rooms, contents: &[PackContent]) -> ModFloors {
+        let mut mod_floors = ModFloors::default();
+        let mut owners: HashMap<usize, String> = HashMap::new();
+
+        for (pack, content) in self.packs.iter().zip(contents) {
+            for (file, floor) in content.floors.iter() {
+                let floor = match floor {
+                    Ok(floor) => floor,
+                    Err(err) => {
+                        self.problems.push(PackProblem::new(
+                            pack,
+                            file,
+                            format!("skipped, {}", err),
+                        ));
+                        continue;
+                    }
+                };
+
+                let missing_room = floor.map.iter().enumerate().find_map(|(row_index, row)| {
+                    row.iter()
+                        .find(|number| **number != 0.0 && !rooms.has_room(**number))
+                        .map(|number| (row_index, number.floor()))
+                });
+                if let Some((row_index, missing_room)) = missing_room {
+                    self.problems.push(
+                        PackProblem::new(
+                            pack,
+                            file,
+                            format!("skipped, room {} does not exist", missing_room),
+                        )
+                        .at_entry(row_index),
+                    );
+                    continue;
+                }
+
+                if !floor.is_connected(rooms) {
+                    self.problems.push(
+                        PackProblem::new(
+                            pack,
+                            file,
+                            "skipped, the end room can't be reached from the start room"
+                                .to_string(),
+                        )
+                        .at_entry(floor.end_room().0),
+                    );
+                    continue;
+                }
+
+                if let Some(owner) = owners.insert(floor.number, pack.name.clone()) {
+                    self.problems.push(PackProblem::new(
+                        pack,
+                        file,
+                        format!("floor {} overrides the one from {}", floor.number, owner),
+                    ));
+                }
+                mod_floors.floors.insert(floor.number, floor.map.clone());
+            }
+        }
+
+        mod_floors
+    }
+
+    pub fn report(&self) {
+        for pack in self.packs.iter() {
+            info!("Loaded mod pack {}", pack.name);
+        }
+
+        for problem in self.problems.iter() {
+            warn!("Mod pack problem: {}", problem);
+        }
+    }
+
+    fn index_sprites(&mut self) {
+        for pack in self.packs.iter() {
+            let sprites_path = pack.path.join(SPRITES_DIRECTORY);
+            for path in files_recursive(&sprites_path) {
+                let Ok(relative_path) = path.strip_prefix(&sprites_path) else {
+                    continue;
+                };
+
+                let asset_path = relative_path
+                    .components()
+                    .map(|component| component.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+
+                let mod_path = pack.asset_path(&format!("{}/{}", SPRITES_DIRECTORY, asset_path));
+
+                if self.sprites.insert(asset_path.clone(), mod_path).is_some() {
+                    self.problems.push(PackProblem::new(
+                        pack,
+                        &format!("{}/{}", SPRITES_DIRECTORY, asset_path),
+                        "overrides one from an earlier pack".to_string(),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T, String> {
+    let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    serde_json::from_str(&contents).map_err(|err| err.to_string())
+}
+
+pub fn files(directory: &Path, extension: &str) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return Vec::new();
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|other| other == extension))
+        .collect();
+    paths.sort();
+    paths
+}
+
+fn files_recursive(directory: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return Vec::new();
+    };
+
+    let mut paths = Vec::new();
+    for path in entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+    {
+        if path.is_dir() {
+            paths.extend(files_recursive(&path));
+        } else {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    paths
+}
+
+impl FloorMap {
+    pub fn load(path: &Path) -> Result<FloorMap, String> {
+        let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+        FloorMap::parse(path, &contents).map_err(|err| err.to_string())
+    }
+
+    // same layout the floor generator produces, x.1 marks the start room and x.2 the end
+    // room, whether the rooms exist is only known once the packs rooms are applied
+    pub fn parse(path: &Path, contents: &str) -> Result<FloorMap, ParseError> {
+        let number = path
+            .file_stem()
+            .and_then(|file_stem| file_stem.to_str())
+            .and_then(|file_stem| file_stem.parse::<usize>().ok())
+            .filter(|floor_number| *floor_number > 0)
+            .ok_or(ParseError::new(
+                "it is not named after a floor number".to_string(),
+            ))?;
+
+        let mut map: Vec<Vec<f32>> = Vec::new();
+        let mut start_room_lines = Vec::new();
+        let mut end_room_lines = Vec::new();
+
+        for (line, str_row) in floor_rows(contents) {
+            let row = str_row
+                .split_whitespace()
+                .map(|number| {
+                    number
+                        .parse::<f32>()
+                        .map_err(|_| ParseError::at(line, format!("{} is not a room", number)))
+                })
+                .collect::<Result<Vec<f32>, ParseError>>()?;
+
+            if let Some(first_row) = map.first()
+                && first_row.len() != row.len()
+            {
+                return Err(ParseError::at(
+                    line,
+                    format!(
+                        "row has {} rooms, the first one has {}",
+                        row.len(),
+                        first_row.len()
+                    ),
+                ));
+            }
+
+            for number in row.iter().filter(|number| **number != 0.0) {
+                if *number == number.floor() + START_ROOM_MARK {
+                    start_room_lines.push(line);
+                } else if *number == number.floor() + END_ROOM_MARK {
+                    end_room_lines.push(line);
+                }
+            }
+            map.push(row);
+        }
+
+        if map.is_empty() {
+            return Err(ParseError::new("it has no rooms".to_string()));
+        }
+
+        for (marked_room_lines, name) in [(start_room_lines, "start"), (end_room_lines, "end")] {
+            match marked_room_lines.as_slice() {
+                [] => return Err(ParseError::new(format!("it has no {} room", name))),
+                [_] => {}
+                [_, second_line, ..] => {
+                    return Err(ParseError::at(
+                        *second_line,
+                        format!("second {} room, a floor needs exactly one", name),
+                    ));
+                }
+            }
+        }
+
+        Ok(FloorMap { number, map })
+    }
+
+    // row and column of the end room, parse makes sure there is exactly one
+    pub fn end_room(&self) -> (usize, usize) {
+        self.marked_room(END_ROOM_MARK)
+    }
+
+    fn marked_room(&self, mark: f32) -> (usize, usize) {
+        self.map
+            .iter()
+            .enumerate()
+            .find_map(|(row_index, row)| {
+                row.iter()
+                    .position(|number| *number != 0.0 && *number == number.floor() + mark)
+                    .map(|column_index| (row_index, column_index))
+            })
+            .expect("Floor has no marked room")
+    }
+
+    // neighbour rooms are only connected when both of them have a door on the shared side
+    pub fn is_connected(&self, rooms: &Rooms) -> bool {
+        let map = &self.map;
+        let start_room = self.marked_room(START_ROOM_MARK);
+        let end_room = self.end_room();
+
+        let total_rows = map.len();
+        let total_columns = map[0].len();
+        let mut is_visited = vec![vec![false; total_columns]; total_rows];
+        is_visited[start_room.0][start_room.1] = true;
+
+        let mut queue = VecDeque::from([start_room]);
+        while let Some((row_index, column_index)) = queue.pop_front() {
+            if (row_index, column_index) == end_room {
+                return true;
+            }
+
+            let Some(room) = rooms.find_room(map[row_index][column_index]) else {
+                continue;
+            };
+
+            for door in Door::iterator() {
+                let (neighbour, opposite_door) = match door {
+                    Door::Left if column_index > 0 => ((row_index, column_index - 1), Door::Right),
+                    Door::Right if column_index + 1 < total_columns => {
+                        ((row_index, column_index + 1), Door::Left)
+                    }
+                    Door::Top if row_index > 0 => ((row_index - 1, column_index), Door::Bottom),
+                    Door::Bottom if row_index + 1 < total_rows => {
+                        ((row_index + 1, column_index), Door::Top)
+                    }
+                    _ => continue,
+                };
+
+                if is_visited[neighbour.0][neighbour.1] || map[neighbour.0][neighbour.1] == 0.0 {
+                    continue;
+                }
+
+                let Some(neighbour_room) = rooms.find_room(map[neighbour.0][neighbour.1]) else {
+                    continue;
+                };
+
+                if room.door_tile_position(door).is_some()
+                    && neighbour_room.door_tile_position(&opposite_door).is_some()
+                {
+                    is_visited[neighbour.0][neighbour.1] = true;
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        false
+    }
+}
+
+// the rows of a floor file with the line they come from, blank lines are skipped
+pub fn floor_rows(contents: &str) -> impl Iterator<Item = (usize, &str)> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| (index + 1, line))
+}
@@ -20,4 +20,7 @@ pub struct Monster {
     pub skill: Option<MonsterSkill>,
     pub origin_width: f32,
     pub origin_height: f32,
+    // a tileset of `frames` tiles of origin_width by origin_height, idle ones then moving ones
+    pub sprite: String,
+    pub frames: u8,
 }
@@ -1,16 +1,19 @@
-use bevy_inspector_egui::InspectorOptions;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
-#[derive(Serialize, Deserialize, Debug, Clone, InspectorOptions)]
-pub enum MonsterClass {
-    SmallZombie,
-    Zombie,
-    BigZombie,
-    Goblin,
-    Orc,
-    Ogre,
-    Imp,
-    Chort,
-    BigDemon,
-    Swampy,
+// the id of a monster in data.json, a new monster only needs a new entry there
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct MonsterClass(String);
+
+impl MonsterClass {
+    pub fn new(id: &str) -> Self {
+        MonsterClass(id.to_string())
+    }
+}
+
+impl fmt::Display for MonsterClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
 }
@@ -1,7 +1,158 @@
-use bevy_inspector_egui::InspectorOptions;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Clone, InspectorOptions)]
+use crate::resources::monster::monster_class::MonsterClass;
+
+// `name` picks the skill in data.json and every field of it has to be set, times are in
+// seconds, the skills of living monsters wait `cooldown` and then telegraph for `telegraph`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "name")]
 pub enum MonsterSkill {
-    Split,
+    // on death the monster splits into `count` monsters of `monster_class`
+    Split {
+        monster_class: MonsterClass,
+        count: u8,
+    },
+    Ranged {
+        cooldown: f32,
+        telegraph: f32,
+        range: f32,
+        damage: f32,
+        speed: f32,
+    },
+    Charge {
+        cooldown: f32,
+        telegraph: f32,
+        range: f32,
+        speed: f32,
+        duration: f32,
+    },
+    Summon {
+        cooldown: f32,
+        telegraph: f32,
+        range: f32,
+        monster_class: MonsterClass,
+        count: u8,
+    },
+    Heal {
+        cooldown: f32,
+        telegraph: f32,
+        heal_points: f32,
+    },
+    Slam {
+        cooldown: f32,
+        telegraph: f32,
+        range: f32,
+        damage: f32,
+        radius: f32,
+    },
+}
+
+impl MonsterSkill {
+    pub fn cooldown(&self) -> f32 {
+        match *self {
+            MonsterSkill::Split { .. } => 0.0,
+            MonsterSkill::Ranged { cooldown, .. }
+            | MonsterSkill::Charge { cooldown, .. }
+            | MonsterSkill::Summon { cooldown, .. }
+            | MonsterSkill::Heal { cooldown, .. }
+            | MonsterSkill::Slam { cooldown, .. } => cooldown,
+        }
+    }
+
+    pub fn telegraph(&self) -> f32 {
+        match *self {
+            MonsterSkill::Split { .. } => 0.0,
+            MonsterSkill::Ranged { telegraph, .. }
+            | MonsterSkill::Charge { telegraph, .. }
+            | MonsterSkill::Summon { telegraph, .. }
+            | MonsterSkill::Heal { telegraph, .. }
+            | MonsterSkill::Slam { telegraph, .. } => telegraph,
+        }
+    }
+
+    // a skill without a range is used wherever the player is
+    pub fn range(&self) -> Option<f32> {
+        match *self {
+            MonsterSkill::Split { .. } | MonsterSkill::Heal { .. } => None,
+            MonsterSkill::Ranged { range, .. }
+            | MonsterSkill::Charge { range, .. }
+            | MonsterSkill::Summon { range, .. }
+            | MonsterSkill::Slam { range, .. } => Some(range),
+        }
+    }
+
+    pub fn monster_class(&self) -> Option<&MonsterClass> {
+        match self {
+            MonsterSkill::Split { monster_class, .. }
+            | MonsterSkill::Summon { monster_class, .. } => Some(monster_class),
+            _ => None,
+        }
+    }
+
+    // every number of the skill by its data.json name, for the game data validation
+    pub fn values(&self) -> Vec<(&'static str, f32)> {
+        match *self {
+            MonsterSkill::Split { count, .. } => vec![("count", count as f32)],
+            MonsterSkill::Ranged {
+                cooldown,
+                telegraph,
+                range,
+                damage,
+                speed,
+            } => vec![
+                ("cooldown", cooldown),
+                ("telegraph", telegraph),
+                ("range", range),
+                ("damage", damage),
+                ("speed", speed),
+            ],
+            MonsterSkill::Charge {
+                cooldown,
+                telegraph,
+                range,
+                speed,
+                duration,
+            } => vec![
+                ("cooldown", cooldown),
+                ("telegraph", telegraph),
+                ("range", range),
+                ("speed", speed),
+                ("duration", duration),
+            ],
+            MonsterSkill::Summon {
+                cooldown,
+                telegraph,
+                range,
+                count,
+                ..
+            } => vec![
+                ("cooldown", cooldown),
+                ("telegraph", telegraph),
+                ("range", range),
+                ("count", count as f32),
+            ],
+            MonsterSkill::Heal {
+                cooldown,
+                telegraph,
+                heal_points,
+            } => vec![
+                ("cooldown", cooldown),
+                ("telegraph", telegraph),
+                ("heal_points", heal_points),
+            ],
+            MonsterSkill::Slam {
+                cooldown,
+                telegraph,
+                range,
+                damage,
+                radius,
+            } => vec![
+                ("cooldown", cooldown),
+                ("telegraph", telegraph),
+                ("range", range),
+                ("damage", damage),
+                ("radius", radius),
+            ],
+        }
+    }
 }
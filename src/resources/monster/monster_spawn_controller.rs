@@ -1,15 +1,39 @@
+use crate::config::TILE_SIZE;
 use crate::resources::game_mode::GameMode;
 use bevy::prelude::*;
+use rand::Rng;
 
 #[derive(Resource)]
 pub struct MonsterSpawnController {
     pub game_mode: GameMode,
-    pub alive_monsters: i8,
-    pub max_avalible_monsters: i8,
-    pub require_monster: i8,
-    pub killed_monsters: i8,
+    pub alive_monsters: i32,
+    pub max_avalible_monsters: i32,
+    pub require_monster: i32,
+    pub killed_monsters: i32,
     pub spawn_area_start_x: f32,
     pub spawn_area_start_y: f32,
     pub spawn_area_end_x: f32,
     pub spawn_area_end_y: f32,
+    // centres of the room tiles monsters may spawn on, the spawn area is used when empty
+    pub spawn_positions: Vec<Vec2>,
+}
+
+impl MonsterSpawnController {
+    pub fn random_spawn_position(&self, rng: &mut impl Rng) -> Vec2 {
+        if self.spawn_positions.is_empty() {
+            return Vec2::new(
+                rng.gen_range(self.spawn_area_start_x..self.spawn_area_end_x),
+                rng.gen_range(self.spawn_area_end_y..self.spawn_area_start_y),
+            );
+        }
+
+        let tile_position = self.spawn_positions[rng.gen_range(0..self.spawn_positions.len())];
+        let half_tile = TILE_SIZE / 2.0;
+
+        tile_position
+            + Vec2::new(
+                rng.gen_range(-half_tile..half_tile),
+                rng.gen_range(-half_tile..half_tile),
+            )
+    }
 }
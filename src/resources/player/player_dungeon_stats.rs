@@ -1,9 +1,10 @@
 use bevy::prelude::*;
 use bevy_inspector_egui::InspectorOptions;
+use serde::{Deserialize, Serialize};
 
 use crate::resources::dungeon::position::Position;
 
-#[derive(Resource, InspectorOptions, Default)]
+#[derive(Resource, InspectorOptions, Default, Serialize, Deserialize, Clone)]
 pub struct PlayerDungeonStats {
     pub current_floor_index: usize,
     pub current_room_position: Position,
@@ -6,7 +6,6 @@ use crate::resources::game_mode::GameMode;
 use crate::resources::hero::gender::Gender;
 use crate::resources::hero::hero_class::HeroClass;
 use crate::resources::stored_profile::StoredProfile;
-use crate::scenes::hero_select_scene::ButtonComponent;
 
 #[derive(Resource, Serialize, Deserialize, Debug, Clone)]
 pub struct Profile {
@@ -21,6 +20,7 @@ pub struct Profile {
     pub end_time: String,
     pub is_run_completed: bool,
     pub is_run_finished: bool,
+    pub seed: u64,
 }
 
 impl Profile {
@@ -30,7 +30,7 @@ impl Profile {
         Profile {
             name: String::new(),
             game_mode: GameMode::ClassicMode,
-            hero_class: HeroClass::Elf,
+            hero_class: HeroClass::new("Elf"),
             gender: Gender::Male,
             total_cleared_rooms: 0,
             total_killed_monsters: 0,
@@ -39,6 +39,7 @@ impl Profile {
             start_time: start_time.to_rfc3339(),
             is_run_completed: false,
             is_run_finished: false,
+            seed: rand::random::<u32>() as u64,
         }
     }
 
@@ -46,41 +47,13 @@ impl Profile {
         self.game_mode = game_mode;
     }
 
-    pub fn set_hero(&mut self, button: ButtonComponent) {
-        match button {
-            ButtonComponent::MaleElf => {
-                self.hero_class = HeroClass::Elf;
-                self.gender = Gender::Male;
-            }
-            ButtonComponent::FemaleElf => {
-                self.hero_class = HeroClass::Elf;
-                self.gender = Gender::Female;
-            }
-            ButtonComponent::MaleKnight => {
-                self.hero_class = HeroClass::Knight;
-                self.gender = Gender::Male;
-            }
-            ButtonComponent::FemaleKnight => {
-                self.hero_class = HeroClass::Knight;
-                self.gender = Gender::Female;
-            }
-            ButtonComponent::MaleLizard => {
-                self.hero_class = HeroClass::Lizard;
-                self.gender = Gender::Male;
-            }
-            ButtonComponent::FemaleLizard => {
-                self.hero_class = HeroClass::Lizard;
-                self.gender = Gender::Female;
-            }
-            ButtonComponent::MaleWizard => {
-                self.hero_class = HeroClass::Wizard;
-                self.gender = Gender::Male;
-            }
-            ButtonComponent::FemaleWizard => {
-                self.hero_class = HeroClass::Wizard;
-                self.gender = Gender::Female;
-            }
-        }
+    pub fn set_hero(&mut self, hero_class: HeroClass, gender: Gender) {
+        self.hero_class = hero_class;
+        self.gender = gender;
+    }
+
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
     }
 
     pub fn set_name(&mut self, user_name: String) {
@@ -107,6 +80,7 @@ impl Profile {
             total_cleared_waves: self.total_cleared_waves,
             date: self.start_time.clone(),
             playtime: diff_time_second,
+            seed: self.seed,
         }
     }
 }
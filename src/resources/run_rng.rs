@@ -0,0 +1,92 @@
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+// Every random roll of a run goes through one of these streams, so the same
+// seed gives the same dungeon and spawns whatever happens in combat
+#[derive(Resource)]
+pub struct RunRng {
+    pub map: RunStream,
+    pub spawns: RunStream,
+    pub loot: RunStream,
+    pub upgrades: RunStream,
+    pub combat: RunStream,
+}
+
+// how far every stream of a run has got, kept in the saves so a resumed run rolls on
+// where it stopped instead of starting the streams over
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct RunRngDraws {
+    pub map: u64,
+    pub spawns: u64,
+    pub loot: u64,
+    pub upgrades: u64,
+    pub combat: u64,
+}
+
+impl RunRng {
+    pub fn new(seed: u64) -> Self {
+        RunRng::resume(seed, &RunRngDraws::default())
+    }
+
+    pub fn resume(seed: u64, draws: &RunRngDraws) -> Self {
+        RunRng {
+            map: RunStream::new(seed, 1, draws.map),
+            spawns: RunStream::new(seed, 2, draws.spawns),
+            loot: RunStream::new(seed, 3, draws.loot),
+            upgrades: RunStream::new(seed, 4, draws.upgrades),
+            combat: RunStream::new(seed, 5, draws.combat),
+        }
+    }
+
+    pub fn draws(&self) -> RunRngDraws {
+        RunRngDraws {
+            map: self.map.draws,
+            spawns: self.spawns.draws,
+            loot: self.loot.draws,
+            upgrades: self.upgrades.draws,
+            combat: self.combat.draws,
+        }
+    }
+}
+
+// StdRng hands out its output one u32 word at a time, a u64 takes two words and bytes
+// take whole words, so counting words is enough to bring a new stream to the same point
+pub struct RunStream {
+    rng: StdRng,
+    draws: u64,
+}
+
+impl RunStream {
+    fn new(seed: u64, stream: u64, draws: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed ^ stream.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        for _ in 0..draws {
+            rng.next_u32();
+        }
+
+        RunStream { rng, draws }
+    }
+}
+
+impl RngCore for RunStream {
+    fn next_u32(&mut self) -> u32 {
+        self.draws += 1;
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.draws += 2;
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.draws += dest.len().div_ceil(4) as u64;
+        self.rng.fill_bytes(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.draws += dest.len().div_ceil(4) as u64;
+        self.rng.try_fill_bytes(dest)
+    }
+}
@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use chrono::{DateTime, Local};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::path::Path;
+
+use crate::components::player::PlayerComponent;
+use crate::components::player_list_effects::PlayerListEffectsComponent;
+use crate::components::skill::SkillComponent;
+use crate::components::weapon::WeaponComponent;
+use crate::config::SAVED_RUN_FILE;
+use crate::resources::dungeon::Dungeon;
+use crate::resources::effect::Effect;
+use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
+use crate::resources::profile::Profile;
+use crate::resources::run_rng::RunRngDraws;
+use crate::resources::skill::Skill;
+
+// the hero part of a save, shared by both game modes and used by the player and weapon setup
+#[derive(Resource, Serialize, Deserialize, Clone)]
+pub struct SavedPlayer {
+    pub player: PlayerComponent,
+    pub position: (f32, f32),
+    pub skill: Skill,
+    pub skill_monster_counter: u32,
+    pub effects_information: Vec<Effect>,
+    pub weapon_level: u8,
+}
+
+impl SavedPlayer {
+    pub fn new(
+        player: &PlayerComponent,
+        transform: &Transform,
+        skill: &SkillComponent,
+        player_list_effects: &PlayerListEffectsComponent,
+        weapon: &WeaponComponent,
+    ) -> Self {
+        SavedPlayer {
+            player: player.clone(),
+            position: (transform.translation.x, transform.translation.y),
+            skill: skill.skill.clone(),
+            skill_monster_counter: skill.monster_counter,
+            effects_information: player_list_effects.information.clone(),
+            weapon_level: weapon.level,
+        }
+    }
+}
+
+// everything needed to rebuild a classic mode run, monsters of an uncleared room are respawned
+#[derive(Resource, Serialize, Deserialize, Clone)]
+pub struct SavedRun {
+    pub profile: Profile,
+    pub dungeon: Dungeon,
+    pub player_dungeon_stats: PlayerDungeonStats,
+    pub player: SavedPlayer,
+    pub require_monsters: i32,
+    pub killed_monsters: i32,
+    // older saves have none, their streams start over
+    #[serde(default)]
+    pub run_rng: RunRngDraws,
+    pub saved_time: String,
+}
+
+impl SavedRun {
+    pub fn exists() -> bool {
+        Path::new(SAVED_RUN_FILE).exists()
+    }
+
+    pub fn load() -> Option<SavedRun> {
+        load_file(SAVED_RUN_FILE)
+    }
+
+    pub fn store(&self) {
+        store_file(SAVED_RUN_FILE, self);
+    }
+
+    pub fn delete() {
+        delete_file(SAVED_RUN_FILE);
+    }
+
+    pub fn resumed_profile(&self) -> Profile {
+        resumed_profile(&self.profile, &self.saved_time)
+    }
+}
+
+pub fn load_file<T: DeserializeOwned>(path: &str) -> Option<T> {
+    match File::open(path) {
+        Ok(mut file) => {
+            let mut contents = String::new();
+            file.read_to_string(&mut contents).ok()?;
+            serde_json::from_str(&contents).ok()
+        }
+        Err(_err) => None,
+    }
+}
+
+pub fn store_file<T: Serialize>(path: &str, value: &T) {
+    let mut saved_run_file = File::create(path).expect("Can't open saved run file");
+    let saved_run_str: String = serde_json::to_string(value).unwrap();
+    saved_run_file
+        .write_all(saved_run_str.as_bytes())
+        .expect("Unable to write file");
+}
+
+pub fn delete_file(path: &str) {
+    if Path::new(path).exists() {
+        fs::remove_file(path).expect("Can't remove saved run file");
+    }
+}
+
+// moves the start time forward so the time spent away from the run is not counted as playtime
+pub fn resumed_profile(profile: &Profile, saved_time: &str) -> Profile {
+    let mut profile = profile.clone();
+
+    let start_time =
+        DateTime::parse_from_rfc3339(profile.start_time.as_str()).expect("Error convert time");
+    let saved_time = DateTime::parse_from_rfc3339(saved_time).expect("Error convert time");
+
+    let resumed_start_time = Local::now() - (saved_time - start_time);
+    profile.start_time = resumed_start_time.to_rfc3339();
+    profile
+}
@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::config::SAVED_SURVIVAL_RUN_FILE;
+use crate::resources::profile::Profile;
+use crate::resources::run_rng::RunRngDraws;
+use crate::resources::saved_run::{self, SavedPlayer};
+
+// taken once a reward is picked, so resuming starts right at the next wave
+#[derive(Resource, Serialize, Deserialize, Clone)]
+pub struct SavedSurvivalRun {
+    pub profile: Profile,
+    pub wave_number: usize,
+    pub wave_duration: i64,
+    pub player: SavedPlayer,
+    #[serde(default)]
+    pub run_rng: RunRngDraws,
+    pub saved_time: String,
+}
+
+impl SavedSurvivalRun {
+    pub fn exists() -> bool {
+        Path::new(SAVED_SURVIVAL_RUN_FILE).exists()
+    }
+
+    pub fn load() -> Option<SavedSurvivalRun> {
+        saved_run::load_file(SAVED_SURVIVAL_RUN_FILE)
+    }
+
+    pub fn store(&self) {
+        saved_run::store_file(SAVED_SURVIVAL_RUN_FILE, self);
+    }
+
+    pub fn delete() {
+        saved_run::delete_file(SAVED_SURVIVAL_RUN_FILE);
+    }
+
+    pub fn resumed_profile(&self) -> Profile {
+        saved_run::resumed_profile(&self.profile, &self.saved_time)
+    }
+}
@@ -6,18 +6,30 @@ use std::io::prelude::*;
 use crate::config::*;
 use crate::resources::language::Language;
 
+// bump when a stored field changes meaning, older files are migrated in `Setting::load`
+const SETTING_VERSION: u32 = 1;
+
 #[derive(Resource, Serialize, Deserialize, Debug)]
+#[serde(default = "Setting::new")]
 pub struct Setting {
+    // files written before the version field existed are read as version 0
+    #[serde(default)]
+    version: u32,
     enable_sound: bool,
     enable_music: bool,
+    sound_volume: f32,
+    music_volume: f32,
     language: Language,
 }
 
 impl Setting {
-    pub fn new(enable_sound: bool, enable_music: bool) -> Self {
+    pub fn new() -> Self {
         Setting {
-            enable_sound,
-            enable_music,
+            version: SETTING_VERSION,
+            enable_sound: true,
+            enable_music: true,
+            sound_volume: 0.5,
+            music_volume: 0.2,
             language: Language::EN,
         }
     }
@@ -30,6 +42,14 @@ impl Setting {
         self.enable_music
     }
 
+    pub fn get_sound_volume(&self) -> f32 {
+        self.sound_volume
+    }
+
+    pub fn get_music_volume(&self) -> f32 {
+        self.music_volume
+    }
+
     pub fn get_language(&self) -> Language {
         self.language
     }
@@ -42,6 +62,14 @@ impl Setting {
         self.enable_music = enable_music;
     }
 
+    pub fn set_sound_volume(&mut self, sound_volume: f32) {
+        self.sound_volume = sound_volume.clamp(0.0, 1.0);
+    }
+
+    pub fn set_music_volume(&mut self, music_volume: f32) {
+        self.music_volume = music_volume.clamp(0.0, 1.0);
+    }
+
     pub fn set_language(&mut self, language: Language) {
         self.language = language;
     }
@@ -50,38 +78,52 @@ impl Setting {
         let mut setting_file = File::create(SETTING_FILE).expect("Can't open setting file");
         let setting_str: String = serde_json::to_string(&self).unwrap();
         setting_file
-            .write(setting_str.as_bytes())
+            .write_all(setting_str.as_bytes())
             .expect("Unable to write file");
     }
 
-    #[allow(dead_code)] // unused but maybe needed for something
-    pub fn load_setting(&mut self) {
+    pub fn load() -> Self {
         let setting = match File::open(SETTING_FILE) {
             Ok(mut file) => {
                 let mut contents = String::new();
-                file.read_to_string(&mut contents).unwrap();
-                serde_json::from_str(&contents).expect("JSON was not well-formatted")
+                let parsed_setting = match file.read_to_string(&mut contents) {
+                    Ok(_) => {
+                        serde_json::from_str::<Setting>(&contents).map_err(|err| err.to_string())
+                    }
+                    Err(err) => Err(err.to_string()),
+                };
+
+                match parsed_setting {
+                    Ok(setting) => setting,
+                    Err(err) => {
+                        warn!("Setting file is malformed, using default setting: {}", err);
+                        let setting = Setting::new();
+                        setting.store();
+                        setting
+                    }
+                }
             }
             Err(_err) => {
-                let mut setting_file =
-                    File::create(SETTING_FILE).expect("Error create setting file");
-                let setting_str = serde_json::to_string(&Setting::new(true, true)).unwrap();
-                setting_file
-                    .write(setting_str.as_bytes())
-                    .expect("Unable to write file");
-                Setting::new(true, true)
+                let setting = Setting::new();
+                setting.store();
+                setting
             }
         };
-        self.enable_sound = setting.enable_sound;
-        self.enable_music = setting.enable_music;
-        self.language = setting.language;
+
+        setting.migrate()
+    }
+
+    fn migrate(mut self) -> Self {
+        if self.version < SETTING_VERSION {
+            self.version = SETTING_VERSION;
+            self.store();
+        }
+        self
     }
 }
 
 impl FromWorld for Setting {
     fn from_world(_world: &mut World) -> Self {
-        let setting: Setting = Setting::new(false, false);
-        // setting.load_setting();
-        setting
+        Setting::load()
     }
 }
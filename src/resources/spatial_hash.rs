@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+use crate::components::collider::{Collider, CollisionLayer};
+use crate::config::TILE_SIZE;
+
+#[derive(Clone, Copy, Debug)]
+pub struct SpatialEntry {
+    pub entity: Entity,
+    pub translation: Vec3,
+    pub collider: Collider,
+}
+
+// Broad phase shared by the collision systems. Every entry is stored in each cell
+// its collider overlaps, so a query only has to look at the cells around the collider it is given.
+#[derive(Resource)]
+pub struct SpatialHash {
+    cell_size: f32,
+    cells: HashMap<(CollisionLayer, i32, i32), Vec<SpatialEntry>>,
+}
+
+impl SpatialHash {
+    pub fn new(cell_size: f32) -> Self {
+        SpatialHash {
+            cell_size,
+            cells: HashMap::new(),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        // keep the allocated cells, most of them are filled again next frame
+        for entries in self.cells.values_mut() {
+            entries.clear();
+        }
+    }
+
+    pub fn insert(&mut self, entity: Entity, translation: Vec3, collider: &Collider) {
+        let entry = SpatialEntry {
+            entity,
+            translation,
+            collider: *collider,
+        };
+
+        let ((start_x, start_y), (end_x, end_y)) = self.cell_range(translation, collider);
+        for cell_x in start_x..=end_x {
+            for cell_y in start_y..=end_y {
+                self.cells
+                    .entry((collider.layer, cell_x, cell_y))
+                    .or_default()
+                    .push(entry);
+            }
+        }
+    }
+
+    // entries of `layer` sharing a cell with the collider, they do not have to touch it
+    pub fn query(
+        &self,
+        layer: CollisionLayer,
+        translation: Vec3,
+        collider: &Collider,
+    ) -> Vec<SpatialEntry> {
+        let mut entries: Vec<SpatialEntry> = Vec::new();
+        // an entry is stored in every cell it overlaps, it is only returned once
+        let mut seen_entities: HashSet<Entity> = HashSet::new();
+
+        let ((start_x, start_y), (end_x, end_y)) = self.cell_range(translation, collider);
+        for cell_x in start_x..=end_x {
+            for cell_y in start_y..=end_y {
+                if let Some(cell_entries) = self.cells.get(&(layer, cell_x, cell_y)) {
+                    for entry in cell_entries.iter() {
+                        if seen_entities.insert(entry.entity) {
+                            entries.push(*entry);
+                        }
+                    }
+                }
+            }
+        }
+
+        entries
+    }
+
+    // entries of `layer` whose collider intersects the given one
+    pub fn colliding(
+        &self,
+        layer: CollisionLayer,
+        translation: Vec3,
+        collider: &Collider,
+    ) -> Vec<SpatialEntry> {
+        self.query(layer, translation, collider)
+            .into_iter()
+            .filter(|entry| collider.intersects(translation, &entry.collider, entry.translation))
+            .collect()
+    }
+
+    fn cell_range(&self, translation: Vec3, collider: &Collider) -> ((i32, i32), (i32, i32)) {
+        let centre = collider.centre(translation).truncate();
+        let half_size = collider.size() / 2.0;
+        let start = (centre - half_size) / self.cell_size;
+        let end = (centre + half_size) / self.cell_size;
+
+        (
+            (start.x.floor() as i32, start.y.floor() as i32),
+            (end.x.floor() as i32, end.y.floor() as i32),
+        )
+    }
+}
+
+impl Default for SpatialHash {
+    fn default() -> Self {
+        SpatialHash::new(TILE_SIZE * 2.0)
+    }
+}
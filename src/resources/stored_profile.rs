@@ -15,4 +15,6 @@ pub struct StoredProfile {
     pub total_cleared_waves: usize,
     pub date: String,
     pub playtime: i64,
+    #[serde(default)]
+    pub seed: u64,
 }
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::resources::game_data::GameData;
+
 mod effect_upgrade;
 mod skill_upgrade;
 mod stats_upgrade;
@@ -18,3 +20,63 @@ pub struct Upgrade {
     pub stats_upgrade: Option<StatsUpgrade>,
     pub effect_upgrade: Option<EffectUpgrade>,
 }
+
+impl Upgrade {
+    pub fn upgrade_type(&self) -> UpgradeType {
+        self.upgrade_type.clone()
+    }
+
+    // the type, what it upgrades and the bonuses it gives, e.g. "Effect SpeedUp duration_bonus=2"
+    pub fn name(&self) -> String {
+        let payload = match self.upgrade_type {
+            UpgradeType::Skill => serde_json::to_value(&self.skill_upgrade),
+            UpgradeType::Stats => serde_json::to_value(&self.stats_upgrade),
+            UpgradeType::Effect => serde_json::to_value(&self.effect_upgrade),
+            UpgradeType::Weapon => Ok(serde_json::Value::Null),
+        }
+        .expect("Upgrade can't be serialized");
+
+        let mut parts = vec![format!("{:?}", self.upgrade_type)];
+        if let serde_json::Value::Object(fields) = payload {
+            if let Some(serde_json::Value::String(name)) = fields.get("name") {
+                parts.push(name.clone());
+            }
+            for (key, value) in fields.iter() {
+                // bonuses are f32, printed as f64 they would get a long tail
+                if let Some(number) = value.as_f64() {
+                    parts.push(format!("{}={}", key, number as f32));
+                }
+            }
+        }
+        parts.join(" ")
+    }
+
+    // the payload has to match the upgrade type and point at things that exist
+    pub fn validate(&self, game_data: &GameData) -> Result<(), String> {
+        match self.upgrade_type {
+            UpgradeType::Skill => match &self.skill_upgrade {
+                Some(skill_upgrade) if !game_data.has_skill(skill_upgrade.name.clone()) => Err(
+                    format!("skill upgrade for unknown skill {:?}", skill_upgrade.name),
+                ),
+                Some(_) => Ok(()),
+                None => Err("skill upgrade without skill_upgrade".to_string()),
+            },
+            UpgradeType::Stats => match &self.stats_upgrade {
+                Some(_) => Ok(()),
+                None => Err("stats upgrade without stats_upgrade".to_string()),
+            },
+            UpgradeType::Effect => match &self.effect_upgrade {
+                Some(effect_upgrade) if !game_data.has_effect_information(effect_upgrade.name) => {
+                    Err(format!(
+                        "effect upgrade for unknown effect {:?}",
+                        effect_upgrade.name
+                    ))
+                }
+                Some(_) => Ok(()),
+                None => Err("effect upgrade without effect_upgrade".to_string()),
+            },
+            // the next weapon comes from the hero progression
+            UpgradeType::Weapon => Ok(()),
+        }
+    }
+}
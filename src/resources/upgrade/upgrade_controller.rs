@@ -1,34 +1,74 @@
 use bevy::prelude::*;
 use rand::prelude::*;
-use std::fs::File;
-use std::io::prelude::*;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
 
 use crate::config::*;
-use crate::resources::hero::hero_class::HeroClass;
+use crate::resources::game_data::GameData;
 use crate::resources::skill::skill_type::SkillType;
 use crate::resources::upgrade::Upgrade;
 use crate::resources::upgrade::upgrade_type::UpgradeType;
 
+// the contents of an upgrades.json, from the base game or a mod pack
+#[derive(Asset, TypePath, Deserialize, Clone)]
+#[serde(transparent)]
+pub struct UpgradeList {
+    pub upgrades: Vec<Upgrade>,
+}
+
 #[derive(Resource)]
 pub struct UpgradeController {
     pub upgrades: Vec<Upgrade>,
 }
 
 impl UpgradeController {
+    // reads the upgrades right away, the game loads them through the asset server instead
     pub fn new() -> Self {
-        let upgrades: Vec<Upgrade> = match File::open(UPGRADES_FILE) {
-            Ok(mut file) => {
-                let mut contents = String::new();
-                file.read_to_string(&mut contents).unwrap();
-                serde_json::from_str(&contents).expect("JSON was not well-formatted")
-            }
-            Err(err) => panic!("Can't find language file: {}", err),
+        let path = Path::new(ASSETS_DIRECTORY).join(UPGRADES_FILE);
+        let upgrades: Vec<Upgrade> = match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).expect("JSON was not well-formatted"),
+            Err(err) => panic!("Can't open upgrades file: {}", err),
         };
 
         UpgradeController { upgrades }
     }
 
-    pub fn get_skill_upgrade(&self, skill_name: SkillType) -> Upgrade {
+    // the upgrade rewards pick a random skill, stats and effect upgrade, so every hero skill
+    // needs at least one skill upgrade and there has to be a stats and an effect one
+    pub fn missing_upgrades(&self, game_data: &GameData) -> Vec<String> {
+        let mut missing = Vec::new();
+
+        for hero in game_data.get_heroes() {
+            let has_skill_upgrade = self.upgrades.iter().any(|upgrade| {
+                upgrade.upgrade_type == UpgradeType::Skill
+                    && upgrade
+                        .skill_upgrade
+                        .as_ref()
+                        .is_some_and(|skill_upgrade| skill_upgrade.name == hero.skill)
+            });
+            if !has_skill_upgrade {
+                missing.push(format!(
+                    "hero {:?} skill {:?} has no skill upgrade",
+                    hero.hero_class, hero.skill
+                ));
+            }
+        }
+
+        for upgrade_type in [UpgradeType::Stats, UpgradeType::Effect] {
+            if !self
+                .upgrades
+                .iter()
+                .any(|upgrade| upgrade.upgrade_type == upgrade_type)
+            {
+                missing.push(format!("there is no {:?} upgrade", upgrade_type));
+            }
+        }
+
+        missing
+    }
+
+    pub fn get_skill_upgrade(&self, skill_name: SkillType, rng: &mut impl Rng) -> Upgrade {
         let skill_upgrades: Vec<Upgrade> = self
             .upgrades
             .iter()
@@ -39,12 +79,11 @@ impl UpgradeController {
             .cloned()
             .collect();
 
-        let mut rng = rand::thread_rng();
         let random_index = rng.gen_range(0..skill_upgrades.len());
         skill_upgrades[random_index].clone()
     }
 
-    pub fn get_stats_upgrade(&self) -> Upgrade {
+    pub fn get_stats_upgrade(&self, rng: &mut impl Rng) -> Upgrade {
         let stats_upgrades: Vec<Upgrade> = self
             .upgrades
             .iter()
@@ -52,12 +91,11 @@ impl UpgradeController {
             .cloned()
             .collect();
 
-        let mut rng = rand::thread_rng();
         let random_index = rng.gen_range(0..stats_upgrades.len());
         stats_upgrades[random_index].clone()
     }
 
-    pub fn get_effect_upgrade(&self) -> Upgrade {
+    pub fn get_effect_upgrade(&self, rng: &mut impl Rng) -> Upgrade {
         let effect_upgrades: Vec<Upgrade> = self
             .upgrades
             .iter()
@@ -65,24 +103,23 @@ impl UpgradeController {
             .cloned()
             .collect();
 
-        let mut rng = rand::thread_rng();
         let random_index = rng.gen_range(0..effect_upgrades.len());
         effect_upgrades[random_index].clone()
     }
 
-    pub fn get_three_upgrades(&self, hero_class: HeroClass, weapon_level: u8) -> Vec<UpgradeType> {
+    pub fn get_three_upgrades(
+        &self,
+        can_upgrade_weapon: bool,
+        rng: &mut impl Rng,
+    ) -> Vec<UpgradeType> {
         let mut three_upgrades: Vec<UpgradeType> = Vec::new();
         let mut upgrade_types: Vec<UpgradeType> =
             vec![UpgradeType::Stats, UpgradeType::Skill, UpgradeType::Effect];
 
-        if (hero_class == HeroClass::Elf && weapon_level < 2)
-            || (hero_class != HeroClass::Elf && weapon_level < 3)
-        {
+        if can_upgrade_weapon {
             upgrade_types.push(UpgradeType::Weapon);
         }
 
-        let mut rng = rand::thread_rng();
-
         loop {
             if three_upgrades.len() < 4 {
                 let random_index = rng.gen_range(0..upgrade_types.len());
@@ -1,10 +1,13 @@
 use bevy_inspector_egui::InspectorOptions;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Clone, InspectorOptions, Copy)]
+// without a sprite the weapon shoots the default bullet, the color is a hex code
+#[derive(Serialize, Deserialize, Debug, Clone, InspectorOptions)]
 pub struct Bullet {
     pub width: f32,
     pub height: f32,
     pub speed: f32,
     pub scale: f32,
+    pub sprite: Option<String>,
+    pub color: Option<String>,
 }
@@ -11,7 +11,7 @@ use attack_type::AttackType;
 use bullet::Bullet;
 use weapon_type::WeaponType;
 
-#[derive(Serialize, Deserialize, Debug, Clone, InspectorOptions, Copy)]
+#[derive(Serialize, Deserialize, Debug, Clone, InspectorOptions)]
 pub struct Weapon {
     pub name: WeaponType,
     pub attack_type: AttackType,
@@ -24,7 +24,11 @@ pub struct Weapon {
     pub effect: Option<EffectType>,
     pub trigger_chance: Option<f32>,
     pub effect_bonus: Option<f32>,
+    pub shoot_while_moving: Option<bool>,
     pub bullet: Option<Bullet>,
     pub cooldown: Option<u64>,
     pub scale: f32,
+    pub sprite: String,
+    // a shooting weapon with an aim rotation turns towards the cursor, in degrees
+    pub aim_rotation: Option<f32>,
 }
@@ -1,17 +1,19 @@
-use bevy_inspector_egui::prelude::InspectorOptions;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Copy, InspectorOptions)]
-pub enum WeaponType {
-    Bow,
-    Spear,
-    ShortSword,
-    Sword,
-    BigMachete,
-    SmallWand,
-    MagicWand,
-    MagicSword,
-    SmallHammer,
-    Mace,
-    BigHammer,
+// the id of a weapon in data.json, a new weapon only needs a new entry there
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct WeaponType(String);
+
+impl WeaponType {
+    pub fn new(id: &str) -> Self {
+        WeaponType(id.to_string())
+    }
+}
+
+impl fmt::Display for WeaponType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
 }
@@ -1,3 +1,4 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
 use bevy::prelude::*;
 use std::slice::Iter;
 
@@ -13,6 +14,7 @@ use bevy::color::palettes::css::*;
 
 const RETURN_BUTTON_SIDE: f32 = 50.0;
 const FONT_SIZE: f32 = 35.0;
+const MAX_SEED_DIGITS: usize = 10;
 
 const BOX_TILE_SIZE: f32 = 60.0;
 const BOX_WIDTH_TILES: f32 = 10.0;
@@ -51,12 +53,22 @@ struct GameModeSelectSceneData {
     user_interface_root: Entity,
 }
 
+#[derive(Component)]
+struct SeedText;
+
+// typed digits, the generated seed is used while nothing is typed
+#[derive(Resource)]
+struct SeedInput {
+    value: String,
+    random_seed: u64,
+}
+
 impl Plugin for GameModeSelectScenePlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(SceneState::GameModeSelectScene), setup);
         app.add_systems(
             Update,
-            (button_handle_system, return_button_handle)
+            (button_handle_system, return_button_handle, seed_input_handle)
                 .run_if(in_state(SceneState::GameModeSelectScene)),
         );
         app.add_systems(OnExit(SceneState::GameModeSelectScene), cleanup);
@@ -69,6 +81,8 @@ fn setup(
     dictionary: Res<Dictionary>,
     mut commands: Commands,
 ) {
+    let profile = Profile::new();
+
     // user interface root
     let user_interface_root = commands
         .spawn((
@@ -83,6 +97,7 @@ fn setup(
             menu_box(parent, &scenes_materials.menu_box_materials);
             select_game_mode_text(parent, &font_materials, &dictionary);
             buttons(parent, &scenes_materials, &font_materials, &dictionary);
+            seed_text(parent, &font_materials, &dictionary, profile.seed);
         })
         .insert(Name::new("UIRoot"))
         .id();
@@ -91,13 +106,19 @@ fn setup(
         user_interface_root,
     });
 
-    commands.insert_resource(Profile::new());
+    commands.insert_resource(SeedInput {
+        value: String::new(),
+        random_seed: profile.seed,
+    });
+    commands.insert_resource(profile);
 }
 
 fn cleanup(mut commands: Commands, game_mode_select_scene_data: Res<GameModeSelectSceneData>) {
     commands
         .entity(game_mode_select_scene_data.user_interface_root)
         .despawn();
+
+    commands.remove_resource::<SeedInput>();
 }
 
 fn menu_box(root: &mut ChildSpawnerCommands, menu_box_materials: &MenuBoxMaterials) {
@@ -236,6 +257,85 @@ fn buttons(
     }
 }
 
+fn seed_text(
+    root: &mut ChildSpawnerCommands,
+    font_materials: &FontMaterials,
+    dictionary: &Dictionary,
+    seed: u64,
+) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let glossary = dictionary.get_glossary();
+
+    root.spawn(Node {
+        left: Val::Px((WINDOW_HEIGHT * RESOLUTION - 300.0) / 2.0),
+        top: Val::Px(380.0),
+        width: Val::Px(300.0),
+        height: Val::Px(FONT_SIZE),
+        justify_content: JustifyContent::Center,
+        position_type: PositionType::Absolute,
+        ..Default::default()
+    })
+    .with_children(|parent| {
+        parent
+            .spawn((
+                Text::new(glossary.shared_text.seed + seed.to_string().as_str()),
+                TextFont {
+                    font: font.clone(),
+                    font_size: FONT_SIZE * 0.8,
+                    ..Default::default()
+                },
+                TextColor(Color::from(GRAY)),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ))
+            .insert(SeedText);
+    })
+    .insert(Name::new("Seed"));
+}
+
+fn seed_input_handle(
+    seed_text_query: Query<Entity, With<SeedText>>,
+    mut keyboard_input_events: EventReader<KeyboardInput>,
+    mut seed_input: ResMut<SeedInput>,
+    mut profile: ResMut<Profile>,
+    dictionary: Res<Dictionary>,
+    mut writer: TextUiWriter,
+) {
+    let mut is_seed_changed = false;
+
+    for event in keyboard_input_events.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Character(character) => {
+                let digit = character.chars().next().unwrap_or_default();
+                if digit.is_ascii_digit() && seed_input.value.len() < MAX_SEED_DIGITS {
+                    seed_input.value.push(digit);
+                    is_seed_changed = true;
+                }
+            }
+            Key::Backspace => {
+                seed_input.value.pop();
+                is_seed_changed = true;
+            }
+            _ => {}
+        }
+    }
+
+    if is_seed_changed {
+        let seed = seed_input
+            .value
+            .parse::<u64>()
+            .unwrap_or(seed_input.random_seed);
+        profile.set_seed(seed);
+
+        let glossary = dictionary.get_glossary();
+        let entity = seed_text_query.single().unwrap();
+        *writer.text(entity, 0) = glossary.shared_text.seed + seed.to_string().as_str();
+    }
+}
+
 fn button_handle_system(
     mut button_query: Query<
         (&Interaction, &ButtonComponent, &Children),
@@ -1,11 +1,11 @@
 use bevy::prelude::*;
-use std::slice::Iter;
 
 use crate::materials::font::FontMaterials;
 use crate::materials::ingame::InGameMaterials;
 use crate::materials::menu_box::MenuBoxMaterials;
 use crate::materials::scenes::ScenesMaterials;
 use crate::resources::dictionary::Dictionary;
+use crate::resources::game_data::GameData;
 use crate::resources::game_mode::GameMode;
 use crate::resources::hero::gender::Gender;
 use crate::resources::hero::hero_class::HeroClass;
@@ -15,6 +15,12 @@ use crate::scenes::SceneState;
 const RETURN_BUTTON_SIZE: f32 = 50.0;
 const BOX_TILE_SIZE: f32 = 60.0;
 
+// the hero columns share the width of the menu box whatever the number of heroes
+const HEROES_WIDTH: f32 = 700.0;
+const HEROES_CENTER_X: f32 = 12.5;
+const HERO_ROWS_Y: [f32; 2] = [75.0, -100.0];
+const BUTTON_OFFSET: Vec2 = Vec2::new(460.0, 245.0);
+
 const BOX_ARRAY: [[i8; 13]; 9] = [
     [0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2],
     [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
@@ -30,54 +36,27 @@ const BOX_ARRAY: [[i8; 13]; 9] = [
 #[derive(Component)]
 struct ReturnButtonComponent;
 
-#[derive(Component, PartialEq, Clone)]
-pub enum ButtonComponent {
-    MaleElf,
-    FemaleElf,
-    MaleKnight,
-    FemaleKnight,
-    MaleWizard,
-    FemaleWizard,
-    MaleLizard,
-    FemaleLizard,
+// the hero and gender a button or an image of the scene stands for
+#[derive(PartialEq, Clone)]
+struct HeroChoice {
+    hero_class: HeroClass,
+    gender: Gender,
 }
 
-impl ButtonComponent {
-    pub fn iterator() -> Iter<'static, ButtonComponent> {
-        [
-            ButtonComponent::MaleElf,
-            ButtonComponent::MaleKnight,
-            ButtonComponent::MaleWizard,
-            ButtonComponent::MaleLizard,
-            ButtonComponent::FemaleElf,
-            ButtonComponent::FemaleKnight,
-            ButtonComponent::FemaleWizard,
-            ButtonComponent::FemaleLizard,
-        ]
-        .iter()
-    }
-}
+#[derive(Component)]
+struct ButtonComponent(HeroChoice);
 
 #[derive(Resource)]
 struct AnimationController {
     run_animation: bool,
-    hero_image: HeroImageComponent,
+    hero_image: Option<HeroChoice>,
     timer: Timer,
 }
 
 pub struct HeroSelectScenePlugin;
 
-#[derive(Component, Clone, PartialEq, Eq)]
-enum HeroImageComponent {
-    MaleElf,
-    FemaleElf,
-    MaleKnight,
-    FemaleKnight,
-    MaleWizard,
-    FemaleWizard,
-    MaleLizard,
-    FemaleLizard,
-}
+#[derive(Component)]
+struct HeroImageComponent(HeroChoice);
 
 #[derive(Resource)]
 struct HeroSelectSceneData {
@@ -104,11 +83,14 @@ impl Plugin for HeroSelectScenePlugin {
 fn setup(
     texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
     ingame_materials: Res<InGameMaterials>,
+    game_data: Res<GameData>,
     scenes_materials: Res<ScenesMaterials>,
     font_materials: Res<FontMaterials>,
     dictionary: Res<Dictionary>,
     mut commands: Commands,
 ) {
+    let hero_choices = hero_choices(&game_data);
+
     let sprite_bundle = commands
         .spawn(Sprite {
             image: scenes_materials.sub_background_image.clone(),
@@ -116,7 +98,7 @@ fn setup(
         })
         .with_children(|parent| {
             menu_box(parent, &scenes_materials.menu_box_materials);
-            heroes_images(parent, &ingame_materials, texture_atlases)
+            heroes_images(parent, &hero_choices, &ingame_materials, texture_atlases)
         })
         .insert(Name::new("SpriteBundle"))
         .id();
@@ -133,7 +115,7 @@ fn setup(
         .with_children(|parent| {
             select_hero_text(parent, &font_materials, &dictionary);
             return_button(parent, &scenes_materials);
-            heroes_buttons(parent);
+            heroes_buttons(parent, &hero_choices);
         })
         .insert(Name::new("UIRoot"))
         .id();
@@ -145,7 +127,7 @@ fn setup(
 
     commands.insert_resource(AnimationController {
         run_animation: false,
-        hero_image: HeroImageComponent::MaleElf,
+        hero_image: None,
         timer: Timer::from_seconds(0.1, TimerMode::Repeating),
     });
 }
@@ -243,105 +225,61 @@ fn return_button_handle(
     }
 }
 
+// every hero of the game data gets a column, males on the top row and females below
+fn hero_choices(game_data: &GameData) -> Vec<(Vec2, HeroChoice)> {
+    let heroes = game_data.get_heroes();
+    let column_width = HEROES_WIDTH / heroes.len() as f32;
+    let first_column_x = HEROES_CENTER_X - column_width * (heroes.len() - 1) as f32 / 2.0;
+
+    let mut hero_choices = Vec::new();
+    for (column, hero) in heroes.into_iter().enumerate() {
+        for (gender, y) in Gender::iterator().zip(HERO_ROWS_Y) {
+            let position = Vec2::new(first_column_x + column_width * column as f32, y);
+            let hero_choice = HeroChoice {
+                hero_class: hero.hero_class.clone(),
+                gender: gender.clone(),
+            };
+            hero_choices.push((position, hero_choice));
+        }
+    }
+
+    hero_choices
+}
+
 fn heroes_images(
     root: &mut ChildSpawnerCommands,
+    hero_choices: &[(Vec2, HeroChoice)],
     ingame_materials: &InGameMaterials,
     mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
 ) {
-    let mut index = 0;
-    let hero_image_positions: [[f32; 2]; 8] = [
-        [-250.0, 75.0],
-        [-250.0, -100.0],
-        [-75.0, 75.0],
-        [-75.0, -100.0],
-        [100.0, 75.0],
-        [100.0, -100.0],
-        [275.0, 75.0],
-        [275.0, -100.0],
-    ];
-
-    for hero_class in HeroClass::iterator() {
-        for gender in Gender::iterator() {
-            let hero_tileset;
-            let hero_image;
-            let component_name;
-
-            match hero_class {
-                HeroClass::Elf => match gender {
-                    Gender::Male => {
-                        hero_tileset = ingame_materials.heroes_materials.male_elf.clone();
-                        hero_image = HeroImageComponent::MaleElf;
-                        component_name = format!("{}_{}", "Elf", "Male");
-                    }
-                    Gender::Female => {
-                        hero_tileset = ingame_materials.heroes_materials.female_elf.clone();
-                        hero_image = HeroImageComponent::FemaleElf;
-                        component_name = format!("{}_{}", "Elf", "Female");
-                    }
-                },
-                HeroClass::Knight => match gender {
-                    Gender::Male => {
-                        hero_tileset = ingame_materials.heroes_materials.male_knight.clone();
-                        hero_image = HeroImageComponent::MaleKnight;
-                        component_name = format!("{}_{}", "Knight", "Male");
-                    }
-                    Gender::Female => {
-                        hero_tileset = ingame_materials.heroes_materials.female_knight.clone();
-                        hero_image = HeroImageComponent::FemaleKnight;
-                        component_name = format!("{}_{}", "Knight", "Female");
-                    }
-                },
-                HeroClass::Lizard => match gender {
-                    Gender::Male => {
-                        hero_tileset = ingame_materials.heroes_materials.male_lizard.clone();
-                        hero_image = HeroImageComponent::MaleLizard;
-                        component_name = format!("{}_{}", "Lizard", "Male");
-                    }
-                    Gender::Female => {
-                        hero_tileset = ingame_materials.heroes_materials.female_lizard.clone();
-                        hero_image = HeroImageComponent::FemaleLizard;
-                        component_name = format!("{}_{}", "Lizard", "Female");
-                    }
-                },
-                HeroClass::Wizard => match gender {
-                    Gender::Male => {
-                        hero_tileset = ingame_materials.heroes_materials.male_wizard.clone();
-                        hero_image = HeroImageComponent::MaleWizard;
-                        component_name = format!("{}_{}", "Wizard", "Male");
-                    }
-                    Gender::Female => {
-                        hero_tileset = ingame_materials.heroes_materials.female_wizard.clone();
-                        hero_image = HeroImageComponent::FemaleWizard;
-                        component_name = format!("{}_{}", "Wizard", "Female");
-                    }
-                },
-            };
-
-            let texture_atlas = TextureAtlasLayout::from_grid(UVec2::new(16, 28), 9, 1, None, None);
-            let texture_atlas_handle = texture_atlases.add(texture_atlas);
-            let sprite = Sprite::from_atlas_image(
-                hero_tileset,
-                TextureAtlas {
-                    layout: texture_atlas_handle,
-                    index: 0,
-                },
-            );
-
-            let x = hero_image_positions[index][0];
-            let y = hero_image_positions[index][1];
+    for (position, hero_choice) in hero_choices.iter() {
+        let hero_tileset = ingame_materials
+            .heroes_materials
+            .get_texture(&hero_choice.hero_class, &hero_choice.gender);
+
+        let texture_atlas = TextureAtlasLayout::from_grid(UVec2::new(16, 28), 9, 1, None, None);
+        let texture_atlas_handle = texture_atlases.add(texture_atlas);
+        let sprite = Sprite::from_atlas_image(
+            hero_tileset,
+            TextureAtlas {
+                layout: texture_atlas_handle,
+                index: 0,
+            },
+        );
 
-            root.spawn((
-                sprite,
-                Transform {
-                    translation: Vec3::new(x, y, 0.2),
-                    scale: Vec3::splat(4.0),
-                    ..Default::default()
-                },
-            ))
-            .insert(Name::new(component_name))
-            .insert(hero_image);
-            index += 1;
-        }
+        root.spawn((
+            sprite,
+            Transform {
+                translation: position.extend(0.2),
+                scale: Vec3::splat(4.0),
+                ..Default::default()
+            },
+        ))
+        .insert(Name::new(format!(
+            "{}_{:?}",
+            hero_choice.hero_class, hero_choice.gender
+        )))
+        .insert(HeroImageComponent(hero_choice.clone()));
     }
 }
 
@@ -371,36 +309,15 @@ fn select_hero_text(
     .insert(Name::new("SelectHeroText"));
 }
 
-fn heroes_buttons(root: &mut ChildSpawnerCommands) {
-    let button_positions: [[f32; 2]; 8] = [
-        [210.0, 170.0],
-        [380.0, 170.0],
-        [560.0, 170.0],
-        [740.0, 170.0],
-        [210.0, 350.0],
-        [380.0, 350.0],
-        [560.0, 350.0],
-        [740.0, 350.0],
-    ];
-
-    for (index, value) in ButtonComponent::iterator().enumerate() {
-        let component_name = match index {
-            1 => "MaleElf",
-            2 => "MaleKnight",
-            3 => "MaleLizard",
-            4 => "MaleWizard",
-            5 => "FemaleElf",
-            6 => "FemaleKnight",
-            7 => "FemaleLizard",
-            _ => "FemaleWizard",
-        };
-
+// the buttons are laid out from the top left corner of the window, over their images
+fn heroes_buttons(root: &mut ChildSpawnerCommands, hero_choices: &[(Vec2, HeroChoice)]) {
+    for (position, hero_choice) in hero_choices.iter() {
         root.spawn((
             Button { ..default() },
             Node {
                 position_type: PositionType::Absolute,
-                left: Val::Px(button_positions[index][0]),
-                top: Val::Px(button_positions[index][1]),
+                left: Val::Px(position.x + BUTTON_OFFSET.x),
+                top: Val::Px(BUTTON_OFFSET.y - position.y),
                 right: Val::Auto,
                 bottom: Val::Auto,
                 width: Val::Px(100.0),
@@ -409,8 +326,11 @@ fn heroes_buttons(root: &mut ChildSpawnerCommands) {
             },
             BackgroundColor(Color::NONE),
         ))
-        .insert(Name::new(component_name))
-        .insert(value.clone());
+        .insert(Name::new(format!(
+            "{}{:?}",
+            hero_choice.hero_class, hero_choice.gender
+        )))
+        .insert(ButtonComponent(hero_choice.clone()));
     }
 }
 
@@ -425,35 +345,10 @@ fn hero_select_handle(
             Interaction::None => animation_controller.run_animation = false,
             Interaction::Hovered => {
                 animation_controller.run_animation = true;
-                match button {
-                    ButtonComponent::MaleElf => {
-                        animation_controller.hero_image = HeroImageComponent::MaleElf
-                    }
-                    ButtonComponent::FemaleElf => {
-                        animation_controller.hero_image = HeroImageComponent::FemaleElf
-                    }
-                    ButtonComponent::MaleKnight => {
-                        animation_controller.hero_image = HeroImageComponent::MaleKnight
-                    }
-                    ButtonComponent::FemaleKnight => {
-                        animation_controller.hero_image = HeroImageComponent::FemaleKnight
-                    }
-                    ButtonComponent::MaleLizard => {
-                        animation_controller.hero_image = HeroImageComponent::MaleLizard
-                    }
-                    ButtonComponent::FemaleLizard => {
-                        animation_controller.hero_image = HeroImageComponent::FemaleLizard
-                    }
-                    ButtonComponent::MaleWizard => {
-                        animation_controller.hero_image = HeroImageComponent::MaleWizard
-                    }
-                    ButtonComponent::FemaleWizard => {
-                        animation_controller.hero_image = HeroImageComponent::FemaleWizard
-                    }
-                };
+                animation_controller.hero_image = Some(button.0.clone());
             }
             Interaction::Pressed => {
-                profile.set_hero(button.clone());
+                profile.set_hero(button.0.hero_class.clone(), button.0.gender.clone());
                 if profile.game_mode == GameMode::ClassicMode {
                     state.set(SceneState::PreClassicMode);
                 } else {
@@ -470,7 +365,9 @@ fn hero_image_animation_handle(
     mut animation_controller: ResMut<AnimationController>,
 ) {
     for (hero_image, mut sprite) in query.iter_mut() {
-        if animation_controller.run_animation && *hero_image == animation_controller.hero_image {
+        if animation_controller.run_animation
+            && animation_controller.hero_image.as_ref() == Some(&hero_image.0)
+        {
             animation_controller.timer.tick(time.delta());
             if animation_controller.timer.just_finished() {
                 let min_index = 0;
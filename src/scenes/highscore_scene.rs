@@ -10,7 +10,6 @@ use crate::materials::font::FontMaterials;
 use crate::materials::scenes::ScenesMaterials;
 use crate::resources::dictionary::Dictionary;
 use crate::resources::hero::gender::Gender;
-use crate::resources::hero::hero_class::HeroClass;
 use crate::resources::language::Language;
 use crate::resources::tile_size::TileSize;
 use crate::resources::{game_mode::GameMode, stored_profile::StoredProfile};
@@ -400,24 +399,10 @@ fn hero_image_handle_system(
         let highscore_book = highscore_book_query.single_mut().unwrap();
         if highscore_book.current_page != -1 && highscore_book.animation_indexes.is_empty() {
             let index = highscore_book.current_page as usize;
-            ui_image.image = match highscore_book.profiles[index].hero_class {
-                HeroClass::Elf => match highscore_book.profiles[index].gender {
-                    Gender::Male => scenes_materials.heroes_materials.male_elf.clone(),
-                    Gender::Female => scenes_materials.heroes_materials.female_elf.clone(),
-                },
-                HeroClass::Knight => match highscore_book.profiles[index].gender {
-                    Gender::Male => scenes_materials.heroes_materials.male_knight.clone(),
-                    Gender::Female => scenes_materials.heroes_materials.female_knight.clone(),
-                },
-                HeroClass::Lizard => match highscore_book.profiles[index].gender {
-                    Gender::Male => scenes_materials.heroes_materials.male_lizard.clone(),
-                    Gender::Female => scenes_materials.heroes_materials.female_lizard.clone(),
-                },
-                HeroClass::Wizard => match highscore_book.profiles[index].gender {
-                    Gender::Male => scenes_materials.heroes_materials.male_wizard.clone(),
-                    Gender::Female => scenes_materials.heroes_materials.female_wizard.clone(),
-                },
-            };
+            let profile = &highscore_book.profiles[index];
+            ui_image.image = scenes_materials
+                .heroes_materials
+                .get_texture(&profile.hero_class, &profile.gender);
             *visibility = Visibility::Visible;
         } else {
             *visibility = Visibility::Hidden;
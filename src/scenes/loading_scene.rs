@@ -18,9 +18,9 @@ use crate::materials::potions::PotionsMaterials;
 use crate::materials::scenes::ScenesMaterials;
 use crate::materials::weapons::WeaponsMaterials;
 use crate::resources::dictionary::Dictionary;
-use crate::resources::dungeon::rooms::Rooms;
 use crate::resources::game_data::GameData;
 use crate::resources::language::Language;
+use crate::resources::mod_packs::ModPacks;
 
 const LOADING_TEXT_FONT_SIZE: f32 = 30.0;
 const TEXT_FONT_SIZE: f32 = 40.0;
@@ -43,10 +43,18 @@ pub struct LoadingScenePlugin;
 
 impl Plugin for LoadingScenePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(SceneState::LoadingScene), setup);
+        // the text is shown once the glossaries are loaded, the content plugin loads
+        // the rest meanwhile
         app.add_systems(
             Update,
-            (load_materials, load_data, update_loader).run_if(in_state(SceneState::LoadingScene)),
+            (
+                setup.run_if(
+                    resource_exists::<Dictionary>.and(not(resource_exists::<LoadingSceneData>)),
+                ),
+                (load_materials, update_loader).run_if(resource_exists::<LoadingSceneData>),
+            )
+                .chain()
+                .run_if(in_state(SceneState::LoadingScene)),
         );
         app.add_systems(OnExit(SceneState::LoadingScene), cleanup);
     }
@@ -195,9 +203,11 @@ fn loading_text(
     });
 }
 
+// the bar waits full until the game data is in
 fn update_loader(
     mut query: Query<(&mut LoaderComponent, &mut Node, &Children)>,
     mut state: ResMut<NextState<SceneState>>,
+    game_data: Option<Res<GameData>>,
     text_query: Query<Entity>,
     mut writer: TextUiWriter,
 ) {
@@ -211,142 +221,125 @@ fn update_loader(
                 let entity = text_query.get(children[0]).unwrap();
                 *writer.text(entity, 0) = value.to_string() + "%";
             }
-        } else {
+        } else if game_data.is_some() {
             state.set(SceneState::MainMenuScene);
         }
     }
 }
 
-fn load_materials(mut commands: Commands, asset_server: Res<AssetServer>) {
+// the heroes, weapons and monsters are loaded from the sprite paths in the game data
+fn load_materials(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mod_packs: Res<ModPacks>,
+    game_data: Res<GameData>,
+) {
     let font_materials: FontMaterials = FontMaterials {
-        roboto_font: asset_server.load(ROBOTO_FONT),
-        fibberish_font: asset_server.load(FIBBERISH_FONT),
+        roboto_font: mod_packs.load(&asset_server, ROBOTO_FONT),
+        fibberish_font: mod_packs.load(&asset_server, FIBBERISH_FONT),
     };
 
     let scenes_materials: ScenesMaterials = ScenesMaterials {
-        main_background_image: asset_server.load(MAIN_MENU_BACKGROUND_IMAGE),
-        sub_background_image: asset_server.load(SUB_MENU_BACKGROUND_IMAGE),
+        main_background_image: mod_packs.load(&asset_server, MAIN_MENU_BACKGROUND_IMAGE),
+        sub_background_image: mod_packs.load(&asset_server, SUB_MENU_BACKGROUND_IMAGE),
         menu_box_materials: MenuBoxMaterials {
-            top_right: asset_server.load("scenes/gui/menu_box/top_right.png"),
-            top_center: asset_server.load("scenes/gui/menu_box/top_center.png"),
-            top_left: asset_server.load("scenes/gui/menu_box/top_left.png"),
-            mid_right: asset_server.load("scenes/gui/menu_box/mid_right.png"),
-            mid_center: asset_server.load("scenes/gui/menu_box/mid_center.png"),
-            mid_left: asset_server.load("scenes/gui/menu_box/mid_left.png"),
-            bottom_right: asset_server.load("scenes/gui/menu_box/bottom_right.png"),
-            bottom_center: asset_server.load("scenes/gui/menu_box/bottom_center.png"),
-            bottom_left: asset_server.load("scenes/gui/menu_box/bottom_left.png"),
+            top_right: mod_packs.load(&asset_server, "scenes/gui/menu_box/top_right.png"),
+            top_center: mod_packs.load(&asset_server, "scenes/gui/menu_box/top_center.png"),
+            top_left: mod_packs.load(&asset_server, "scenes/gui/menu_box/top_left.png"),
+            mid_right: mod_packs.load(&asset_server, "scenes/gui/menu_box/mid_right.png"),
+            mid_center: mod_packs.load(&asset_server, "scenes/gui/menu_box/mid_center.png"),
+            mid_left: mod_packs.load(&asset_server, "scenes/gui/menu_box/mid_left.png"),
+            bottom_right: mod_packs.load(&asset_server, "scenes/gui/menu_box/bottom_right.png"),
+            bottom_center: mod_packs.load(&asset_server, "scenes/gui/menu_box/bottom_center.png"),
+            bottom_left: mod_packs.load(&asset_server, "scenes/gui/menu_box/bottom_left.png"),
         },
         icon_materials: IconMaterials {
-            home_icon_normal: asset_server.load("icons/home_icon_normal.png"),
-            home_icon_hovered: asset_server.load("icons/home_icon_hovered.png"),
-            home_icon_clicked: asset_server.load("icons/home_icon_clicked.png"),
-            music_icon_on: asset_server.load("icons/music_icon_on.png"),
-            music_icon_off: asset_server.load("icons/music_icon_off.png"),
-            music_icon_hovered: asset_server.load("icons/music_icon_hovered.png"),
-            sound_icon_on: asset_server.load("icons/sound_icon_on.png"),
-            sound_icon_off: asset_server.load("icons/sound_icon_off.png"),
-            sound_icon_hovered: asset_server.load("icons/sound_icon_hovered.png"),
-            leaderboard: asset_server.load("icons/leaderboard.png"),
-            leaderboard_hovered: asset_server.load("icons/leaderboard_hovered.png"),
-            restart: asset_server.load("icons/restart.png"),
-            restart_hovered: asset_server.load("icons/restart_hovered.png"),
-        },
-        book_tileset: asset_server.load("scenes/book.png"),
-        heroes_materials: HeroesMaterials {
-            male_elf: asset_server.load("scenes/heroes/male_elf.png"),
-            male_knight: asset_server.load("scenes/heroes/male_knight.png"),
-            male_wizard: asset_server.load("scenes/heroes/male_wizard.png"),
-            male_lizard: asset_server.load("scenes/heroes/male_lizard.png"),
-            female_elf: asset_server.load("scenes/heroes/female_elf.png"),
-            female_knight: asset_server.load("scenes/heroes/female_knight.png"),
-            female_wizard: asset_server.load("scenes/heroes/female_wizard.png"),
-            female_lizard: asset_server.load("scenes/heroes/female_lizard.png"),
+            home_icon_normal: mod_packs.load(&asset_server, "icons/home_icon_normal.png"),
+            home_icon_hovered: mod_packs.load(&asset_server, "icons/home_icon_hovered.png"),
+            home_icon_clicked: mod_packs.load(&asset_server, "icons/home_icon_clicked.png"),
+            music_icon_on: mod_packs.load(&asset_server, "icons/music_icon_on.png"),
+            music_icon_off: mod_packs.load(&asset_server, "icons/music_icon_off.png"),
+            music_icon_hovered: mod_packs.load(&asset_server, "icons/music_icon_hovered.png"),
+            sound_icon_on: mod_packs.load(&asset_server, "icons/sound_icon_on.png"),
+            sound_icon_off: mod_packs.load(&asset_server, "icons/sound_icon_off.png"),
+            sound_icon_hovered: mod_packs.load(&asset_server, "icons/sound_icon_hovered.png"),
+            leaderboard: mod_packs.load(&asset_server, "icons/leaderboard.png"),
+            leaderboard_hovered: mod_packs.load(&asset_server, "icons/leaderboard_hovered.png"),
+            restart: mod_packs.load(&asset_server, "icons/restart.png"),
+            restart_hovered: mod_packs.load(&asset_server, "icons/restart_hovered.png"),
         },
+        book_tileset: mod_packs.load(&asset_server, "scenes/book.png"),
+        heroes_materials: HeroesMaterials::load(
+            &game_data,
+            |hero| &hero.portraits,
+            &mod_packs,
+            &asset_server,
+        ),
         flag_materials: FlagMaterials {
-            vietnam: asset_server.load("scenes/vietnam.png"),
-            united_states: asset_server.load("scenes/united_states.png"),
+            vietnam: mod_packs.load(&asset_server, "scenes/vietnam.png"),
+            united_states: mod_packs.load(&asset_server, "scenes/united_states.png"),
         },
     };
 
     let ingame_materials: InGameMaterials = InGameMaterials {
-        heroes_materials: HeroesMaterials {
-            male_elf: asset_server.load("ingame/heroes/male_elf.png"),
-            male_knight: asset_server.load("ingame/heroes/male_knight.png"),
-            male_wizard: asset_server.load("ingame/heroes/male_wizard.png"),
-            male_lizard: asset_server.load("ingame/heroes/male_lizard.png"),
-            female_elf: asset_server.load("ingame/heroes/female_elf.png"),
-            female_knight: asset_server.load("ingame/heroes/female_knight.png"),
-            female_wizard: asset_server.load("ingame/heroes/female_wizard.png"),
-            female_lizard: asset_server.load("ingame/heroes/female_lizard.png"),
-        },
-        weapons_materials: WeaponsMaterials {
-            bow: asset_server.load("ingame/weapons/bow.png"),
-            short_sword: asset_server.load("ingame/weapons/short_sword.png"),
-            sword: asset_server.load("ingame/weapons/sword.png"),
-            machete: asset_server.load("ingame/weapons/machete.png"),
-            small_hammer: asset_server.load("ingame/weapons/small_hammer.png"),
-            mace: asset_server.load("ingame/weapons/mace.png"),
-            big_hammer: asset_server.load("ingame/weapons/big_hammer.png"),
-            small_wand: asset_server.load("ingame/weapons/small_wand.png"),
-            magic_wand: asset_server.load("ingame/weapons/magic_wand.png"),
-            magic_sword: asset_server.load("ingame/weapons/magic_sword.png"),
-            spear: asset_server.load("ingame/weapons/spear.png"),
-        },
+        heroes_materials: HeroesMaterials::load(
+            &game_data,
+            |hero| &hero.sprites,
+            &mod_packs,
+            &asset_server,
+        ),
+        weapons_materials: WeaponsMaterials::load(&game_data, &mod_packs, &asset_server),
         bullet_materials: BulletsMaterials {
-            arrow: asset_server.load("ingame/bullets/arrow.png"),
-            bullet: asset_server.load("ingame/bullets/bullet.png"),
+            bullet: mod_packs.load(&asset_server, "ingame/bullets/bullet.png"),
         },
         dungeon_materials: DungeonMaterials {
-            floor: asset_server.load("ingame/dungeon/floor.png"),
-            ladder: asset_server.load("ingame/dungeon/ladder.png"),
-            wall: asset_server.load("ingame/dungeon/wall.png"),
-            treasure: asset_server.load("ingame/dungeon/treasure.png"),
-            wall_border_mid: asset_server.load("ingame/dungeon/wall_border_mid.png"),
-            wall_border_corner_top_left: asset_server
-                .load("ingame/dungeon/wall_border_corner_top_left.png"),
-            wall_border_corner_top_right: asset_server
-                .load("ingame/dungeon/wall_border_corner_top_right.png"),
-            wall_border_corner_bottom_left: asset_server
-                .load("ingame/dungeon/wall_border_corner_bottom_left.png"),
-            wall_border_corner_bottom_right: asset_server
-                .load("ingame/dungeon/wall_border_corner_bottom_right.png"),
-            wall_border_left: asset_server.load("ingame/dungeon/wall_border_left.png"),
-            wall_border_right: asset_server.load("ingame/dungeon/wall_border_right.png"),
-            wall_border_corner_left: asset_server
-                .load("ingame/dungeon/wall_border_corner_left.png"),
-            wall_border_corner_right: asset_server
-                .load("ingame/dungeon/wall_border_corner_right.png"),
-            wall_left: asset_server.load("ingame/dungeon/wall_left.png"),
-            wall_right: asset_server.load("ingame/dungeon/wall_right.png"),
-            door_opened: asset_server.load("ingame/dungeon/door_opened.png"),
-            door_closed: asset_server.load("ingame/dungeon/door_closed.png"),
-            door_top_part: asset_server.load("ingame/dungeon/door_top_part.png"),
-            door_left_part: asset_server.load("ingame/dungeon/door_left_part.png"),
-            door_right_part: asset_server.load("ingame/dungeon/door_right_part.png"),
+            floor: mod_packs.load(&asset_server, "ingame/dungeon/floor.png"),
+            ladder: mod_packs.load(&asset_server, "ingame/dungeon/ladder.png"),
+            wall: mod_packs.load(&asset_server, "ingame/dungeon/wall.png"),
+            treasure: mod_packs.load(&asset_server, "ingame/dungeon/treasure.png"),
+            wall_border_mid: mod_packs.load(&asset_server, "ingame/dungeon/wall_border_mid.png"),
+            wall_border_corner_top_left: mod_packs.load(
+                &asset_server,
+                "ingame/dungeon/wall_border_corner_top_left.png",
+            ),
+            wall_border_corner_top_right: mod_packs.load(
+                &asset_server,
+                "ingame/dungeon/wall_border_corner_top_right.png",
+            ),
+            wall_border_corner_bottom_left: mod_packs.load(
+                &asset_server,
+                "ingame/dungeon/wall_border_corner_bottom_left.png",
+            ),
+            wall_border_corner_bottom_right: mod_packs.load(
+                &asset_server,
+                "ingame/dungeon/wall_border_corner_bottom_right.png",
+            ),
+            wall_border_left: mod_packs.load(&asset_server, "ingame/dungeon/wall_border_left.png"),
+            wall_border_right: mod_packs
+                .load(&asset_server, "ingame/dungeon/wall_border_right.png"),
+            wall_border_corner_left: mod_packs
+                .load(&asset_server, "ingame/dungeon/wall_border_corner_left.png"),
+            wall_border_corner_right: mod_packs
+                .load(&asset_server, "ingame/dungeon/wall_border_corner_right.png"),
+            wall_left: mod_packs.load(&asset_server, "ingame/dungeon/wall_left.png"),
+            wall_right: mod_packs.load(&asset_server, "ingame/dungeon/wall_right.png"),
+            door_opened: mod_packs.load(&asset_server, "ingame/dungeon/door_opened.png"),
+            door_closed: mod_packs.load(&asset_server, "ingame/dungeon/door_closed.png"),
+            door_top_part: mod_packs.load(&asset_server, "ingame/dungeon/door_top_part.png"),
+            door_left_part: mod_packs.load(&asset_server, "ingame/dungeon/door_left_part.png"),
+            door_right_part: mod_packs.load(&asset_server, "ingame/dungeon/door_right_part.png"),
         },
         hearts_materials: HeartsMaterials {
-            full_heart: asset_server.load("ingame/hearts/full_heart.png"),
-            half_heart: asset_server.load("ingame/hearts/half_heart.png"),
-            empty_heart: asset_server.load("ingame/hearts/empty_heart.png"),
-        },
-        monsters_materials: MonstersMaterials {
-            small_zombie: asset_server.load("ingame/monsters/small_zombie.png"),
-            zombie: asset_server.load("ingame/monsters/zombie.png"),
-            big_zombie: asset_server.load("ingame/monsters/big_zombie.png"),
-            goblin: asset_server.load("ingame/monsters/goblin.png"),
-            orc: asset_server.load("ingame/monsters/orc.png"),
-            ogre: asset_server.load("ingame/monsters/ogre.png"),
-            imp: asset_server.load("ingame/monsters/imp.png"),
-            chort: asset_server.load("ingame/monsters/chort.png"),
-            big_demon: asset_server.load("ingame/monsters/big_demon.png"),
-            swampy: asset_server.load("ingame/monsters/swampy.png"),
+            full_heart: mod_packs.load(&asset_server, "ingame/hearts/full_heart.png"),
+            half_heart: mod_packs.load(&asset_server, "ingame/hearts/half_heart.png"),
+            empty_heart: mod_packs.load(&asset_server, "ingame/hearts/empty_heart.png"),
         },
+        monsters_materials: MonstersMaterials::load(&game_data, &mod_packs, &asset_server),
         potions_materials: PotionsMaterials {
-            heal: asset_server.load("ingame/potions/heal.png"),
-            focus: asset_server.load("ingame/potions/focus.png"),
-            speed_up: asset_server.load("ingame/potions/speed_up.png"),
-            evasion_up: asset_server.load("ingame/potions/evasion_up.png"),
+            heal: mod_packs.load(&asset_server, "ingame/potions/heal.png"),
+            focus: mod_packs.load(&asset_server, "ingame/potions/focus.png"),
+            speed_up: mod_packs.load(&asset_server, "ingame/potions/speed_up.png"),
+            evasion_up: mod_packs.load(&asset_server, "ingame/potions/evasion_up.png"),
         },
     };
 
@@ -354,8 +347,3 @@ fn load_materials(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands.insert_resource(scenes_materials);
     commands.insert_resource(ingame_materials);
 }
-
-fn load_data(mut commands: Commands) {
-    commands.insert_resource(GameData::new());
-    commands.insert_resource(Rooms::new());
-}
@@ -2,28 +2,26 @@ use crate::materials::font::FontMaterials;
 use crate::materials::menu_box::MenuBoxMaterials;
 use crate::materials::scenes::ScenesMaterials;
 use crate::resources::dictionary::Dictionary;
+use crate::resources::saved_run::SavedRun;
+use crate::resources::saved_survival_run::SavedSurvivalRun;
 use crate::scenes::SceneState;
 use bevy::app::AppExit;
 use bevy::color::palettes::basic::GRAY;
 use bevy::color::palettes::css::RED;
 use bevy::prelude::*;
-use std::slice::Iter;
-
-const MAIN_MENU_BOX_ARRAY: [[i8; 5]; 8] = [
-    [0, 1, 1, 1, 2],
-    [3, 4, 4, 4, 5],
-    [3, 4, 4, 4, 5],
-    [3, 4, 4, 4, 5],
-    [3, 4, 4, 4, 5],
-    [3, 4, 4, 4, 5],
-    [3, 4, 4, 4, 5],
-    [6, 7, 7, 7, 8],
-];
+
+const MAIN_MENU_BOX_TOP_ROW: [i8; 5] = [0, 1, 1, 1, 2];
+const MAIN_MENU_BOX_MID_ROW: [i8; 5] = [3, 4, 4, 4, 5];
+const MAIN_MENU_BOX_BOTTOM_ROW: [i8; 5] = [6, 7, 7, 7, 8];
 const FONT_SIZE: f32 = 36.0;
 const MAIN_MENU_BOX_TILE_SIZE: f32 = 50.0;
+const MAIN_MENU_BOX_TOP: f32 = 150.0;
+const DEFAULT_TOTAL_BUTTONS: usize = 6;
 
 #[derive(Component, Copy, Clone)]
 enum ButtonComponent {
+    Continue,
+    ContinueSurvival,
     Play,
     Highscore,
     Options,
@@ -33,16 +31,25 @@ enum ButtonComponent {
 }
 
 impl ButtonComponent {
-    pub fn iterator() -> Iter<'static, ButtonComponent> {
-        [
+    // continue entries are only offered when there is a saved run to resume
+    pub fn buttons() -> Vec<ButtonComponent> {
+        let mut buttons = Vec::new();
+        if SavedRun::exists() {
+            buttons.push(ButtonComponent::Continue);
+        }
+        if SavedSurvivalRun::exists() {
+            buttons.push(ButtonComponent::ContinueSurvival);
+        }
+
+        buttons.extend([
             ButtonComponent::Play,
             ButtonComponent::Highscore,
             ButtonComponent::Options,
             ButtonComponent::Help,
             ButtonComponent::Credits,
             ButtonComponent::Quit,
-        ]
-        .iter()
+        ]);
+        buttons
     }
 }
 
@@ -70,6 +77,8 @@ fn setup(
     mut commands: Commands,
     font_materials: Res<FontMaterials>,
 ) {
+    let buttons_list = ButtonComponent::buttons();
+
     let user_interface_root = commands
         .spawn((
             Node {
@@ -81,8 +90,12 @@ fn setup(
             ImageNode::new(scenes_materials.main_background_image.clone()),
         ))
         .with_children(|parent| {
-            main_menu_box(parent, &scenes_materials.menu_box_materials);
-            buttons(parent, &font_materials, dictionary);
+            main_menu_box(
+                parent,
+                &scenes_materials.menu_box_materials,
+                buttons_list.len(),
+            );
+            buttons(parent, &font_materials, dictionary, &buttons_list);
         })
         .id();
 
@@ -97,8 +110,23 @@ fn cleanup(mut commands: Commands, main_menu_scene_data: Res<MainMenuSceneData>)
         .despawn();
 }
 
-fn main_menu_box(root: &mut ChildSpawnerCommands, menu_box_materials: &MenuBoxMaterials) {
-    for (row_index, row) in MAIN_MENU_BOX_ARRAY.iter().enumerate() {
+// the box grows upwards when the continue button is shown so it still fits the window
+fn main_menu_box_top(total_buttons: usize) -> f32 {
+    MAIN_MENU_BOX_TOP
+        - MAIN_MENU_BOX_TILE_SIZE * total_buttons.saturating_sub(DEFAULT_TOTAL_BUTTONS) as f32
+}
+
+fn main_menu_box(
+    root: &mut ChildSpawnerCommands,
+    menu_box_materials: &MenuBoxMaterials,
+    total_buttons: usize,
+) {
+    let mut main_menu_box_array = vec![MAIN_MENU_BOX_TOP_ROW];
+    main_menu_box_array.extend(vec![MAIN_MENU_BOX_MID_ROW; total_buttons]);
+    main_menu_box_array.push(MAIN_MENU_BOX_BOTTOM_ROW);
+    let start_top = main_menu_box_top(total_buttons);
+
+    for (row_index, row) in main_menu_box_array.iter().enumerate() {
         for (column_index, value) in row.iter().enumerate() {
             let image: Handle<Image> = match value {
                 0 => menu_box_materials.top_left.clone(),
@@ -118,7 +146,7 @@ fn main_menu_box(root: &mut ChildSpawnerCommands, menu_box_materials: &MenuBoxMa
                 Node {
                     position_type: PositionType::Absolute,
                     left: Val::Px(10.0 + MAIN_MENU_BOX_TILE_SIZE * column_index as f32),
-                    top: Val::Px(150.0 + MAIN_MENU_BOX_TILE_SIZE * row_index as f32),
+                    top: Val::Px(start_top + MAIN_MENU_BOX_TILE_SIZE * row_index as f32),
                     bottom: Val::Auto,
                     right: Val::Auto,
                     width: Val::Px(MAIN_MENU_BOX_TILE_SIZE),
@@ -130,10 +158,16 @@ fn main_menu_box(root: &mut ChildSpawnerCommands, menu_box_materials: &MenuBoxMa
     }
 }
 
-fn buttons(root: &mut ChildSpawnerCommands, materials: &Res<FontMaterials>, dictionary: Res<Dictionary>) {
+fn buttons(
+    root: &mut ChildSpawnerCommands,
+    materials: &Res<FontMaterials>,
+    dictionary: Res<Dictionary>,
+    buttons_list: &[ButtonComponent],
+) {
     let glossary = dictionary.get_glossary();
+    let start_top = main_menu_box_top(buttons_list.len());
 
-    for (index, button) in ButtonComponent::iterator().enumerate() {
+    for (index, button) in buttons_list.iter().enumerate() {
         root.spawn((
             Button { ..default() },
             Node {
@@ -145,7 +179,7 @@ fn buttons(root: &mut ChildSpawnerCommands, materials: &Res<FontMaterials>, dict
                 align_self: AlignSelf::FlexEnd,
                 left: Val::Px(10.0 + MAIN_MENU_BOX_TILE_SIZE * (3.0 - 1.0) / 2.0),
                 right: Val::Auto,
-                top: Val::Px(150.0 + MAIN_MENU_BOX_TILE_SIZE * (index as f32 + 1.0)),
+                top: Val::Px(start_top + MAIN_MENU_BOX_TILE_SIZE * (index as f32 + 1.0)),
                 bottom: Val::Auto,
                 ..Default::default()
             },
@@ -153,6 +187,10 @@ fn buttons(root: &mut ChildSpawnerCommands, materials: &Res<FontMaterials>, dict
         ))
         .with_children(|parent| {
             let text: &str = match button {
+                ButtonComponent::Continue => glossary.shared_text.continue_.as_str(),
+                ButtonComponent::ContinueSurvival => {
+                    glossary.main_menu_scene_text.continue_survival.as_str()
+                }
                 ButtonComponent::Play => glossary.main_menu_scene_text.play.as_str(),
                 ButtonComponent::Highscore => glossary.main_menu_scene_text.highscore.as_str(),
                 ButtonComponent::Options => glossary.main_menu_scene_text.options.as_str(),
@@ -185,6 +223,7 @@ fn button_handle_system(
     mut state: ResMut<NextState<SceneState>>,
     mut exit: EventWriter<AppExit>,
     mut writer: TextUiWriter,
+    mut commands: Commands,
 ) {
     for (interaction, button, children) in button_query.iter_mut() {
         let entity = text_query.get(children[0]).unwrap();
@@ -194,6 +233,25 @@ fn button_handle_system(
             Interaction::Pressed => {
                 *writer.color(entity, 0) = TextColor::from(RED);
                 match button {
+                    ButtonComponent::Continue => {
+                        // the save is consumed so a run can only be resumed once
+                        if let Some(saved_run) = SavedRun::load() {
+                            commands.insert_resource(saved_run.resumed_profile());
+                            commands.insert_resource(saved_run.player.clone());
+                            commands.insert_resource(saved_run);
+                            state.set(SceneState::PreClassicMode);
+                        }
+                        SavedRun::delete();
+                    }
+                    ButtonComponent::ContinueSurvival => {
+                        if let Some(saved_survival_run) = SavedSurvivalRun::load() {
+                            commands.insert_resource(saved_survival_run.resumed_profile());
+                            commands.insert_resource(saved_survival_run.player.clone());
+                            commands.insert_resource(saved_survival_run);
+                            state.set(SceneState::PreSurvivalMode);
+                        }
+                        SavedSurvivalRun::delete();
+                    }
                     ButtonComponent::Play => state.set(SceneState::GameModeSelectScene),
                     ButtonComponent::Highscore => state.set(SceneState::HighscoreScene),
                     ButtonComponent::Options => state.set(SceneState::OptionsScene),
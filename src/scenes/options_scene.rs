@@ -6,7 +6,9 @@ use crate::materials::font::FontMaterials;
 use crate::materials::menu_box::MenuBoxMaterials;
 use crate::materials::scenes::ScenesMaterials;
 use crate::resources::dictionary::Dictionary;
+use crate::resources::glossary::Glossary;
 use crate::resources::language::Language;
+use crate::resources::mod_packs::ModPacks;
 use crate::resources::setting::Setting;
 use crate::scenes::SceneState;
 
@@ -16,17 +18,21 @@ const NORMAL_BUTTON_SIZE: f32 = 32.0;
 const MENU_BOX_TILE_SIZE: f32 = 60.0;
 
 const MENU_BOX_WIDTH_TILES: f32 = 8.0;
-const MENU_BOX_HEIGHT_TILES: f32 = 6.0;
+const MENU_BOX_HEIGHT_TILES: f32 = 8.0;
 
-const MENU_BOX_ARRAY: [[i8; 8]; 6] = [
+const MENU_BOX_ARRAY: [[i8; 8]; 8] = [
     [0, 1, 1, 1, 1, 1, 1, 2],
     [3, 4, 4, 4, 4, 4, 4, 5],
     [3, 4, 4, 4, 4, 4, 4, 5],
     [3, 4, 4, 4, 4, 4, 4, 5],
     [3, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 5],
     [6, 7, 7, 7, 7, 7, 7, 8],
 ];
 
+const VOLUME_STEP: f32 = 0.1;
+
 const SELECTED_FLAG_COLOR: Srgba = Srgba {
     red: (160.0 / 255.0),
     green: (170.0 / 255.0),
@@ -75,12 +81,46 @@ impl ButtonComponent {
     }
 }
 
+#[derive(Component, Copy, Clone)]
+enum VolumeButtonComponent {
+    SoundDown,
+    SoundUp,
+    MusicDown,
+    MusicUp,
+}
+
+impl VolumeButtonComponent {
+    pub fn iterator() -> Iter<'static, VolumeButtonComponent> {
+        [
+            VolumeButtonComponent::SoundDown,
+            VolumeButtonComponent::SoundUp,
+            VolumeButtonComponent::MusicDown,
+            VolumeButtonComponent::MusicUp,
+        ]
+        .iter()
+    }
+}
+
+#[derive(Component, Copy, Clone)]
+enum VolumeTextComponent {
+    Sound,
+    Music,
+}
+
+impl VolumeTextComponent {
+    pub fn iterator() -> Iter<'static, VolumeTextComponent> {
+        [VolumeTextComponent::Sound, VolumeTextComponent::Music].iter()
+    }
+}
+
 #[derive(Component, Clone)]
 enum TextComponent {
     Options,
     EnableSound,
     EnableMusic,
     Language,
+    SoundVolume,
+    MusicVolume,
 }
 
 impl TextComponent {
@@ -90,11 +130,17 @@ impl TextComponent {
             TextComponent::EnableSound,
             TextComponent::EnableMusic,
             TextComponent::Language,
+            TextComponent::SoundVolume,
+            TextComponent::MusicVolume,
         ]
         .iter()
     }
 }
 
+// lists the mod packs that were loaded at startup
+#[derive(Component)]
+struct ModsTextComponent;
+
 pub struct OptionsScenePlugin;
 
 #[derive(Resource)]
@@ -110,6 +156,7 @@ impl Plugin for OptionsScenePlugin {
             (
                 button_handle_system,
                 pair_button_handle_system,
+                volume_button_handle_system,
                 text_handle_system,
             )
                 .run_if(in_state(SceneState::OptionsScene)),
@@ -124,6 +171,7 @@ fn setup(
     scenes_materials: Res<ScenesMaterials>,
     setting: Res<Setting>,
     dictionary: Res<Dictionary>,
+    mod_packs: Res<ModPacks>,
 ) {
     // user interface root
     let user_interface_root = commands
@@ -140,6 +188,8 @@ fn setup(
             texts(parent, &font_materials, &dictionary);
             buttons(parent, &setting, &scenes_materials);
             pair_buttons(parent, &setting, &scenes_materials);
+            volume_buttons(parent, &setting, &font_materials, &dictionary);
+            mods_text(parent, &font_materials, &dictionary, &mod_packs);
         })
         .id();
     commands.insert_resource(OptionsSceneData {
@@ -204,11 +254,13 @@ fn texts(root: &mut ChildSpawnerCommands, font_materials: &FontMaterials, dictio
     let font = font_materials.get_font(dictionary.get_current_language());
     let glossary = dictionary.get_glossary();
 
-    let position_of_texts: [[f32; 2]; 4] = [
-        [440.0, 160.0],
-        [320.0, 230.0],
-        [320.0, 290.0],
-        [320.0, 350.0],
+    let position_of_texts: [[f32; 2]; 6] = [
+        [440.0, 130.0],
+        [320.0, 200.0],
+        [320.0, 260.0],
+        [320.0, 320.0],
+        [320.0, 380.0],
+        [320.0, 440.0],
     ];
 
     for (index, prevalue) in TextComponent::iterator().enumerate() {
@@ -217,6 +269,8 @@ fn texts(root: &mut ChildSpawnerCommands, font_materials: &FontMaterials, dictio
             1 => glossary.options_scene_text.enable_music.clone(),
             2 => glossary.options_scene_text.enable_sound.clone(),
             3 => glossary.options_scene_text.language.clone(),
+            4 => glossary.options_scene_text.sound_volume.clone(),
+            5 => glossary.options_scene_text.music_volume.clone(),
             _ => panic!("Unknown text"),
         };
 
@@ -225,6 +279,8 @@ fn texts(root: &mut ChildSpawnerCommands, font_materials: &FontMaterials, dictio
             1 => "EnableMusicText",
             2 => "EnableSoundText",
             3 => "LanguageText",
+            4 => "SoundVolumeText",
+            5 => "MusicVolumeText",
             _ => "Unknown text",
         };
 
@@ -254,6 +310,64 @@ fn texts(root: &mut ChildSpawnerCommands, font_materials: &FontMaterials, dictio
     }
 }
 
+fn mods_text(
+    root: &mut ChildSpawnerCommands,
+    font_materials: &FontMaterials,
+    dictionary: &Dictionary,
+    mod_packs: &ModPacks,
+) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let glossary = dictionary.get_glossary();
+
+    root.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(320.0),
+            top: Val::Px(485.0),
+            max_width: Val::Px(390.0),
+            ..Default::default()
+        },
+        Text::new(mods_text_value(&glossary, mod_packs)),
+        TextFont {
+            font: font.clone(),
+            font_size: 20.0,
+            ..Default::default()
+        },
+        TextColor(Color::BLACK),
+    ))
+    .insert(Name::new("ModsText"))
+    .insert(ModsTextComponent);
+}
+
+fn mods_text_value(glossary: &Glossary, mod_packs: &ModPacks) -> String {
+    let options_scene_text = &glossary.options_scene_text;
+
+    let names: Vec<&str> = mod_packs
+        .packs()
+        .iter()
+        .map(|pack| pack.name.as_str())
+        .collect();
+
+    let mut value = if names.is_empty() {
+        format!(
+            "{}: {}",
+            options_scene_text.mods, options_scene_text.no_mods
+        )
+    } else {
+        format!("{}: {}", options_scene_text.mods, names.join(", "))
+    };
+
+    if !mod_packs.problems().is_empty() {
+        value += &format!(
+            " ({} {})",
+            mod_packs.problems().len(),
+            options_scene_text.mod_problems
+        );
+    }
+
+    value
+}
+
 fn buttons(root: &mut ChildSpawnerCommands, setting: &Setting, scenes_materials: &ScenesMaterials) {
     let positions: [UiRect; 3] = [
         UiRect {
@@ -264,13 +378,13 @@ fn buttons(root: &mut ChildSpawnerCommands, setting: &Setting, scenes_materials:
         },
         UiRect {
             left: Val::Px(610.0),
-            top: Val::Px(230.0),
+            top: Val::Px(200.0),
             right: Val::Auto,
             bottom: Val::Auto,
         },
         UiRect {
             left: Val::Px(610.0),
-            top: Val::Px(290.0),
+            top: Val::Px(260.0),
             right: Val::Auto,
             bottom: Val::Auto,
         },
@@ -331,13 +445,13 @@ fn pair_buttons(root: &mut ChildSpawnerCommands, setting: &Setting, scenes_mater
     let positions: [UiRect; 2] = [
         UiRect {
             left: Val::Px(570.0),
-            top: Val::Px(350.0),
+            top: Val::Px(320.0),
             right: Val::Auto,
             bottom: Val::Auto,
         },
         UiRect {
             left: Val::Px(620.0),
-            top: Val::Px(350.0),
+            top: Val::Px(320.0),
             right: Val::Auto,
             bottom: Val::Auto,
         },
@@ -387,6 +501,82 @@ fn pair_buttons(root: &mut ChildSpawnerCommands, setting: &Setting, scenes_mater
     }
 }
 
+fn volume_buttons(
+    root: &mut ChildSpawnerCommands,
+    setting: &Setting,
+    font_materials: &FontMaterials,
+    dictionary: &Dictionary,
+) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+
+    for volume_button in VolumeButtonComponent::iterator() {
+        let (value, left_position, top_position, component_name) = match volume_button {
+            VolumeButtonComponent::SoundDown => ("-", 570.0, 380.0, "SoundVolumeDown"),
+            VolumeButtonComponent::SoundUp => ("+", 690.0, 380.0, "SoundVolumeUp"),
+            VolumeButtonComponent::MusicDown => ("-", 570.0, 440.0, "MusicVolumeDown"),
+            VolumeButtonComponent::MusicUp => ("+", 690.0, 440.0, "MusicVolumeUp"),
+        };
+
+        root.spawn((
+            Button,
+            Node {
+                left: Val::Px(left_position),
+                top: Val::Px(top_position),
+                width: Val::Px(NORMAL_BUTTON_SIZE),
+                height: Val::Px(NORMAL_BUTTON_SIZE),
+                justify_content: JustifyContent::Center,
+                position_type: PositionType::Absolute,
+                ..Default::default()
+            },
+            BackgroundColor(Color::NONE),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(value),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 35.0,
+                    ..Default::default()
+                },
+                TextColor(Color::BLACK),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+        })
+        .insert(Name::new(component_name))
+        .insert(*volume_button);
+    }
+
+    for volume_text_component in VolumeTextComponent::iterator() {
+        let (volume, top_position, component_name) = match volume_text_component {
+            VolumeTextComponent::Sound => (setting.get_sound_volume(), 380.0, "SoundVolume"),
+            VolumeTextComponent::Music => (setting.get_music_volume(), 440.0, "MusicVolume"),
+        };
+
+        root.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(610.0),
+                top: Val::Px(top_position),
+                ..Default::default()
+            },
+            Text::new(volume_text(volume)),
+            TextFont {
+                font: font.clone(),
+                font_size: 35.0,
+                ..Default::default()
+            },
+            TextColor(Color::BLACK),
+            TextLayout::new_with_justify(JustifyText::Center),
+        ))
+        .insert(Name::new(component_name))
+        .insert(*volume_text_component);
+    }
+}
+
+fn volume_text(volume: f32) -> String {
+    format!("{}%", (volume * 100.0).round())
+}
+
 fn button_handle_system(
     mut button_query: Query<
         (&Interaction, &ButtonComponent, &mut ImageNode),
@@ -481,10 +671,47 @@ fn pair_button_handle_system(
     }
 }
 
+fn volume_button_handle_system(
+    button_query: Query<(&Interaction, &VolumeButtonComponent), Changed<Interaction>>,
+    volume_text_query: Query<(&VolumeTextComponent, Entity)>,
+    mut setting: ResMut<Setting>,
+    mut writer: TextUiWriter,
+) {
+    for (interaction, button) in button_query.iter() {
+        if *interaction == Interaction::Pressed {
+            let sound_volume = setting.get_sound_volume();
+            let music_volume = setting.get_music_volume();
+            match *button {
+                VolumeButtonComponent::SoundDown => {
+                    setting.set_sound_volume(sound_volume - VOLUME_STEP)
+                }
+                VolumeButtonComponent::SoundUp => {
+                    setting.set_sound_volume(sound_volume + VOLUME_STEP)
+                }
+                VolumeButtonComponent::MusicDown => {
+                    setting.set_music_volume(music_volume - VOLUME_STEP)
+                }
+                VolumeButtonComponent::MusicUp => {
+                    setting.set_music_volume(music_volume + VOLUME_STEP)
+                }
+            }
+
+            for (volume_text_component, entity) in volume_text_query.iter() {
+                *writer.text(entity, 0) = match volume_text_component {
+                    VolumeTextComponent::Sound => volume_text(setting.get_sound_volume()),
+                    VolumeTextComponent::Music => volume_text(setting.get_music_volume()),
+                };
+            }
+        }
+    }
+}
+
 fn text_handle_system(
     mut text_query: Query<(&TextComponent, Entity)>,
+    mods_text_query: Query<Entity, With<ModsTextComponent>>,
     font_materials: Res<FontMaterials>,
     dictionary: Res<Dictionary>,
+    mod_packs: Res<ModPacks>,
     mut writer: TextUiWriter,
 ) {
     let font = font_materials.get_font(dictionary.get_current_language());
@@ -505,7 +732,18 @@ fn text_handle_system(
                 TextComponent::Language => {
                     *writer.text(entity, 0) = glossary.options_scene_text.language.clone();
                 }
+                TextComponent::SoundVolume => {
+                    *writer.text(entity, 0) = glossary.options_scene_text.sound_volume.clone();
+                }
+                TextComponent::MusicVolume => {
+                    *writer.text(entity, 0) = glossary.options_scene_text.music_volume.clone();
+                }
             }
         }
+
+        for entity in mods_text_query.iter() {
+            writer.font(entity, 0).font = font.clone();
+            *writer.text(entity, 0) = mods_text_value(&glossary, &mod_packs);
+        }
     }
 }
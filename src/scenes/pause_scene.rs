@@ -4,25 +4,24 @@ use crate::materials::menu_box::MenuBoxMaterials;
 use crate::materials::scenes::ScenesMaterials;
 use crate::resources::dictionary::Dictionary;
 use crate::resources::game_data::PauseSceneData;
+use crate::resources::game_mode::GameMode;
 use crate::resources::profile::Profile;
+use crate::resources::saved_survival_run::SavedSurvivalRun;
 use crate::scenes::SceneState;
 use bevy::color::palettes::css::GRAY;
 use bevy::prelude::*;
-use std::slice::Iter;
 
 const BOX_TILE_SIZE: f32 = 60.0;
 const BOX_WIDTH_TILES: f32 = 7.0;
-const BOX_HEIGHT_TILES: f32 = 3.0;
 
-const BOX_ARRAY: [[i8; 7]; 3] = [
-    [0, 1, 1, 1, 1, 1, 2],
-    [3, 4, 4, 4, 4, 4, 5],
-    [6, 7, 7, 7, 7, 7, 8],
-];
+const BOX_TOP_ROW: [i8; 7] = [0, 1, 1, 1, 1, 1, 2];
+const BOX_MID_ROW: [i8; 7] = [3, 4, 4, 4, 4, 4, 5];
+const BOX_BOTTOM_ROW: [i8; 7] = [6, 7, 7, 7, 7, 7, 8];
 
 #[derive(Component, Copy, Clone, PartialEq, Eq)]
 pub enum ButtonComponent {
     Continue,
+    SaveAndQuit,
     Quit,
 }
 
@@ -30,9 +29,27 @@ pub enum ButtonComponent {
 #[derive(Resource)]
 pub struct PauseSceneFlag;
 
+// asks the game mode to keep the run saved when leaving the in game scene
+#[derive(Resource)]
+pub struct SaveRunFlag;
+
 impl ButtonComponent {
-    pub fn iterator() -> Iter<'static, ButtonComponent> {
-        [ButtonComponent::Continue, ButtonComponent::Quit].iter()
+    // survival mode is only saved between waves, so there is nothing to keep before the first reward
+    pub fn buttons(game_mode: &GameMode) -> Vec<ButtonComponent> {
+        let can_save = match game_mode {
+            GameMode::ClassicMode => true,
+            GameMode::SurvivalMode => SavedSurvivalRun::exists(),
+        };
+
+        if can_save {
+            vec![
+                ButtonComponent::Continue,
+                ButtonComponent::SaveAndQuit,
+                ButtonComponent::Quit,
+            ]
+        } else {
+            vec![ButtonComponent::Continue, ButtonComponent::Quit]
+        }
     }
 }
 
@@ -43,10 +60,13 @@ pub fn pause(
     font_materials: Res<FontMaterials>,
     scenes_materials: Res<ScenesMaterials>,
     dictionary: Res<Dictionary>,
+    profile: Res<Profile>,
 ) {
     if keyboard_input.pressed(KeyCode::Escape) {
         keyboard_input.reset(KeyCode::Escape);
 
+        let buttons_list = ButtonComponent::buttons(&profile.game_mode);
+
         let user_interface_root = commands
             .spawn((
                 Node {
@@ -58,8 +78,12 @@ pub fn pause(
                 BackgroundColor(Color::NONE),
             ))
             .with_children(|parent| {
-                menu_box(parent, &scenes_materials.menu_box_materials);
-                buttons(parent, &font_materials, &dictionary);
+                menu_box(
+                    parent,
+                    &scenes_materials.menu_box_materials,
+                    buttons_list.len(),
+                );
+                buttons(parent, &font_materials, &dictionary, &buttons_list);
             })
             .insert(Name::new("PauseUI"))
             .id();
@@ -71,15 +95,28 @@ pub fn pause(
     }
 }
 
-fn menu_box(root: &mut ChildSpawnerCommands, menu_box_materials: &MenuBoxMaterials) {
+fn box_height_tiles(total_buttons: usize) -> usize {
+    total_buttons + 1
+}
+
+fn menu_box(
+    root: &mut ChildSpawnerCommands,
+    menu_box_materials: &MenuBoxMaterials,
+    total_buttons: usize,
+) {
+    let box_height_tiles = box_height_tiles(total_buttons);
     let start_left = (WINDOW_HEIGHT * RESOLUTION - BOX_TILE_SIZE * BOX_WIDTH_TILES) / 2.0;
-    let start_top = (WINDOW_HEIGHT - BOX_TILE_SIZE * BOX_HEIGHT_TILES) / 2.0;
+    let start_top = (WINDOW_HEIGHT - BOX_TILE_SIZE * box_height_tiles as f32) / 2.0;
+
+    let mut box_array = vec![BOX_TOP_ROW];
+    box_array.extend(vec![BOX_MID_ROW; box_height_tiles - 2]);
+    box_array.push(BOX_BOTTOM_ROW);
 
     root.spawn(Node {
         ..Default::default()
     })
     .with_children(|parent| {
-        for (row_index, row) in BOX_ARRAY.iter().enumerate() {
+        for (row_index, row) in box_array.iter().enumerate() {
             for (column_index, value) in row.iter().enumerate() {
                 let image: Handle<Image> = match value {
                     0 => menu_box_materials.top_left.clone(),
@@ -113,20 +150,26 @@ fn menu_box(root: &mut ChildSpawnerCommands, menu_box_materials: &MenuBoxMateria
     .insert(Name::new("MenuBox"));
 }
 
-fn buttons(root: &mut ChildSpawnerCommands, font_materials: &FontMaterials, dictionary: &Dictionary) {
+fn buttons(
+    root: &mut ChildSpawnerCommands,
+    font_materials: &FontMaterials,
+    dictionary: &Dictionary,
+    buttons_list: &[ButtonComponent],
+) {
     let font = font_materials.get_font(dictionary.get_current_language());
     let glossary = dictionary.get_glossary();
 
-    for button in ButtonComponent::iterator() {
+    let box_height_tiles = box_height_tiles(buttons_list.len());
+    let start_top = (WINDOW_HEIGHT - BOX_TILE_SIZE * box_height_tiles as f32) / 2.0;
+
+    for (index, button) in buttons_list.iter().enumerate() {
         let value = match *button {
             ButtonComponent::Continue => glossary.shared_text.continue_.clone(),
+            ButtonComponent::SaveAndQuit => glossary.shared_text.save_and_quit.clone(),
             ButtonComponent::Quit => glossary.shared_text.quit.clone(),
         };
 
-        let top_position = match *button {
-            ButtonComponent::Continue => 250.0,
-            ButtonComponent::Quit => 300.0,
-        };
+        let top_position = start_top + 52.0 + 50.0 * index as f32;
 
         root.spawn((
             Button { ..default() },
@@ -178,9 +221,16 @@ pub fn button_handle_system(
             Interaction::None => *writer.color(entity, 0) = TextColor::from(GRAY),
             Interaction::Hovered => *writer.color(entity, 0) = TextColor::BLACK,
             Interaction::Pressed => {
-                if *button == ButtonComponent::Quit {
-                    profile.is_run_finished = true;
-                    next_state.set(SceneState::MainMenuScene);
+                match *button {
+                    ButtonComponent::Continue => {}
+                    ButtonComponent::SaveAndQuit => {
+                        commands.insert_resource(SaveRunFlag);
+                        next_state.set(SceneState::MainMenuScene);
+                    }
+                    ButtonComponent::Quit => {
+                        profile.is_run_finished = true;
+                        next_state.set(SceneState::MainMenuScene);
+                    }
                 }
 
                 commands
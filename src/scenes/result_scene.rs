@@ -55,6 +55,7 @@ enum PrefixWordComponent {
     TotalKilledMonsters,
     TotalClearedRooms,
     TotalClearedWaves,
+    Seed,
 }
 
 impl PrefixWordComponent {
@@ -68,6 +69,7 @@ impl PrefixWordComponent {
             PrefixWordComponent::TotalKilledMonsters,
             PrefixWordComponent::TotalClearedWaves,
             PrefixWordComponent::TotalClearedRooms,
+            PrefixWordComponent::Seed,
         ]
         .iter()
     }
@@ -232,7 +234,7 @@ fn texts(
     })
     .with_children(|parent| {
         for (index, prefix) in PrefixWordComponent::iterator().enumerate() {
-            let top_position = 110.0 + (index as f32) * 40.0;
+            let top_position = 110.0 + (index as f32) * 36.0;
             let left_position = 300.0;
 
             let value: String = match prefix {
@@ -344,6 +346,11 @@ fn texts(
 
                     prefix + total_cleared_waves.to_string().as_str()
                 }
+                PrefixWordComponent::Seed => {
+                    let prefix = glossary.shared_text.seed.clone();
+
+                    prefix + profile.seed.to_string().as_str()
+                }
                 PrefixWordComponent::Playtime => {
                     let prefix = glossary.result_scene_text.playtime.clone();
 
@@ -393,6 +400,7 @@ fn texts(
                 PrefixWordComponent::TotalClearedRooms => "TotalClearedRooms",
                 PrefixWordComponent::TotalClearedWaves => "TotalClearedWaves",
                 PrefixWordComponent::Playtime => "Playtime",
+                PrefixWordComponent::Seed => "Seed",
             };
 
             parent
@@ -1,4 +1,4 @@
-use bevy::math::bounding::{Aabb2d, IntersectsVolume};
+use bevy::math::bounding::{Aabb2d, BoundingCircle, IntersectsVolume};
 use bevy::math::{Vec2, Vec3};
 
 pub fn collide(a_pos: Vec3, a_size: Vec2, b_pos: Vec3, b_size: Vec2) -> bool {
@@ -6,3 +6,20 @@ pub fn collide(a_pos: Vec3, a_size: Vec2, b_pos: Vec3, b_size: Vec2) -> bool {
         .intersects(&Aabb2d::new(b_pos.truncate().trunc(), b_size / 2.));
     collision
 }
+
+pub fn collide_circles(a_pos: Vec3, a_radius: f32, b_pos: Vec3, b_radius: f32) -> bool {
+    BoundingCircle::new(a_pos.truncate().trunc(), a_radius)
+        .intersects(&BoundingCircle::new(b_pos.truncate().trunc(), b_radius))
+}
+
+pub fn collide_circle_rectangle(
+    circle_pos: Vec3,
+    radius: f32,
+    rectangle_pos: Vec3,
+    rectangle_size: Vec2,
+) -> bool {
+    BoundingCircle::new(circle_pos.truncate().trunc(), radius).intersects(&Aabb2d::new(
+        rectangle_pos.truncate().trunc(),
+        rectangle_size / 2.,
+    ))
+}
@@ -0,0 +1,224 @@
+use bevy::prelude::*;
+use rand::Rng;
+use std::time::Duration;
+
+use crate::components::invinsible_cooldown::InvisibleCooldownComponent;
+use crate::components::monster::MonsterComponent;
+use crate::components::monster_list_effects::MonsterListEffectsComponent;
+use crate::components::player::PlayerComponent;
+use crate::components::player_animation::PlayerAnimation;
+use crate::components::player_list_effects::PlayerListEffectsComponent;
+use crate::components::weapon::WeaponComponent;
+use crate::resources::animation_state::AnimationState;
+use crate::resources::effect::effect_type::EffectType;
+use crate::resources::game_data::GameData;
+use crate::resources::hero::power::Power;
+
+pub const CRITICAL_DAMAGE_MULTIPLIER: f32 = 1.5;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DamageSource {
+    Bullet,
+    Swing,
+    Thunderstorm,
+    MonsterTouch,
+    MonsterBullet,
+    MonsterSlam,
+}
+
+// what the attacker brings to a single hit
+#[derive(Clone, Copy, Debug)]
+pub struct Attacker {
+    pub damage: f32,
+    pub damage_percent_bonus: f32,
+    pub critical_chance: f32,
+    pub effect: Option<EffectType>,
+    pub trigger_chance: f32,
+    pub effect_bonus: f32,
+}
+
+impl Attacker {
+    pub fn player(player: &PlayerComponent, damage: f32) -> Self {
+        Attacker {
+            damage,
+            damage_percent_bonus: player.damage_percent_bonus,
+            critical_chance: player.critical_chance,
+            effect: None,
+            trigger_chance: 0.0,
+            effect_bonus: 0.0,
+        }
+    }
+
+    // player stat plus weapon stat, picked by the hero power
+    pub fn player_with_weapon(player: &PlayerComponent, weapon: &WeaponComponent) -> Self {
+        let damage = if player.power == Power::Intelligence {
+            player.intelligence + weapon.intelligence
+        } else {
+            player.strength + weapon.strength
+        };
+
+        Attacker {
+            effect_bonus: weapon.effect_bonus.unwrap_or(0.0),
+            ..Attacker::player(player, damage)
+                .with_effect(weapon.debuff_effect, weapon.trigger_chance)
+        }
+    }
+
+    pub fn monster(damage: f32, damage_percent_bonus: f32) -> Self {
+        Attacker {
+            damage,
+            damage_percent_bonus,
+            critical_chance: 0.0,
+            effect: None,
+            trigger_chance: 0.0,
+            effect_bonus: 0.0,
+        }
+    }
+
+    pub fn with_effect(mut self, effect: Option<EffectType>, trigger_chance: f32) -> Self {
+        self.effect = effect;
+        self.trigger_chance = trigger_chance;
+        self
+    }
+}
+
+// what the defender brings to a single hit, an effect it already has does not land again
+// so it can't be kept on it by hitting it every frame
+#[derive(Clone, Debug, Default)]
+pub struct Defender {
+    pub active_effects: Vec<EffectType>,
+}
+
+impl Defender {
+    pub fn player(player_list_effects: &PlayerListEffectsComponent) -> Self {
+        Defender {
+            active_effects: player_list_effects
+                .activated_effects
+                .keys()
+                .copied()
+                .filter(|effect_type| player_list_effects.is_active(*effect_type))
+                .collect(),
+        }
+    }
+
+    pub fn monster(monster_list_effects: &MonsterListEffectsComponent) -> Self {
+        Defender {
+            active_effects: monster_list_effects
+                .activated_effects
+                .keys()
+                .copied()
+                .filter(|effect_type| monster_list_effects.is_active(*effect_type))
+                .collect(),
+        }
+    }
+
+    pub fn has_effect(&self, effect_type: EffectType) -> bool {
+        self.active_effects.contains(&effect_type)
+    }
+}
+
+// the outcome of a resolved hit, ready to be applied to the defender
+#[derive(Clone, Debug)]
+pub struct DamageEvent {
+    pub source: DamageSource,
+    pub amount: f32,
+    pub is_critical: bool,
+    pub effects: Vec<EffectType>,
+    pub effect_bonus: f32,
+}
+
+impl DamageEvent {
+    // how long the defender flashes, critical hits flash longer
+    pub fn hurt_duration(&self) -> Duration {
+        let seconds = match self.source {
+            DamageSource::Thunderstorm => 0.2,
+            _ => 0.3,
+        };
+
+        if self.is_critical {
+            Duration::from_secs_f32(seconds * 2.0)
+        } else {
+            Duration::from_secs_f32(seconds)
+        }
+    }
+}
+
+pub fn resolve_damage(
+    attacker: &Attacker,
+    defender: &Defender,
+    source: DamageSource,
+    rng: &mut impl Rng,
+) -> DamageEvent {
+    let mut amount = (attacker.damage * (1.0 + attacker.damage_percent_bonus)).max(0.0);
+
+    let is_critical =
+        attacker.critical_chance > 0.0 && rng.gen_range(0.0..1.0) < attacker.critical_chance;
+    if is_critical {
+        amount *= CRITICAL_DAMAGE_MULTIPLIER;
+    }
+
+    let mut effects = Vec::new();
+    if let Some(effect) = attacker.effect
+        && !defender.has_effect(effect)
+        && attacker.trigger_chance > 0.0
+        && rng.gen_range(0.0..1.0) < attacker.trigger_chance
+    {
+        effects.push(effect);
+    }
+
+    DamageEvent {
+        source,
+        amount,
+        is_critical,
+        effects,
+        effect_bonus: attacker.effect_bonus,
+    }
+}
+
+// active monster debuffs that change how hard it hits, e.g. reduce damage
+pub fn monster_damage_percent_bonus(monster_list_effects: &MonsterListEffectsComponent) -> f32 {
+    -monster_list_effects.bonus(EffectType::ReduceDamage)
+}
+
+pub fn damage_monster(
+    damage: &DamageEvent,
+    monster: &mut MonsterComponent,
+    monster_list_effects: &mut MonsterListEffectsComponent,
+    game_data: &GameData,
+) {
+    monster.current_health_points = (monster.current_health_points - damage.amount).max(0.0);
+
+    // game data validation makes sure every effect a monster can get has a duration
+    for effect in damage.effects.iter() {
+        if let Some(duration) = game_data.get_effect_duration(*effect) {
+            monster_list_effects.activate(*effect, duration, damage.effect_bonus);
+        }
+    }
+}
+
+pub fn damage_player(
+    damage: &DamageEvent,
+    player: &mut PlayerComponent,
+    player_list_effects: &mut PlayerListEffectsComponent,
+) {
+    player.current_health_points = (player.current_health_points - damage.amount).max(0.0);
+
+    for effect in damage.effects.iter() {
+        player_list_effects.activate(*effect);
+    }
+}
+
+// a monster hit that got through, the player flashes and can't be hit again for a while
+pub fn hurt_player(
+    damage: &DamageEvent,
+    player: &mut PlayerComponent,
+    player_animation: &mut PlayerAnimation,
+    player_list_effects: &mut PlayerListEffectsComponent,
+    invincible_cooldown: &mut InvisibleCooldownComponent,
+) {
+    damage_player(damage, player, player_list_effects);
+
+    invincible_cooldown.duration = Timer::new(Duration::from_secs_f32(2.0), TimerMode::Once);
+    invincible_cooldown.hurt_duration = Timer::new(damage.hurt_duration(), TimerMode::Once);
+    player_animation.animation_state = AnimationState::Hit;
+}
@@ -1 +1,2 @@
 pub mod collide;
+pub mod damage;
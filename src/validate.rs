@@ -0,0 +1,301 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::config::{ASSETS_DIRECTORY, DATA_FILE, LIST_ROOM_FILE, MODS_DIRECTORY, UPGRADES_FILE};
+use crate::resources::dungeon::parse_error::ParseError;
+use crate::resources::dungeon::room::{self, Room};
+use crate::resources::dungeon::rooms::Rooms;
+use crate::resources::game_data::{GameData, GameDataFragment};
+use crate::resources::mod_packs::{
+    FLOORS_DIRECTORY, FloorMap, ModPacks, PackProblem, ROOMS_DIRECTORY, floor_rows,
+};
+use crate::resources::upgrade::Upgrade;
+use crate::resources::upgrade::upgrade_controller::{UpgradeController, UpgradeList};
+
+// something wrong in a content file, `line` starts at 1 and is missing when the
+// problem is about the file as a whole
+pub struct Problem {
+    pub path: String,
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl Problem {
+    fn new(path: &Path, line: Option<usize>, message: String) -> Self {
+        Problem {
+            path: path.display().to_string(),
+            line,
+            message,
+        }
+    }
+
+    fn from_parse_error(path: &Path, err: ParseError) -> Self {
+        Problem::new(path, err.line, err.message)
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{}: {}", self.path, line, self.message),
+            None => write!(f, "{}: {}", self.path, self.message),
+        }
+    }
+}
+
+// checks the base game content and then applies the mod packs the way the game does, so
+// a pack is checked against everything that comes before it
+pub fn validate_content() -> Vec<Problem> {
+    let mut problems = Vec::new();
+
+    let assets_path = Path::new(ASSETS_DIRECTORY);
+    let upgrades_path = assets_path.join(UPGRADES_FILE);
+    let mut game_data = check_game_data(&assets_path.join(DATA_FILE), &mut problems);
+    let upgrades = check_upgrades(&upgrades_path, game_data.as_ref(), &mut problems);
+    let mut upgrade_controller = UpgradeController { upgrades };
+    let mut rooms = check_room_list(&assets_path.join(LIST_ROOM_FILE), &mut problems);
+
+    let mut mod_packs = ModPacks::new();
+    let contents = mod_packs.read_content();
+    // without base game data there is nothing to check the pack data and upgrades against
+    if let Some(game_data) = game_data.as_mut() {
+        mod_packs.apply_game_data(game_data, &contents);
+        mod_packs.apply_upgrades(&mut upgrade_controller, game_data, &contents);
+    }
+    mod_packs.apply_rooms(&mut rooms, &contents);
+    mod_packs.load_floors(&rooms, &contents);
+
+    for problem in mod_packs.problems() {
+        problems.push(pack_problem(problem));
+    }
+
+    // packs only add upgrades, so what is missing is reported on the base upgrades
+    if let Some(game_data) = game_data.as_ref() {
+        for missing in upgrade_controller.missing_upgrades(game_data) {
+            problems.push(Problem::new(&upgrades_path, None, missing));
+        }
+    }
+
+    problems
+}
+
+// the game only keeps the message of a pack problem, the file is read again to find the
+// line it is about
+fn pack_problem(problem: &PackProblem) -> Problem {
+    let path = Path::new(MODS_DIRECTORY)
+        .join(&problem.pack)
+        .join(&problem.file);
+    let contents = fs::read_to_string(&path).unwrap_or_default();
+
+    let line = if problem.file == DATA_FILE {
+        json_error_line::<GameDataFragment>(&contents)
+    } else if problem.file == UPGRADES_FILE {
+        json_error_line::<UpgradeList>(&contents).or_else(|| {
+            problem
+                .entry
+                .and_then(|index| array_element_lines(&contents, None).get(index).copied())
+        })
+    } else if problem.file.starts_with(ROOMS_DIRECTORY) {
+        Room::parse(&path, &contents).err().and_then(|err| err.line)
+    } else if problem.file.starts_with(FLOORS_DIRECTORY) {
+        match FloorMap::parse(&path, &contents) {
+            Err(err) => err.line,
+            Ok(_) => problem
+                .entry
+                .and_then(|row_index| floor_rows(&contents).nth(row_index))
+                .map(|(line, _)| line),
+        }
+    } else {
+        None
+    };
+
+    Problem::new(&path, line, problem.message.clone())
+}
+
+fn json_error_line<T: serde::de::DeserializeOwned>(contents: &str) -> Option<usize> {
+    serde_json::from_str::<T>(contents)
+        .err()
+        .map(|err| err.line())
+        .filter(|line| *line > 0)
+}
+
+fn read(path: &Path, problems: &mut Vec<Problem>) -> Option<String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Some(contents),
+        Err(err) => {
+            problems.push(Problem::new(path, None, err.to_string()));
+            None
+        }
+    }
+}
+
+fn parse_json<T: serde::de::DeserializeOwned>(
+    path: &Path,
+    contents: &str,
+    problems: &mut Vec<Problem>,
+) -> Option<T> {
+    match serde_json::from_str(contents) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            let line = Some(err.line()).filter(|line| *line > 0);
+            problems.push(Problem::new(path, line, err.to_string()));
+            None
+        }
+    }
+}
+
+fn check_game_data(path: &Path, problems: &mut Vec<Problem>) -> Option<GameData> {
+    let contents = read(path, problems)?;
+    let game_data: GameData = parse_json(path, &contents, problems)?;
+
+    if let Err(errors) = game_data.validate() {
+        for error in errors {
+            let line = error.entry.and_then(|entry| {
+                array_element_lines(&contents, Some(entry.collection()))
+                    .get(entry.index())
+                    .copied()
+            });
+            problems.push(Problem::new(path, line, error.message));
+        }
+    }
+
+    Some(game_data)
+}
+
+// upgrade names are only checked when there is game data to check them against, returns
+// the upgrades the game would keep
+fn check_upgrades(
+    path: &Path,
+    game_data: Option<&GameData>,
+    problems: &mut Vec<Problem>,
+) -> Vec<Upgrade> {
+    let Some(contents) = read(path, problems) else {
+        return Vec::new();
+    };
+    let Some(upgrades) = parse_json::<Vec<Upgrade>>(path, &contents, problems) else {
+        return Vec::new();
+    };
+    let Some(game_data) = game_data else {
+        return Vec::new();
+    };
+
+    let lines = array_element_lines(&contents, None);
+    let mut valid_upgrades = Vec::new();
+    for (index, upgrade) in upgrades.into_iter().enumerate() {
+        match upgrade.validate(game_data) {
+            Ok(()) => valid_upgrades.push(upgrade),
+            Err(err) => problems.push(Problem::new(path, lines.get(index).copied(), err)),
+        }
+    }
+
+    valid_upgrades
+}
+
+// line of every element of the top level json array, or of the array under `key` in the
+// top level object, to point at a bad upgrade or data entry
+fn array_element_lines(contents: &str, key: Option<&str>) -> Vec<usize> {
+    let mut lines = Vec::new();
+    let mut line = 1;
+    let mut depth = 0;
+    let mut is_in_string = false;
+    let mut is_escaped = false;
+    let mut string = String::new();
+    let mut last_string = String::new();
+    let mut last_key = String::new();
+    let mut array_depth = None;
+    let mut is_element_expected = false;
+
+    for character in contents.chars() {
+        if character == '\n' {
+            line += 1;
+        }
+
+        if is_in_string {
+            match character {
+                _ if is_escaped => {
+                    is_escaped = false;
+                    string.push(character);
+                }
+                '\\' => is_escaped = true,
+                '"' => {
+                    is_in_string = false;
+                    last_string = std::mem::take(&mut string);
+                }
+                _ => string.push(character),
+            }
+            continue;
+        }
+
+        if is_element_expected && !character.is_whitespace() && character != ']' {
+            lines.push(line);
+            is_element_expected = false;
+        }
+
+        match character {
+            '"' => is_in_string = true,
+            ':' => last_key = std::mem::take(&mut last_string),
+            '[' => {
+                depth += 1;
+                let is_wanted = match key {
+                    None => depth == 1,
+                    Some(key) => depth == 2 && last_key == key,
+                };
+                if is_wanted && array_depth.is_none() {
+                    array_depth = Some(depth);
+                    is_element_expected = true;
+                }
+            }
+            '{' => depth += 1,
+            ']' if array_depth == Some(depth) => break,
+            ']' | '}' => depth -= 1,
+            ',' if array_depth == Some(depth) => is_element_expected = true,
+            _ => {}
+        }
+    }
+
+    lines
+}
+
+fn check_room_list(path: &Path, problems: &mut Vec<Problem>) -> Rooms {
+    let Some(contents) = read(path, problems) else {
+        return Rooms::from_rooms(Vec::new());
+    };
+
+    let mut rooms = Vec::new();
+    for (index, file_name) in contents.lines().enumerate() {
+        let file_name = file_name.trim();
+        if file_name.is_empty() {
+            continue;
+        }
+
+        let room_path = Path::new(room::PREFIX).join(file_name);
+        if !room_path.exists() {
+            problems.push(Problem::new(
+                path,
+                Some(index + 1),
+                format!("room file {} does not exist", room_path.display()),
+            ));
+            continue;
+        }
+
+        if let Some(room) = check_room(&room_path, problems) {
+            rooms.push(room);
+        }
+    }
+
+    Rooms::from_rooms(rooms)
+}
+
+// the room is parsed the way the game does it, the error points at the bad row
+fn check_room(path: &Path, problems: &mut Vec<Problem>) -> Option<Room> {
+    let contents = read(path, problems)?;
+
+    match Room::parse(path, &contents) {
+        Ok(room) => Some(room),
+        Err(err) => {
+            problems.push(Problem::from_parse_error(path, err));
+            None
+        }
+    }
+}
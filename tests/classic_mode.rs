@@ -0,0 +1,205 @@
+mod common;
+
+use bevy::prelude::*;
+
+use dungeon_quest_v2::components::monster::MonsterComponent;
+use dungeon_quest_v2::components::player::PlayerComponent;
+use dungeon_quest_v2::plugins::weapon::AimTarget;
+use dungeon_quest_v2::resources::dungeon::Dungeon;
+use dungeon_quest_v2::resources::dungeon::door::Door;
+use dungeon_quest_v2::resources::dungeon::position::Position;
+use dungeon_quest_v2::resources::game_mode::GameMode;
+use dungeon_quest_v2::resources::hero::hero_class::HeroClass;
+use dungeon_quest_v2::resources::monster::monster_spawn_controller::MonsterSpawnController;
+use dungeon_quest_v2::resources::player::player_dungeon_stats::PlayerDungeonStats;
+use dungeon_quest_v2::resources::profile::Profile;
+use dungeon_quest_v2::scenes::SceneState;
+
+// far enough from a door to stand on the floor of the room
+const DOOR_DISTANCE: f32 = 50.0;
+
+fn current_room(app: &App) -> Position {
+    app.world()
+        .resource::<PlayerDungeonStats>()
+        .current_room_position
+}
+
+fn is_room_cleared(app: &App) -> bool {
+    app.world().resource::<PlayerDungeonStats>().is_room_cleared
+}
+
+// a room next to the current one and the door that leads there
+fn neighbour_room(app: &App) -> (Door, Position) {
+    let position = current_room(app);
+    let map = &app.world().resource::<Dungeon>().current_floor.map;
+
+    let mut neighbours = vec![
+        (Door::Right, position.row_index, position.column_index + 1),
+        (Door::Bottom, position.row_index + 1, position.column_index),
+    ];
+    if position.column_index > 0 {
+        neighbours.push((Door::Left, position.row_index, position.column_index - 1));
+    }
+    if position.row_index > 0 {
+        neighbours.push((Door::Top, position.row_index - 1, position.column_index));
+    }
+
+    neighbours
+        .into_iter()
+        .find(|(_, row_index, column_index)| {
+            map.get(*row_index)
+                .and_then(|rooms| rooms.get(*column_index))
+                .is_some_and(|room| *room != 0.0)
+        })
+        .map(|(door, row_index, column_index)| {
+            (
+                door,
+                Position {
+                    row_index,
+                    column_index,
+                },
+            )
+        })
+        .expect("the room has no neighbour")
+}
+
+// puts the hero in front of the door and holds the key that walks through it
+fn walk_through_door(app: &mut App) -> Position {
+    let (door, next_room) = neighbour_room(app);
+
+    let door_position = app
+        .world_mut()
+        .query::<(&Door, &GlobalTransform)>()
+        .iter(app.world())
+        .find(|(other, _)| **other == door)
+        .map(|(_, transform)| transform.translation().truncate())
+        .expect("the door was not built");
+
+    let (outwards, key) = match door {
+        Door::Left => (Vec2::NEG_X, KeyCode::KeyA),
+        Door::Right => (Vec2::X, KeyCode::KeyD),
+        Door::Top => (Vec2::Y, KeyCode::KeyW),
+        Door::Bottom => (Vec2::NEG_Y, KeyCode::KeyS),
+    };
+
+    let player = common::player(app);
+    let start = door_position - outwards * DOOR_DISTANCE;
+    let mut transform = app.world_mut().get_mut::<Transform>(player).unwrap();
+    transform.translation.x = start.x;
+    transform.translation.y = start.y;
+
+    common::press(app, key);
+    let has_walked = common::update_until(app, 120, |app| current_room(app) == next_room);
+    common::release(app, key);
+    assert!(
+        has_walked,
+        "the hero never walked through the {:?} door",
+        door
+    );
+
+    next_room
+}
+
+#[test]
+fn walking_through_a_door_changes_the_room() {
+    let mut app = common::start_run(GameMode::ClassicMode, HeroClass::new("Knight"));
+    common::update(&mut app, 5);
+
+    let start_room = current_room(&app);
+    assert!(is_room_cleared(&app));
+
+    let next_room = walk_through_door(&mut app);
+
+    assert_ne!(next_room, start_room);
+    assert_eq!(
+        app.world()
+            .resource::<Dungeon>()
+            .current_floor
+            .current_position,
+        next_room
+    );
+    assert!(!is_room_cleared(&app));
+}
+
+#[test]
+fn entering_a_room_spawns_the_required_monsters() {
+    let mut app = common::start_run(GameMode::ClassicMode, HeroClass::new("Knight"));
+    common::update(&mut app, 5);
+    assert!(common::monsters(&mut app).is_empty());
+
+    walk_through_door(&mut app);
+    common::update(&mut app, 2);
+
+    let require_monster = app
+        .world()
+        .resource::<MonsterSpawnController>()
+        .require_monster;
+    assert_eq!(common::monsters(&mut app).len(), require_monster as usize);
+}
+
+#[test]
+fn killing_every_monster_clears_the_room() {
+    let mut app = common::start_run(GameMode::ClassicMode, HeroClass::new("Knight"));
+    common::update(&mut app, 5);
+    walk_through_door(&mut app);
+
+    let player = common::player(&mut app);
+    let weapon = common::weapon(&mut app);
+    let is_cleared = common::update_until(&mut app, 600, |app| {
+        // keep the hero alive and swing at the monsters lined up on the sword
+        let mut player_component = app.world_mut().get_mut::<PlayerComponent>(player).unwrap();
+        player_component.current_health_points = player_component.max_health_points;
+
+        let target = app
+            .world()
+            .get::<Transform>(weapon)
+            .unwrap()
+            .translation
+            .truncate();
+
+        for monster in common::monsters(app) {
+            let mut transform = app.world_mut().get_mut::<Transform>(monster).unwrap();
+            transform.translation.x = target.x;
+            transform.translation.y = target.y;
+        }
+
+        app.world_mut().resource_mut::<AimTarget>().position = Some(target);
+        common::click(app);
+
+        is_room_cleared(app)
+    });
+
+    assert!(is_cleared, "the room was never cleared");
+    let monster_spawn_controller = app.world().resource::<MonsterSpawnController>();
+    assert_eq!(
+        monster_spawn_controller.killed_monsters,
+        monster_spawn_controller.require_monster
+    );
+    assert!(
+        app.world_mut()
+            .query::<&MonsterComponent>()
+            .iter(app.world())
+            .next()
+            .is_none()
+    );
+}
+
+#[test]
+fn losing_every_health_point_finishes_the_run() {
+    let mut app = common::start_run(GameMode::ClassicMode, HeroClass::new("Knight"));
+    common::update(&mut app, 5);
+    assert!(!app.world().resource::<Profile>().is_run_finished);
+
+    let player = common::player(&mut app);
+    app.world_mut()
+        .get_mut::<PlayerComponent>(player)
+        .unwrap()
+        .current_health_points = 0.0;
+
+    let has_ended = common::update_until(&mut app, 5, |app| {
+        common::state(app) == SceneState::ResultScene
+    });
+    assert!(has_ended, "the run did not end");
+    assert!(app.world().resource::<Profile>().is_run_finished);
+    assert!(!app.world().resource::<Profile>().is_run_completed);
+}
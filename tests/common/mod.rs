@@ -0,0 +1,110 @@
+// every test binary uses a different part of the helpers
+#![allow(dead_code)]
+
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+
+use dungeon_quest_v2::components::monster::MonsterComponent;
+use dungeon_quest_v2::components::player::PlayerComponent;
+use dungeon_quest_v2::components::weapon::WeaponComponent;
+use dungeon_quest_v2::headless::{self, HeadlessInput, HeadlessOptions};
+use dungeon_quest_v2::resources::game_mode::GameMode;
+use dungeon_quest_v2::resources::hero::hero_class::HeroClass;
+use dungeon_quest_v2::scenes::SceneState;
+
+pub const SEED: u64 = 42;
+
+// a headless run that is already in game, buttons are only pressed by the test
+pub fn start_run(game_mode: GameMode, hero_class: HeroClass) -> App {
+    let options = HeadlessOptions {
+        game_mode,
+        hero_class,
+        seed: Some(SEED),
+        input: HeadlessInput::Manual,
+        ..Default::default()
+    };
+
+    let mut app = headless::headless_app(&options);
+    app.init_resource::<MouseClick>();
+    app.add_systems(PreUpdate, click_mouse.after(InputSystem));
+
+    let in_game = update_until(&mut app, 10, |app| {
+        matches!(
+            state(app),
+            SceneState::InGameClassicMode | SceneState::InGameSurvivalMode
+        )
+    });
+    assert!(in_game, "the run never got in game");
+
+    app
+}
+
+pub fn update(app: &mut App, frames: u32) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+// steps the app until the condition holds, false when it never did
+pub fn update_until(app: &mut App, max_frames: u32, condition: impl Fn(&mut App) -> bool) -> bool {
+    for _ in 0..max_frames {
+        if condition(app) {
+            return true;
+        }
+        app.update();
+    }
+    condition(app)
+}
+
+pub fn state(app: &App) -> SceneState {
+    app.world().resource::<State<SceneState>>().get().clone()
+}
+
+pub fn press(app: &mut App, key: KeyCode) {
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(key);
+}
+
+pub fn release(app: &mut App, key: KeyCode) {
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(key);
+}
+
+// the input plugin clears last frame's presses, so a click has to land after it
+#[derive(Resource, Default)]
+pub struct MouseClick(bool);
+
+pub fn click(app: &mut App) {
+    app.world_mut().resource_mut::<MouseClick>().0 = true;
+}
+
+fn click_mouse(mut mouse_click: ResMut<MouseClick>, mut buttons: ResMut<ButtonInput<MouseButton>>) {
+    buttons.release(MouseButton::Left);
+    if mouse_click.0 {
+        buttons.press(MouseButton::Left);
+        mouse_click.0 = false;
+    }
+}
+
+pub fn monsters(app: &mut App) -> Vec<Entity> {
+    app.world_mut()
+        .query_filtered::<Entity, With<MonsterComponent>>()
+        .iter(app.world())
+        .collect()
+}
+
+pub fn player(app: &mut App) -> Entity {
+    app.world_mut()
+        .query_filtered::<Entity, With<PlayerComponent>>()
+        .single(app.world())
+        .unwrap()
+}
+
+pub fn weapon(app: &mut App) -> Entity {
+    app.world_mut()
+        .query_filtered::<Entity, With<WeaponComponent>>()
+        .single(app.world())
+        .unwrap()
+}
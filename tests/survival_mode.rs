@@ -0,0 +1,45 @@
+mod common;
+
+use bevy::prelude::*;
+use std::time::Duration;
+
+use dungeon_quest_v2::plugins::survival_mode::dungeon::wave::RewardsSceneButton;
+use dungeon_quest_v2::resources::dungeon::wave::Wave;
+use dungeon_quest_v2::resources::game_data::PauseSceneData;
+use dungeon_quest_v2::resources::game_mode::GameMode;
+use dungeon_quest_v2::resources::hero::hero_class::HeroClass;
+
+fn reward_buttons(app: &mut App) -> Vec<Entity> {
+    app.world_mut()
+        .query_filtered::<Entity, With<RewardsSceneButton>>()
+        .iter(app.world())
+        .collect()
+}
+
+#[test]
+fn picking_a_reward_starts_the_next_wave() {
+    let mut app = common::start_run(GameMode::SurvivalMode, HeroClass::new("Knight"));
+    common::update(&mut app, 5);
+    assert_eq!(app.world().resource::<Wave>().wave_number, 1);
+
+    // skip to the last moments of the first wave
+    let mut wave = app.world_mut().resource_mut::<Wave>();
+    let duration = wave.timer.duration();
+    wave.timer
+        .set_elapsed(duration.saturating_sub(Duration::from_millis(100)));
+
+    let has_rewards = common::update_until(&mut app, 30, |app| !reward_buttons(app).is_empty());
+    assert!(has_rewards, "the wave ended without rewards");
+    assert_eq!(reward_buttons(&mut app).len(), 3);
+    assert_eq!(app.world().resource::<Wave>().wave_number, 1);
+
+    let button = reward_buttons(&mut app)[0];
+    *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Pressed;
+    common::update(&mut app, 2);
+
+    let wave = app.world().resource::<Wave>();
+    assert_eq!(wave.wave_number, 2);
+    assert!(!wave.timer.finished());
+    assert!(!app.world().contains_resource::<PauseSceneData>());
+    assert!(reward_buttons(&mut app).is_empty());
+}